@@ -1,13 +1,32 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A module containing Complex numbers and their operations.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Complex {
     real: f64,
     imag: f64,
 }
 
 impl Complex {
+    /// The imaginary unit `i`.
+    pub const I: Complex = Complex {
+        real: 0.0,
+        imag: 1.0,
+    };
+
+    /// The multiplicative identity `1 + 0i`.
+    pub const ONE: Complex = Complex {
+        real: 1.0,
+        imag: 0.0,
+    };
+
+    /// The additive identity `0 + 0i`.
+    pub const ZERO: Complex = Complex {
+        real: 0.0,
+        imag: 0.0,
+    };
+
     /// Create a new Complex number given a real and imaginary part.
     ///
     /// # Format:
@@ -114,7 +133,208 @@ impl Complex {
     /// ```
     /// * * *
     pub fn magnitude(&self) -> f64 {
-        (self.real * self.real + self.imag * self.imag).sqrt()
+        self.real.hypot(self.imag)
+    }
+
+    /// Calculates the squared magnitude of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the squared magnitude of
+    ///
+    /// # Returns:
+    ///
+    /// The squared magnitude `a² + b²` of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(3.0, 4.0);
+    ///
+    /// let norm_sqr = z.norm_sqr();
+    ///
+    /// println!("Squared Magnitude of {}: {}", z, norm_sqr);
+    /// ```
+    /// * * *
+    pub fn norm_sqr(&self) -> f64 {
+        self.real * self.real + self.imag * self.imag
+    }
+
+    /// Calculates the argument (phase angle) of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the argument of
+    ///
+    /// # Returns:
+    ///
+    /// The argument of the Complex number in radians, in the range `(-π, π]`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(-1.0, 1.0);
+    ///
+    /// let arg = z.arg();
+    ///
+    /// println!("Argument of {}: {}", z, arg);
+    /// ```
+    /// * * *
+    pub fn arg(&self) -> f64 {
+        self.imag.atan2(self.real)
+    }
+
+    /// Returns the conjugate of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to conjugate
+    ///
+    /// # Returns:
+    ///
+    /// The Complex number `a - bi`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    ///
+    /// let conj = z.conj();
+    ///
+    /// println!("Conjugate of {}: {}", z, conj);
+    /// ```
+    /// * * *
+    pub fn conj(&self) -> Complex {
+        Complex::new(self.real, -self.imag)
+    }
+
+    /// Calculates the reciprocal `1 / z` of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the reciprocal of
+    ///
+    /// # Returns:
+    ///
+    /// The reciprocal of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(3.0, 4.0);
+    ///
+    /// let recip = z.recip();
+    ///
+    /// println!("Reciprocal of {}: {}", z, recip);
+    /// ```
+    /// * * *
+    pub fn recip(&self) -> Complex {
+        Complex::ONE / *self
+    }
+
+    /// Creates a new Complex number given its polar coordinates.
+    ///
+    /// # Format:
+    /// `r(cos θ + i sin θ)`
+    ///
+    /// # Parameters:
+    ///
+    /// - `magnitude`: the magnitude of the Complex number -> `r`
+    /// - `angle`: the argument of the Complex number in radians -> `θ`
+    ///
+    /// # Returns:
+    ///
+    /// A new Complex number with the given magnitude and argument.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    ///
+    /// println!("Complex Number z: {}", z);
+    /// ```
+    /// * * *
+    pub fn from_polar(magnitude: f64, angle: f64) -> Complex {
+        let (sin, cos) = angle.sin_cos();
+        Complex::new(magnitude * cos, magnitude * sin)
+    }
+
+    /// Converts a Complex number into its polar coordinates.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to convert
+    ///
+    /// # Returns:
+    ///
+    /// A tuple `(r, θ)` containing the magnitude and the argument of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 1.0);
+    ///
+    /// let (magnitude, angle) = z.to_polar();
+    ///
+    /// println!("{} = {}(cos {} + i sin {})", z, magnitude, angle, angle);
+    /// ```
+    /// * * *
+    pub fn to_polar(&self) -> (f64, f64) {
+        (self.magnitude(), self.arg())
+    }
+
+    /// Calculates all of the `n`th roots of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the roots of
+    /// - `n`: the degree of the root
+    ///
+    /// # Returns:
+    ///
+    /// A vector containing the `n` distinct roots, starting with the principal root and
+    /// continuing counter-clockwise. An empty vector is returned when `n` is `0`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 0.0);
+    ///
+    /// let roots = z.nth_roots(3);
+    ///
+    /// for root in roots {
+    ///     println!("Cube root of {}: {}", z, root);
+    /// }
+    /// ```
+    /// * * *
+    pub fn nth_roots(&self, n: u32) -> Vec<Complex> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let magnitude = self.magnitude().powf(1.0 / n as f64);
+        let angle = self.arg();
+
+        (0..n)
+            .map(|k| {
+                let theta = (angle + std::f64::consts::TAU * k as f64) / n as f64;
+                Complex::from_polar(magnitude, theta)
+            })
+            .collect()
     }
 
     /// Calculates the Complex number raised to the power of an integer.
@@ -142,7 +362,7 @@ impl Complex {
     /// * * *
     pub fn powi(&self, n: i32) -> Complex {
         let magnitude = self.magnitude().powi(n);
-        let angle = n as f64 * (self.imag / self.real).atan();
+        let angle = n as f64 * self.arg();
         let real = magnitude * angle.cos();
         let imag = magnitude * angle.sin();
         Complex::new(real, imag)
@@ -173,12 +393,187 @@ impl Complex {
     /// * * *
     pub fn powf(&self, n: f64) -> Complex {
         let magnitude = self.magnitude().powf(n);
-        let angle = n * (self.imag / self.real).atan();
+        let angle = n * self.arg();
         let real = magnitude * angle.cos();
         let imag = magnitude * angle.sin();
         Complex::new(real, imag)
     }
 
+    /// Calculates the exponential `e^z` of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to exponentiate
+    ///
+    /// # Returns:
+    ///
+    /// `e` raised to the power of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(0.0, std::f64::consts::PI);
+    ///
+    /// let exp = z.exp();
+    ///
+    /// println!("e^({}) = {}", z, exp);
+    /// ```
+    /// * * *
+    pub fn exp(&self) -> Complex {
+        Complex::from_polar(self.real.exp(), self.imag)
+    }
+
+    /// Calculates `e^z - 1` for a Complex number, accurately even when `z` is close to zero.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to exponentiate
+    ///
+    /// # Returns:
+    ///
+    /// `e` raised to the power of the Complex number, minus one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1e-10, 1e-10);
+    ///
+    /// let exp_m1 = z.exp_m1();
+    ///
+    /// println!("e^({}) - 1 = {}", z, exp_m1);
+    /// ```
+    /// * * *
+    pub fn exp_m1(&self) -> Complex {
+        let half_sin = (self.imag / 2.0).sin();
+        let real = self.real.exp_m1() * self.imag.cos() - 2.0 * half_sin * half_sin;
+        let imag = self.real.exp() * self.imag.sin();
+        Complex::new(real, imag)
+    }
+
+    /// Calculates the principal natural logarithm of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the logarithm of
+    ///
+    /// # Returns:
+    ///
+    /// The principal natural logarithm, with an imaginary part in the range `(-π, π]`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(-1.0, 0.0);
+    ///
+    /// let ln = z.ln();
+    ///
+    /// println!("ln({}) = {}", z, ln);
+    /// ```
+    /// * * *
+    pub fn ln(&self) -> Complex {
+        Complex::new(self.magnitude().ln(), self.arg())
+    }
+
+    /// Calculates the principal square root of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the square root of
+    ///
+    /// # Returns:
+    ///
+    /// The square root with a non-negative real part.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(-4.0, 0.0);
+    ///
+    /// let sqrt = z.sqrt();
+    ///
+    /// println!("sqrt({}) = {}", z, sqrt);
+    /// ```
+    /// * * *
+    pub fn sqrt(&self) -> Complex {
+        if self.real == 0.0 && self.imag == 0.0 {
+            return Complex::new(0.0, self.imag);
+        }
+
+        let t = ((self.real.abs() + self.magnitude()) / 2.0).sqrt();
+
+        if self.real >= 0.0 {
+            Complex::new(t, self.imag / (2.0 * t))
+        } else {
+            Complex::new(self.imag.abs() / (2.0 * t), t.copysign(self.imag))
+        }
+    }
+
+    /// Calculates the sine of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the sine of
+    ///
+    /// # Returns:
+    ///
+    /// The sine of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    ///
+    /// let sin = z.sin();
+    ///
+    /// println!("sin({}) = {}", z, sin);
+    /// ```
+    /// * * *
+    pub fn sin(&self) -> Complex {
+        Complex::new(
+            self.real.sin() * self.imag.cosh(),
+            self.real.cos() * self.imag.sinh(),
+        )
+    }
+
+    /// Calculates the cosine of a Complex number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Complex number to calculate the cosine of
+    ///
+    /// # Returns:
+    ///
+    /// The cosine of the Complex number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    ///
+    /// let cos = z.cos();
+    ///
+    /// println!("cos({}) = {}", z, cos);
+    /// ```
+    /// * * *
+    pub fn cos(&self) -> Complex {
+        Complex::new(
+            self.real.cos() * self.imag.cosh(),
+            -self.real.sin() * self.imag.sinh(),
+        )
+    }
+
     /// Complex Arithmetic:
     ///
     /// Regular Arithmetic but with Complex Numbers.
@@ -201,6 +596,17 @@ impl Complex {
     /// println!("{} - {} = {}", z1, z2, complex_minus);
     /// println!("{} * {} = {}", z1, z2, complex_product);
     /// println!("{} / {} = {}", z1, z2, complex_division);
+    ///
+    /// let scaled = 2.0 * z1 + 1.0;
+    /// let mut accumulator = Complex::ZERO;
+    /// accumulator += scaled;
+    /// accumulator *= Complex::I;
+    ///
+    /// let total: Complex = [z1, z2].iter().sum();
+    ///
+    /// println!("2 * {} + 1 = {}", z1, scaled);
+    /// println!("{} * i = {}", scaled, accumulator);
+    /// println!("{} + {} = {}", z1, z2, total);
     /// ```
     /// * * *
     pub fn arithmetic() {
@@ -257,14 +663,181 @@ impl Mul<Complex> for Complex {
     }
 }
 
-// Implement division for Complex numbers
+// Implement division for Complex numbers using Smith's algorithm
 impl Div<Complex> for Complex {
     type Output = Complex;
 
     fn div(self, other: Complex) -> Complex {
-        let denominator = other.real * other.real + other.imag * other.imag;
-        let real = (self.real * other.real + self.imag * other.imag) / denominator;
-        let imag = (self.imag * other.real - self.real * other.imag) / denominator;
-        Complex::new(real, imag)
+        if other.real.abs() >= other.imag.abs() {
+            let ratio = other.imag / other.real;
+            let denominator = other.real + other.imag * ratio;
+            let real = (self.real + self.imag * ratio) / denominator;
+            let imag = (self.imag - self.real * ratio) / denominator;
+            Complex::new(real, imag)
+        } else {
+            let ratio = other.real / other.imag;
+            let denominator = other.real * ratio + other.imag;
+            let real = (self.real * ratio + self.imag) / denominator;
+            let imag = (self.imag * ratio - self.real) / denominator;
+            Complex::new(real, imag)
+        }
+    }
+}
+
+// Implement negation for Complex numbers
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.real, -self.imag)
+    }
+}
+
+// Implement addition between Complex numbers and floats
+impl Add<f64> for Complex {
+    type Output = Complex;
+
+    fn add(self, other: f64) -> Complex {
+        Complex::new(self.real + other, self.imag)
+    }
+}
+
+impl Add<Complex> for f64 {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self + other.real, other.imag)
+    }
+}
+
+// Implement subtraction between Complex numbers and floats
+impl Sub<f64> for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: f64) -> Complex {
+        Complex::new(self.real - other, self.imag)
+    }
+}
+
+impl Sub<Complex> for f64 {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self - other.real, -other.imag)
+    }
+}
+
+// Implement multiplication between Complex numbers and floats
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: f64) -> Complex {
+        Complex::new(self.real * other, self.imag * other)
+    }
+}
+
+impl Mul<Complex> for f64 {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(self * other.real, self * other.imag)
+    }
+}
+
+// Implement division between Complex numbers and floats
+impl Div<f64> for Complex {
+    type Output = Complex;
+
+    fn div(self, other: f64) -> Complex {
+        Complex::new(self.real / other, self.imag / other)
+    }
+}
+
+impl Div<Complex> for f64 {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        Complex::new(self, 0.0) / other
+    }
+}
+
+// Implement the assignment operators for Complex numbers
+impl AddAssign<Complex> for Complex {
+    fn add_assign(&mut self, other: Complex) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Complex> for Complex {
+    fn sub_assign(&mut self, other: Complex) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<Complex> for Complex {
+    fn mul_assign(&mut self, other: Complex) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<Complex> for Complex {
+    fn div_assign(&mut self, other: Complex) {
+        *self = *self / other;
+    }
+}
+
+impl AddAssign<f64> for Complex {
+    fn add_assign(&mut self, other: f64) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<f64> for Complex {
+    fn sub_assign(&mut self, other: f64) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f64> for Complex {
+    fn mul_assign(&mut self, other: f64) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<f64> for Complex {
+    fn div_assign(&mut self, other: f64) {
+        *self = *self / other;
+    }
+}
+
+// Implement conversion from floats into Complex numbers
+impl From<f64> for Complex {
+    fn from(real: f64) -> Complex {
+        Complex::new(real, 0.0)
+    }
+}
+
+// Implement summing and multiplying iterators of Complex numbers
+impl Sum for Complex {
+    fn sum<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ZERO, |acc, z| acc + z)
+    }
+}
+
+impl<'a> Sum<&'a Complex> for Complex {
+    fn sum<I: Iterator<Item = &'a Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ZERO, |acc, z| acc + *z)
+    }
+}
+
+impl Product for Complex {
+    fn product<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ONE, |acc, z| acc * z)
+    }
+}
+
+impl<'a> Product<&'a Complex> for Complex {
+    fn product<I: Iterator<Item = &'a Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ONE, |acc, z| acc * *z)
     }
 }
@@ -0,0 +1,122 @@
+use crate::complex::Complex;
+use std::fmt;
+use std::str::FromStr;
+
+/// The error returned when a string cannot be parsed into a Complex number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseComplexError {
+    /// The string was empty (or only contained whitespace).
+    Empty,
+    /// One of the components could not be parsed as a float.
+    InvalidNumber(String),
+    /// The string did not match any of the accepted Complex number formats.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseComplexError::Empty => {
+                write!(f, "cannot parse a Complex number from an empty string")
+            }
+            ParseComplexError::InvalidNumber(number) => {
+                write!(f, "invalid number `{}` in Complex number", number)
+            }
+            ParseComplexError::InvalidFormat(input) => write!(
+                f,
+                "invalid Complex number `{}`, the accepted formats are:\na+bi\na+bj\nbi\n(a,b)\nr∠θ",
+                input
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+fn parse_component(component: &str) -> Result<f64, ParseComplexError> {
+    component
+        .parse::<f64>()
+        .map_err(|_| ParseComplexError::InvalidNumber(component.to_string()))
+}
+
+fn parse_imaginary(component: &str) -> Result<f64, ParseComplexError> {
+    match component {
+        "" | "+" => Ok(1.0),
+        "-" => Ok(-1.0),
+        _ => parse_component(component),
+    }
+}
+
+// Finds the sign that separates the real part from the imaginary part, skipping a leading sign
+// and the signs of exponents (ie: `1e-3`).
+fn find_separator(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+
+    (1..bytes.len())
+        .rev()
+        .find(|&i| (bytes[i] == b'+' || bytes[i] == b'-') && !matches!(bytes[i - 1], b'e' | b'E'))
+}
+
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    /// Parses a Complex number from a string.
+    ///
+    /// # Accepted Formats:
+    ///
+    /// - `a+bi` or `a+bj`: rectangular form (ie: `3+4i`, `1e3-2j`)
+    /// - `bi` or `a`: purely imaginary or purely real numbers (ie: `-2.5i`, `7`)
+    /// - `(a,b)`: a tuple of the real and imaginary parts (ie: `(1,2)`)
+    /// - `r∠θ`: polar form with the angle in radians (ie: `5∠0.927`)
+    ///
+    /// Whitespace is ignored and the output of `Display` can always be parsed back.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Complex;
+    ///
+    /// let z: Complex = "3+4i".parse().unwrap();
+    /// let w: Complex = "(1,2)".parse().unwrap();
+    ///
+    /// println!("{} and {}", z, w);
+    /// ```
+    /// * * *
+    fn from_str(input: &str) -> Result<Complex, ParseComplexError> {
+        let cleaned: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+        if cleaned.is_empty() {
+            return Err(ParseComplexError::Empty);
+        }
+
+        if let Some(inner) = cleaned
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let (real, imag) = inner
+                .split_once(',')
+                .ok_or_else(|| ParseComplexError::InvalidFormat(input.to_string()))?;
+            return Ok(Complex::new(parse_component(real)?, parse_component(imag)?));
+        }
+
+        if let Some((magnitude, angle)) = cleaned.split_once('∠') {
+            return Ok(Complex::from_polar(
+                parse_component(magnitude)?,
+                parse_component(angle)?,
+            ));
+        }
+
+        let imaginary = match cleaned.strip_suffix(['i', 'j']) {
+            Some(imaginary) => imaginary,
+            None => return Ok(Complex::new(parse_component(&cleaned)?, 0.0)),
+        };
+
+        match find_separator(imaginary) {
+            Some(index) => Ok(Complex::new(
+                parse_component(&imaginary[..index])?,
+                parse_imaginary(&imaginary[index..])?,
+            )),
+            None => Ok(Complex::new(0.0, parse_imaginary(imaginary)?)),
+        }
+    }
+}
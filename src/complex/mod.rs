@@ -1,3 +1,5 @@
 mod complex_num;
+mod complex_parse;
 
 pub use complex_num::*;
+pub use complex_parse::*;
@@ -0,0 +1,822 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A module containing Dual numbers and their operations.
+///
+/// A Dual number `a + bε` with `ε² = 0` carries a value and its derivative, so evaluating a function
+/// on `Dual::variable(x)` gives `f(x) + f'(x)ε`: forward-mode automatic differentiation with no
+/// truncation error.
+///
+/// # Supported Operations:
+///
+/// - Addition
+/// - Subtraction
+/// - Multiplication
+/// - Division
+/// - Negation
+///
+/// All of the operations are also supported between Dual numbers and floats, together with the
+/// assignment operators (ie: `+=`) and summing or multiplying iterators of Dual numbers.
+///
+/// # Example:
+///
+/// ```
+/// use numerilib::Dual;
+///
+/// let x = Dual::variable(2.0);
+///
+/// // f(x) = (3x^2 + 1) / x, so f(2) = 6.5 and f'(2) = 3 - 1/4 = 2.75
+/// let f = (3.0 * x * x + 1.0) / x;
+///
+/// println!("f(2) = {}, f'(2) = {}", f.real_part(), f.dual_part());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    real: f64,
+    dual: f64,
+}
+
+impl Dual {
+    /// Create a new Dual number given a real part and a dual part.
+    ///
+    /// # Format:
+    /// `a + bε`
+    ///
+    /// # Parameters:
+    ///
+    /// - `real`: the real part (the value)
+    /// - `dual`: the dual part (the derivative)
+    ///
+    /// # Returns:
+    ///
+    /// A new Dual number with the given parts.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::new(3.0, 1.0);
+    ///
+    /// println!("Dual number x: {}", x);
+    /// ```
+    /// * * *
+    pub fn new(real: f64, dual: f64) -> Dual {
+        Dual { real, dual }
+    }
+
+    /// Create the Dual number of an independent variable, `x + 1ε`.
+    ///
+    /// # Parameters:
+    ///
+    /// - `x`: the value of the variable
+    ///
+    /// # Returns:
+    ///
+    /// A Dual number whose derivative is one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    /// let y = x * x + 3.0 * x;
+    ///
+    /// println!("f(2) = {}, f'(2) = {}", y.real_part(), y.dual_part());
+    /// ```
+    /// * * *
+    pub fn variable(x: f64) -> Dual {
+        Dual::new(x, 1.0)
+    }
+
+    /// Create the Dual number of a constant, `c + 0ε`.
+    ///
+    /// # Parameters:
+    ///
+    /// - `c`: the value of the constant
+    ///
+    /// # Returns:
+    ///
+    /// A Dual number whose derivative is zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let c = Dual::constant(2.0);
+    ///
+    /// println!("Constant c: {}", c);
+    /// ```
+    /// * * *
+    pub fn constant(c: f64) -> Dual {
+        Dual::new(c, 0.0)
+    }
+
+    /// Returns the real part of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number to get the real part of
+    ///
+    /// # Returns:
+    ///
+    /// The real part (the value) of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::new(3.0, 1.0);
+    ///
+    /// println!("Real part of {}: {}", x, x.real_part());
+    /// ```
+    /// * * *
+    pub fn real_part(&self) -> f64 {
+        self.real
+    }
+
+    /// Returns the dual part of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number to get the dual part of
+    ///
+    /// # Returns:
+    ///
+    /// The dual part (the derivative) of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::new(3.0, 1.0);
+    ///
+    /// println!("Dual part of {}: {}", x, x.dual_part());
+    /// ```
+    /// * * *
+    pub fn dual_part(&self) -> f64 {
+        self.dual
+    }
+
+    // Applies the chain rule given the value and the derivative of a function at the real part.
+    fn chain(&self, value: f64, derivative: f64) -> Dual {
+        Dual::new(value, derivative * self.dual)
+    }
+
+    /// Raises a Dual number to an integer power.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    /// - `n`: the exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    ///
+    /// println!("({})^3 = {}", x, x.powi(3));
+    /// ```
+    /// * * *
+    pub fn powi(&self, n: i32) -> Dual {
+        // The derivative of x^0 is 0, also at x = 0 where 0 * 0^(-1) would be NaN.
+        let derivative = if n == 0 {
+            0.0
+        } else {
+            n as f64 * self.real.powi(n - 1)
+        };
+        self.chain(self.real.powi(n), derivative)
+    }
+
+    /// Raises a Dual number to a real power.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    /// - `n`: the exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    ///
+    /// println!("({})^1.5 = {}", x, x.powf(1.5));
+    /// ```
+    /// * * *
+    pub fn powf(&self, n: f64) -> Dual {
+        let derivative = if n == 0.0 {
+            0.0
+        } else {
+            n * self.real.powf(n - 1.0)
+        };
+        self.chain(self.real.powf(n), derivative)
+    }
+
+    /// Raises a Dual number to a Dual power, as `e^(n ln(x))`.
+    ///
+    /// The real part of the base must be positive.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    /// - `n`: the Dual exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    ///
+    /// println!("({})^({}) = {}", x, x, x.pow(x));
+    /// ```
+    /// * * *
+    pub fn pow(&self, n: Dual) -> Dual {
+        (n * self.ln()).exp()
+    }
+
+    /// Calculates the reciprocal `1/x` of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The reciprocal of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    ///
+    /// println!("recip({}) = {}", x, x.recip());
+    /// ```
+    /// * * *
+    pub fn recip(&self) -> Dual {
+        let a = self.real;
+        self.chain(1.0 / a, -1.0 / (a * a))
+    }
+
+    /// Calculates the exponential `e^x` of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// `e` raised to the power of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("exp({}) = {}", x, x.exp());
+    /// ```
+    /// * * *
+    pub fn exp(&self) -> Dual {
+        let value = self.real.exp();
+        self.chain(value, value)
+    }
+
+    /// Calculates the natural logarithm of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The natural logarithm of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(2.0);
+    ///
+    /// println!("ln({}) = {}", x, x.ln());
+    /// ```
+    /// * * *
+    pub fn ln(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.ln(), 1.0 / a)
+    }
+
+    /// Calculates the square root of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The square root of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(4.0);
+    ///
+    /// println!("sqrt({}) = {}", x, x.sqrt());
+    /// ```
+    /// * * *
+    pub fn sqrt(&self) -> Dual {
+        let value = self.real.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    /// Calculates the sine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The sine of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("sin({}) = {}", x, x.sin());
+    /// ```
+    /// * * *
+    pub fn sin(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.sin(), a.cos())
+    }
+
+    /// Calculates the cosine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The cosine of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("cos({}) = {}", x, x.cos());
+    /// ```
+    /// * * *
+    pub fn cos(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.cos(), -a.sin())
+    }
+
+    /// Calculates the tangent of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The tangent of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("tan({}) = {}", x, x.tan());
+    /// ```
+    /// * * *
+    pub fn tan(&self) -> Dual {
+        let value = self.real.tan();
+        self.chain(value, 1.0 + value * value)
+    }
+
+    /// Calculates the arcsine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arcsine of the Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(0.5);
+    ///
+    /// println!("asin({}) = {}", x, x.asin());
+    /// ```
+    /// * * *
+    pub fn asin(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.asin(), 1.0 / (1.0 - a * a).sqrt())
+    }
+
+    /// Calculates the arccosine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arccosine of the Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(0.5);
+    ///
+    /// println!("acos({}) = {}", x, x.acos());
+    /// ```
+    /// * * *
+    pub fn acos(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.acos(), -1.0 / (1.0 - a * a).sqrt())
+    }
+
+    /// Calculates the arctangent of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arctangent of the Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("atan({}) = {}", x, x.atan());
+    /// ```
+    /// * * *
+    pub fn atan(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.atan(), 1.0 / (1.0 + a * a))
+    }
+
+    /// Calculates the hyperbolic sine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic sine of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("sinh({}) = {}", x, x.sinh());
+    /// ```
+    /// * * *
+    pub fn sinh(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.sinh(), a.cosh())
+    }
+
+    /// Calculates the hyperbolic cosine of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic cosine of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("cosh({}) = {}", x, x.cosh());
+    /// ```
+    /// * * *
+    pub fn cosh(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.cosh(), a.sinh())
+    }
+
+    /// Calculates the hyperbolic tangent of a Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic tangent of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(1.0);
+    ///
+    /// println!("tanh({}) = {}", x, x.tanh());
+    /// ```
+    /// * * *
+    pub fn tanh(&self) -> Dual {
+        let value = self.real.tanh();
+        self.chain(value, 1.0 - value * value)
+    }
+
+    /// Calculates the absolute value of a Dual number.
+    ///
+    /// The derivative at zero is taken to be zero.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The absolute value of the Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Dual;
+    ///
+    /// let x = Dual::variable(-2.0);
+    ///
+    /// println!("abs({}) = {}", x, x.abs());
+    /// ```
+    /// * * *
+    pub fn abs(&self) -> Dual {
+        let a = self.real;
+        self.chain(a.abs(), if a == 0.0 { 0.0 } else { a.signum() })
+    }
+}
+
+impl std::fmt::Display for Dual {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}{}ε", self.real, sign(self.dual), self.dual.abs())
+    }
+}
+
+fn sign(value: f64) -> char {
+    if value >= 0.0 {
+        '+'
+    } else {
+        '-'
+    }
+}
+
+// Implement addition for Dual numbers
+impl Add<Dual> for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+// Implement subtraction for Dual numbers
+impl Sub<Dual> for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+// Implement multiplication for Dual numbers using the product rule
+impl Mul<Dual> for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real,
+        )
+    }
+}
+
+// Implement division for Dual numbers using the quotient rule
+impl Div<Dual> for Dual {
+    type Output = Dual;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Dual) -> Dual {
+        self * other.recip()
+    }
+}
+
+// Implement negation for Dual numbers
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual::new(-self.real, -self.dual)
+    }
+}
+
+// Implement addition between Dual numbers and floats
+impl Add<f64> for Dual {
+    type Output = Dual;
+
+    fn add(self, other: f64) -> Dual {
+        Dual::new(self.real + other, self.dual)
+    }
+}
+
+impl Add<Dual> for f64 {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        other + self
+    }
+}
+
+// Implement subtraction between Dual numbers and floats
+impl Sub<f64> for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: f64) -> Dual {
+        Dual::new(self.real - other, self.dual)
+    }
+}
+
+impl Sub<Dual> for f64 {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self - other.real, -other.dual)
+    }
+}
+
+// Implement multiplication between Dual numbers and floats
+impl Mul<f64> for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: f64) -> Dual {
+        Dual::new(self.real * other, self.dual * other)
+    }
+}
+
+impl Mul<Dual> for f64 {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        other * self
+    }
+}
+
+// Implement division between Dual numbers and floats
+impl Div<f64> for Dual {
+    type Output = Dual;
+
+    fn div(self, other: f64) -> Dual {
+        Dual::new(self.real / other, self.dual / other)
+    }
+}
+
+impl Div<Dual> for f64 {
+    type Output = Dual;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Dual) -> Dual {
+        self * other.recip()
+    }
+}
+
+// Implement assignment operators for Dual numbers
+impl AddAssign<Dual> for Dual {
+    fn add_assign(&mut self, other: Dual) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Dual> for Dual {
+    fn sub_assign(&mut self, other: Dual) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<Dual> for Dual {
+    fn mul_assign(&mut self, other: Dual) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<Dual> for Dual {
+    fn div_assign(&mut self, other: Dual) {
+        *self = *self / other;
+    }
+}
+
+impl AddAssign<f64> for Dual {
+    fn add_assign(&mut self, other: f64) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<f64> for Dual {
+    fn sub_assign(&mut self, other: f64) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f64> for Dual {
+    fn mul_assign(&mut self, other: f64) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<f64> for Dual {
+    fn div_assign(&mut self, other: f64) {
+        *self = *self / other;
+    }
+}
+
+// Implement conversion from floats to constant Dual numbers
+impl From<f64> for Dual {
+    fn from(real: f64) -> Dual {
+        Dual::constant(real)
+    }
+}
+
+// Implement summation and product of iterators of Dual numbers
+impl Sum for Dual {
+    fn sum<I: Iterator<Item = Dual>>(iter: I) -> Dual {
+        iter.fold(Dual::constant(0.0), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Dual> for Dual {
+    fn sum<I: Iterator<Item = &'a Dual>>(iter: I) -> Dual {
+        iter.fold(Dual::constant(0.0), |acc, x| acc + *x)
+    }
+}
+
+impl Product for Dual {
+    fn product<I: Iterator<Item = Dual>>(iter: I) -> Dual {
+        iter.fold(Dual::constant(1.0), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Dual> for Dual {
+    fn product<I: Iterator<Item = &'a Dual>>(iter: I) -> Dual {
+        iter.fold(Dual::constant(1.0), |acc, x| acc * *x)
+    }
+}
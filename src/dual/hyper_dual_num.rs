@@ -0,0 +1,949 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A module containing Hyper-Dual numbers and their operations.
+///
+/// A Hyper-Dual number `a + bε₁ + cε₂ + dε₁ε₂` with `ε₁² = ε₂² = 0` carries a value together with
+/// first and second derivatives, so evaluating a function on `HyperDual::variable(x)` gives
+/// `f(x) + f'(x)ε₁ + f'(x)ε₂ + f''(x)ε₁ε₂` with no truncation error.
+///
+/// # Supported Operations:
+///
+/// - Addition
+/// - Subtraction
+/// - Multiplication
+/// - Division
+/// - Negation
+///
+/// All of the operations are also supported between Hyper-Dual numbers and floats, together with the
+/// assignment operators (ie: `+=`) and summing or multiplying iterators of Hyper-Dual numbers.
+///
+/// # Example:
+///
+/// ```
+/// use numerilib::HyperDual;
+///
+/// let x = HyperDual::variable(2.0);
+///
+/// // f(x) = (3x^2 + 1) / x, so f'(2) = 2.75 and f''(2) = 2 / 8 = 0.25
+/// let f = (3.0 * x * x + 1.0) / x;
+///
+/// println!("f'(2) = {}, f''(2) = {}", f.first_part(), f.mixed_part());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HyperDual {
+    real: f64,
+    first: f64,
+    second: f64,
+    mixed: f64,
+}
+
+impl HyperDual {
+    /// Create a new Hyper-Dual number given its four parts.
+    ///
+    /// # Format:
+    /// `a + bε₁ + cε₂ + dε₁ε₂`
+    ///
+    /// # Parameters:
+    ///
+    /// - `real`: the real part
+    /// - `first`: the `ε₁` part
+    /// - `second`: the `ε₂` part
+    /// - `mixed`: the `ε₁ε₂` part
+    ///
+    /// # Returns:
+    ///
+    /// A new Hyper-Dual number with the given parts.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::new(3.0, 1.0, 1.0, 0.0);
+    ///
+    /// println!("Hyper-Dual number x: {}", x);
+    /// ```
+    /// * * *
+    pub fn new(real: f64, first: f64, second: f64, mixed: f64) -> HyperDual {
+        HyperDual {
+            real,
+            first,
+            second,
+            mixed,
+        }
+    }
+
+    /// Create the Hyper-Dual number of an independent variable, `x + 1ε₁ + 1ε₂ + 0ε₁ε₂`.
+    ///
+    /// # Parameters:
+    ///
+    /// - `x`: the value of the variable
+    ///
+    /// # Returns:
+    ///
+    /// A Hyper-Dual number whose `ε₁ε₂` part becomes the second derivative of any function applied to it.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    /// let y = x.powi(3);
+    ///
+    /// println!("f'(2) = {}, f''(2) = {}", y.first_part(), y.mixed_part());
+    /// ```
+    /// * * *
+    pub fn variable(x: f64) -> HyperDual {
+        HyperDual::new(x, 1.0, 1.0, 0.0)
+    }
+
+    /// Create the Hyper-Dual number of a constant, `c + 0ε₁ + 0ε₂ + 0ε₁ε₂`.
+    ///
+    /// # Parameters:
+    ///
+    /// - `c`: the value of the constant
+    ///
+    /// # Returns:
+    ///
+    /// A Hyper-Dual number whose derivatives are zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let c = HyperDual::constant(2.0);
+    ///
+    /// println!("Constant c: {}", c);
+    /// ```
+    /// * * *
+    pub fn constant(c: f64) -> HyperDual {
+        HyperDual::new(c, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns the real part of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number to get the real part of
+    ///
+    /// # Returns:
+    ///
+    /// The real part (the value) of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(3.0);
+    ///
+    /// println!("Real part of {}: {}", x, x.real_part());
+    /// ```
+    /// * * *
+    pub fn real_part(&self) -> f64 {
+        self.real
+    }
+
+    /// Returns the `ε₁` part of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number to get the `ε₁` part of
+    ///
+    /// # Returns:
+    ///
+    /// The `ε₁` part (the first derivative) of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(3.0);
+    ///
+    /// println!("ε₁ part of {}: {}", x, x.first_part());
+    /// ```
+    /// * * *
+    pub fn first_part(&self) -> f64 {
+        self.first
+    }
+
+    /// Returns the `ε₂` part of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number to get the `ε₂` part of
+    ///
+    /// # Returns:
+    ///
+    /// The `ε₂` part (the first derivative) of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(3.0);
+    ///
+    /// println!("ε₂ part of {}: {}", x, x.second_part());
+    /// ```
+    /// * * *
+    pub fn second_part(&self) -> f64 {
+        self.second
+    }
+
+    /// Returns the `ε₁ε₂` part of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number to get the `ε₁ε₂` part of
+    ///
+    /// # Returns:
+    ///
+    /// The `ε₁ε₂` part (the second derivative) of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(3.0);
+    ///
+    /// println!("ε₁ε₂ part of {}: {}", x, x.mixed_part());
+    /// ```
+    /// * * *
+    pub fn mixed_part(&self) -> f64 {
+        self.mixed
+    }
+
+    // Applies the chain rule given the value, first and second derivatives of a function at the real part.
+    fn chain(&self, value: f64, derivative: f64, second_derivative: f64) -> HyperDual {
+        HyperDual::new(
+            value,
+            derivative * self.first,
+            derivative * self.second,
+            derivative * self.mixed + second_derivative * self.first * self.second,
+        )
+    }
+
+    /// Raises a Hyper-Dual number to an integer power.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    /// - `n`: the exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Hyper-Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    ///
+    /// println!("({})^3 = {}", x, x.powi(3));
+    /// ```
+    /// * * *
+    pub fn powi(&self, n: i32) -> HyperDual {
+        // Vanishing derivatives are exactly 0, also at x = 0 where 0 * 0^(-1) would be NaN.
+        let derivative = if n == 0 {
+            0.0
+        } else {
+            n as f64 * self.real.powi(n - 1)
+        };
+        let second_derivative = if n == 0 || n == 1 {
+            0.0
+        } else {
+            n as f64 * (n - 1) as f64 * self.real.powi(n - 2)
+        };
+        self.chain(self.real.powi(n), derivative, second_derivative)
+    }
+
+    /// Raises a Hyper-Dual number to a real power.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    /// - `n`: the exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Hyper-Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    ///
+    /// println!("({})^1.5 = {}", x, x.powf(1.5));
+    /// ```
+    /// * * *
+    pub fn powf(&self, n: f64) -> HyperDual {
+        let derivative = if n == 0.0 {
+            0.0
+        } else {
+            n * self.real.powf(n - 1.0)
+        };
+        let second_derivative = if n == 0.0 || n == 1.0 {
+            0.0
+        } else {
+            n * (n - 1.0) * self.real.powf(n - 2.0)
+        };
+        self.chain(self.real.powf(n), derivative, second_derivative)
+    }
+
+    /// Raises a Hyper-Dual number to a Hyper-Dual power, as `e^(n ln(x))`.
+    ///
+    /// The real part of the base must be positive.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    /// - `n`: the Hyper-Dual exponent
+    ///
+    /// # Returns:
+    ///
+    /// The Hyper-Dual number raised to the power `n`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    ///
+    /// println!("({})^({}) = {}", x, x, x.pow(x));
+    /// ```
+    /// * * *
+    pub fn pow(&self, n: HyperDual) -> HyperDual {
+        (n * self.ln()).exp()
+    }
+
+    /// Calculates the reciprocal `1/x` of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The reciprocal of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    ///
+    /// println!("recip({}) = {}", x, x.recip());
+    /// ```
+    /// * * *
+    pub fn recip(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(1.0 / a, -1.0 / (a * a), 2.0 / (a * a * a))
+    }
+
+    /// Calculates the exponential `e^x` of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// `e` raised to the power of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("exp({}) = {}", x, x.exp());
+    /// ```
+    /// * * *
+    pub fn exp(&self) -> HyperDual {
+        let value = self.real.exp();
+        self.chain(value, value, value)
+    }
+
+    /// Calculates the natural logarithm of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The natural logarithm of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(2.0);
+    ///
+    /// println!("ln({}) = {}", x, x.ln());
+    /// ```
+    /// * * *
+    pub fn ln(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.ln(), 1.0 / a, -1.0 / (a * a))
+    }
+
+    /// Calculates the square root of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The square root of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(4.0);
+    ///
+    /// println!("sqrt({}) = {}", x, x.sqrt());
+    /// ```
+    /// * * *
+    pub fn sqrt(&self) -> HyperDual {
+        let value = self.real.sqrt();
+        self.chain(value, 0.5 / value, -0.25 / (self.real * value))
+    }
+
+    /// Calculates the sine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The sine of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("sin({}) = {}", x, x.sin());
+    /// ```
+    /// * * *
+    pub fn sin(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.sin(), a.cos(), -a.sin())
+    }
+
+    /// Calculates the cosine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The cosine of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("cos({}) = {}", x, x.cos());
+    /// ```
+    /// * * *
+    pub fn cos(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.cos(), -a.sin(), -a.cos())
+    }
+
+    /// Calculates the tangent of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The tangent of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("tan({}) = {}", x, x.tan());
+    /// ```
+    /// * * *
+    pub fn tan(&self) -> HyperDual {
+        let value = self.real.tan();
+        self.chain(
+            value,
+            1.0 + value * value,
+            2.0 * value * (1.0 + value * value),
+        )
+    }
+
+    /// Calculates the arcsine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arcsine of the Hyper-Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(0.5);
+    ///
+    /// println!("asin({}) = {}", x, x.asin());
+    /// ```
+    /// * * *
+    pub fn asin(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(
+            a.asin(),
+            1.0 / (1.0 - a * a).sqrt(),
+            a / (1.0 - a * a).powf(1.5),
+        )
+    }
+
+    /// Calculates the arccosine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arccosine of the Hyper-Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(0.5);
+    ///
+    /// println!("acos({}) = {}", x, x.acos());
+    /// ```
+    /// * * *
+    pub fn acos(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(
+            a.acos(),
+            -1.0 / (1.0 - a * a).sqrt(),
+            -a / (1.0 - a * a).powf(1.5),
+        )
+    }
+
+    /// Calculates the arctangent of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The arctangent of the Hyper-Dual number, in radians.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("atan({}) = {}", x, x.atan());
+    /// ```
+    /// * * *
+    pub fn atan(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(
+            a.atan(),
+            1.0 / (1.0 + a * a),
+            -2.0 * a / ((1.0 + a * a) * (1.0 + a * a)),
+        )
+    }
+
+    /// Calculates the hyperbolic sine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic sine of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("sinh({}) = {}", x, x.sinh());
+    /// ```
+    /// * * *
+    pub fn sinh(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.sinh(), a.cosh(), a.sinh())
+    }
+
+    /// Calculates the hyperbolic cosine of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic cosine of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("cosh({}) = {}", x, x.cosh());
+    /// ```
+    /// * * *
+    pub fn cosh(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.cosh(), a.sinh(), a.cosh())
+    }
+
+    /// Calculates the hyperbolic tangent of a Hyper-Dual number.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The hyperbolic tangent of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(1.0);
+    ///
+    /// println!("tanh({}) = {}", x, x.tanh());
+    /// ```
+    /// * * *
+    pub fn tanh(&self) -> HyperDual {
+        let value = self.real.tanh();
+        self.chain(
+            value,
+            1.0 - value * value,
+            -2.0 * value * (1.0 - value * value),
+        )
+    }
+
+    /// Calculates the absolute value of a Hyper-Dual number.
+    ///
+    /// The derivative at zero is taken to be zero.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Hyper-Dual number
+    ///
+    /// # Returns:
+    ///
+    /// The absolute value of the Hyper-Dual number.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::HyperDual;
+    ///
+    /// let x = HyperDual::variable(-2.0);
+    ///
+    /// println!("abs({}) = {}", x, x.abs());
+    /// ```
+    /// * * *
+    pub fn abs(&self) -> HyperDual {
+        let a = self.real;
+        self.chain(a.abs(), if a == 0.0 { 0.0 } else { a.signum() }, 0.0)
+    }
+}
+
+impl std::fmt::Display for HyperDual {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}ε₁{}{}ε₂{}{}ε₁ε₂",
+            self.real,
+            sign(self.first),
+            self.first.abs(),
+            sign(self.second),
+            self.second.abs(),
+            sign(self.mixed),
+            self.mixed.abs()
+        )
+    }
+}
+
+fn sign(value: f64) -> char {
+    if value >= 0.0 {
+        '+'
+    } else {
+        '-'
+    }
+}
+
+// Implement addition for Hyper-Dual numbers
+impl Add<HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    fn add(self, other: HyperDual) -> HyperDual {
+        HyperDual::new(
+            self.real + other.real,
+            self.first + other.first,
+            self.second + other.second,
+            self.mixed + other.mixed,
+        )
+    }
+}
+
+// Implement subtraction for Hyper-Dual numbers
+impl Sub<HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    fn sub(self, other: HyperDual) -> HyperDual {
+        HyperDual::new(
+            self.real - other.real,
+            self.first - other.first,
+            self.second - other.second,
+            self.mixed - other.mixed,
+        )
+    }
+}
+
+// Implement multiplication for Hyper-Dual numbers using the product rule
+impl Mul<HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    fn mul(self, other: HyperDual) -> HyperDual {
+        HyperDual::new(
+            self.real * other.real,
+            self.real * other.first + self.first * other.real,
+            self.real * other.second + self.second * other.real,
+            self.real * other.mixed
+                + self.first * other.second
+                + self.second * other.first
+                + self.mixed * other.real,
+        )
+    }
+}
+
+// Implement division for Hyper-Dual numbers using the quotient rule
+impl Div<HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: HyperDual) -> HyperDual {
+        self * other.recip()
+    }
+}
+
+// Implement negation for Hyper-Dual numbers
+impl Neg for HyperDual {
+    type Output = HyperDual;
+
+    fn neg(self) -> HyperDual {
+        HyperDual::new(-self.real, -self.first, -self.second, -self.mixed)
+    }
+}
+
+// Implement addition between Hyper-Dual numbers and floats
+impl Add<f64> for HyperDual {
+    type Output = HyperDual;
+
+    fn add(self, other: f64) -> HyperDual {
+        HyperDual::new(self.real + other, self.first, self.second, self.mixed)
+    }
+}
+
+impl Add<HyperDual> for f64 {
+    type Output = HyperDual;
+
+    fn add(self, other: HyperDual) -> HyperDual {
+        other + self
+    }
+}
+
+// Implement subtraction between Hyper-Dual numbers and floats
+impl Sub<f64> for HyperDual {
+    type Output = HyperDual;
+
+    fn sub(self, other: f64) -> HyperDual {
+        HyperDual::new(self.real - other, self.first, self.second, self.mixed)
+    }
+}
+
+impl Sub<HyperDual> for f64 {
+    type Output = HyperDual;
+
+    fn sub(self, other: HyperDual) -> HyperDual {
+        HyperDual::new(self - other.real, -other.first, -other.second, -other.mixed)
+    }
+}
+
+// Implement multiplication between Hyper-Dual numbers and floats
+impl Mul<f64> for HyperDual {
+    type Output = HyperDual;
+
+    fn mul(self, other: f64) -> HyperDual {
+        HyperDual::new(
+            self.real * other,
+            self.first * other,
+            self.second * other,
+            self.mixed * other,
+        )
+    }
+}
+
+impl Mul<HyperDual> for f64 {
+    type Output = HyperDual;
+
+    fn mul(self, other: HyperDual) -> HyperDual {
+        other * self
+    }
+}
+
+// Implement division between Hyper-Dual numbers and floats
+impl Div<f64> for HyperDual {
+    type Output = HyperDual;
+
+    fn div(self, other: f64) -> HyperDual {
+        HyperDual::new(
+            self.real / other,
+            self.first / other,
+            self.second / other,
+            self.mixed / other,
+        )
+    }
+}
+
+impl Div<HyperDual> for f64 {
+    type Output = HyperDual;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: HyperDual) -> HyperDual {
+        self * other.recip()
+    }
+}
+
+// Implement assignment operators for Hyper-Dual numbers
+impl AddAssign<HyperDual> for HyperDual {
+    fn add_assign(&mut self, other: HyperDual) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<HyperDual> for HyperDual {
+    fn sub_assign(&mut self, other: HyperDual) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<HyperDual> for HyperDual {
+    fn mul_assign(&mut self, other: HyperDual) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<HyperDual> for HyperDual {
+    fn div_assign(&mut self, other: HyperDual) {
+        *self = *self / other;
+    }
+}
+
+impl AddAssign<f64> for HyperDual {
+    fn add_assign(&mut self, other: f64) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<f64> for HyperDual {
+    fn sub_assign(&mut self, other: f64) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f64> for HyperDual {
+    fn mul_assign(&mut self, other: f64) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<f64> for HyperDual {
+    fn div_assign(&mut self, other: f64) {
+        *self = *self / other;
+    }
+}
+
+// Implement conversion from floats to constant Hyper-Dual numbers
+impl From<f64> for HyperDual {
+    fn from(real: f64) -> HyperDual {
+        HyperDual::constant(real)
+    }
+}
+
+// Implement summation and product of iterators of Hyper-Dual numbers
+impl Sum for HyperDual {
+    fn sum<I: Iterator<Item = HyperDual>>(iter: I) -> HyperDual {
+        iter.fold(HyperDual::constant(0.0), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a HyperDual> for HyperDual {
+    fn sum<I: Iterator<Item = &'a HyperDual>>(iter: I) -> HyperDual {
+        iter.fold(HyperDual::constant(0.0), |acc, x| acc + *x)
+    }
+}
+
+impl Product for HyperDual {
+    fn product<I: Iterator<Item = HyperDual>>(iter: I) -> HyperDual {
+        iter.fold(HyperDual::constant(1.0), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a HyperDual> for HyperDual {
+    fn product<I: Iterator<Item = &'a HyperDual>>(iter: I) -> HyperDual {
+        iter.fold(HyperDual::constant(1.0), |acc, x| acc * *x)
+    }
+}
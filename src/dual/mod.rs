@@ -0,0 +1,5 @@
+mod dual_num;
+mod hyper_dual_num;
+
+pub use dual_num::*;
+pub use hyper_dual_num::*;
@@ -1,7 +1,5 @@
 pub(crate) struct Extra;
 
 impl Extra {
-    pub const EPSILON1: f64 = 1e-7;
-
     pub const EPSILON2: f64 = 1e-15;
 }
@@ -0,0 +1,633 @@
+use crate::func::gauss_kronrod::IntegrationResult;
+use crate::func::gauss_quadrature::{GaussQuadrature, GaussRule};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// Primitive polynomials (degree s, coefficients a) and initial direction numbers m of the Sobol sequence
+// for dimensions 2 to 10 (Joe and Kuo, new-joe-kuo-6.21201). The first dimension is the van der Corput sequence.
+const SOBOL_PARAMETERS: [(u32, u32, [u32; 5]); 9] = [
+    (1, 0, [1, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0]),
+    (4, 4, [1, 3, 5, 13, 0]),
+    (5, 2, [1, 1, 5, 5, 17]),
+    (5, 4, [1, 1, 5, 5, 5]),
+    (5, 7, [1, 1, 7, 11, 19]),
+];
+
+const SOBOL_BITS: usize = 32;
+
+/// A region of integration in several dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Region {
+    /// The box `lower[i] ≤ x[i] ≤ upper[i]`.
+    Cuboid { lower: Vec<f64>, upper: Vec<f64> },
+    /// The simplex spanned by `d + 1` vertices in `d` dimensions.
+    Simplex { vertices: Vec<Vec<f64>> },
+}
+
+/// The low-discrepancy sequence used by quasi-Monte Carlo integration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuasiRandomSequence {
+    /// The Sobol sequence with the direction numbers of Joe and Kuo, for up to 10 dimensions.
+    Sobol,
+    /// The Halton sequence, using the first `d` primes as bases.
+    Halton,
+}
+
+// SplitMix64, a small generator that is good enough for Monte Carlo sampling and random shifts.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+// Generates the points of the Sobol sequence in Gray code order (Antonov-Saleev).
+struct Sobol {
+    directions: Vec<[u32; SOBOL_BITS]>,
+    state: Vec<u32>,
+    index: u32,
+}
+
+impl Sobol {
+    fn new(dimension: usize) -> Self {
+        assert!(
+            dimension <= SOBOL_PARAMETERS.len() + 1,
+            "The Sobol sequence is only available for up to {} dimensions!",
+            SOBOL_PARAMETERS.len() + 1
+        );
+
+        let mut directions = vec![[0_u32; SOBOL_BITS]; dimension];
+
+        for (k, direction) in directions[0].iter_mut().enumerate() {
+            *direction = 1 << (SOBOL_BITS - 1 - k);
+        }
+
+        for (v, &(s, a, m)) in directions.iter_mut().skip(1).zip(SOBOL_PARAMETERS.iter()) {
+            let s = s as usize;
+
+            for k in 0..SOBOL_BITS {
+                v[k] = if k < s {
+                    m[k] << (SOBOL_BITS - 1 - k)
+                } else {
+                    let mut value = v[k - s] ^ (v[k - s] >> s);
+                    for j in 1..s {
+                        if (a >> (s - 1 - j)) & 1 == 1 {
+                            value ^= v[k - j];
+                        }
+                    }
+                    value
+                };
+            }
+        }
+
+        Self {
+            directions,
+            state: vec![0; dimension],
+            index: 0,
+        }
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) {
+        for (coordinate, &state) in point.iter_mut().zip(&self.state) {
+            *coordinate = state as f64 / (1_u64 << SOBOL_BITS) as f64;
+        }
+
+        let bit = self.index.trailing_ones() as usize;
+        for (state, direction) in self.state.iter_mut().zip(&self.directions) {
+            *state ^= direction[bit];
+        }
+        self.index += 1;
+    }
+}
+
+// Generates the points of the Halton sequence from the radical inverses of the point index.
+struct Halton {
+    bases: Vec<u64>,
+    index: u64,
+}
+
+impl Halton {
+    fn new(dimension: usize) -> Self {
+        let mut bases = Vec::with_capacity(dimension);
+        let mut candidate = 2;
+
+        while bases.len() < dimension {
+            if bases.iter().all(|&prime| candidate % prime != 0) {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+
+        Self { bases, index: 0 }
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) {
+        for (coordinate, &base) in point.iter_mut().zip(&self.bases) {
+            let mut remaining = self.index;
+            let mut scale = 1.0 / base as f64;
+            *coordinate = 0.0;
+
+            while remaining > 0 {
+                *coordinate += (remaining % base) as f64 * scale;
+                remaining /= base;
+                scale /= base as f64;
+            }
+        }
+        self.index += 1;
+    }
+}
+
+enum PointGenerator {
+    Sobol(Sobol),
+    Halton(Halton),
+}
+
+impl PointGenerator {
+    fn next_point(&mut self, point: &mut [f64]) {
+        match self {
+            PointGenerator::Sobol(sobol) => sobol.next_point(point),
+            PointGenerator::Halton(halton) => halton.next_point(point),
+        }
+    }
+}
+
+// A subregion of the unit cube with its Genz-Malik estimate, ordered by error.
+struct Subregion {
+    center: Vec<f64>,
+    half_width: Vec<f64>,
+    value: f64,
+    error: f64,
+    split: usize,
+}
+
+impl PartialEq for Subregion {
+    fn eq(&self, other: &Self) -> bool {
+        self.error.total_cmp(&other.error) == Ordering::Equal
+    }
+}
+
+impl Eq for Subregion {}
+
+impl PartialOrd for Subregion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Subregion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error.total_cmp(&other.error)
+    }
+}
+
+pub struct Cubature;
+
+impl Cubature {
+    // The number of randomly shifted copies of the low-discrepancy sequence used to estimate the error of
+    // quasi-Monte Carlo integration.
+    const QMC_SHIFTS: usize = 16;
+    // Monte Carlo samples are drawn in batches of this size between checks of the error estimate.
+    const MC_BATCH: usize = 1024;
+
+    // The determinant of a square matrix by Gaussian elimination with partial pivoting.
+    fn determinant(mut matrix: Vec<Vec<f64>>) -> f64 {
+        let n = matrix.len();
+        let mut determinant = 1.0;
+
+        for column in 0..n {
+            let pivot = (column..n)
+                .max_by(|&i, &j| matrix[i][column].abs().total_cmp(&matrix[j][column].abs()))
+                .unwrap_or(column);
+
+            if matrix[pivot][column] == 0.0 {
+                return 0.0;
+            }
+            if pivot != column {
+                matrix.swap(pivot, column);
+                determinant = -determinant;
+            }
+
+            determinant *= matrix[column][column];
+
+            let (upper, lower) = matrix.split_at_mut(column + 1);
+            let pivot_row = &upper[column];
+            for row in lower.iter_mut() {
+                let factor = row[column] / pivot_row[column];
+                for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+
+        determinant
+    }
+
+    // Rewrites the integral over a region as an integral over the unit cube. A box is mapped affinely and a
+    // simplex through the Duffy transformation y_k = u_k (1 - u_1)...(1 - u_{k-1}) onto the unit simplex,
+    // followed by the affine map onto the vertices.
+    fn unit_cube<'a, F: Fn(&[f64]) -> f64 + 'a>(
+        function: F,
+        region: &'a Region,
+    ) -> (usize, impl Fn(&[f64]) -> f64 + 'a) {
+        let dimension = match region {
+            Region::Cuboid { lower, upper } => {
+                assert_eq!(
+                    lower.len(),
+                    upper.len(),
+                    "The limits of the box must have the same dimension!"
+                );
+                lower.len()
+            }
+            Region::Simplex { vertices } => {
+                let dimension = vertices.len().saturating_sub(1);
+                assert!(
+                    vertices.iter().all(|vertex| vertex.len() == dimension),
+                    "A simplex in d dimensions must have d + 1 vertices of dimension d!"
+                );
+                dimension
+            }
+        };
+
+        let volume = match region {
+            Region::Cuboid { lower, upper } => {
+                lower.iter().zip(upper).map(|(a, b)| b - a).product()
+            }
+            Region::Simplex { vertices } => {
+                let edges = vertices[1..]
+                    .iter()
+                    .map(|vertex| {
+                        vertex
+                            .iter()
+                            .zip(&vertices[0])
+                            .map(|(v, o)| v - o)
+                            .collect()
+                    })
+                    .collect();
+                Self::determinant(edges).abs()
+            }
+        };
+
+        let transformed = move |u: &[f64]| -> f64 {
+            match region {
+                Region::Cuboid { lower, upper } => {
+                    let x: Vec<f64> = u
+                        .iter()
+                        .zip(lower.iter().zip(upper))
+                        .map(|(t, (a, b))| a + (b - a) * t)
+                        .collect();
+                    volume * function(&x)
+                }
+                Region::Simplex { vertices } => {
+                    let mut x = vertices[0].clone();
+                    let mut remaining = 1.0;
+                    let mut jacobian = 1.0;
+
+                    for (k, (t, vertex)) in u.iter().zip(&vertices[1..]).enumerate() {
+                        let y = remaining * t;
+                        for (coordinate, (v, o)) in
+                            x.iter_mut().zip(vertex.iter().zip(&vertices[0]))
+                        {
+                            *coordinate += y * (v - o);
+                        }
+                        jacobian *= (1.0 - t).powi((dimension - 1 - k) as i32);
+                        remaining *= 1.0 - t;
+                    }
+
+                    volume * jacobian * function(&x)
+                }
+            }
+        };
+
+        (dimension, transformed)
+    }
+
+    pub fn nested_gauss<F: Fn(&[f64]) -> f64>(function: F, region: &Region, n: usize) -> f64 {
+        if n == 0 {
+            return 0.0;
+        }
+
+        let (dimension, integrand) = Self::unit_cube(function, region);
+        let (nodes, weights) = GaussQuadrature::nodes(GaussRule::Legendre, n);
+
+        // Iterates over all n^d combinations of nodes like an odometer.
+        let mut indices = vec![0; dimension];
+        let mut point = vec![0.0; dimension];
+        let mut sum = 0.0;
+
+        loop {
+            let mut weight = 1.0;
+            for (coordinate, &index) in point.iter_mut().zip(&indices) {
+                *coordinate = 0.5 * (nodes[index] + 1.0);
+                weight *= 0.5 * weights[index];
+            }
+            sum += weight * integrand(&point);
+
+            let mut axis = 0;
+            while axis < dimension {
+                indices[axis] += 1;
+                if indices[axis] < n {
+                    break;
+                }
+                indices[axis] = 0;
+                axis += 1;
+            }
+
+            if axis == dimension {
+                break;
+            }
+        }
+
+        sum
+    }
+
+    // Applies the degree 7 Genz-Malik rule with its embedded degree 5 rule to a box, and chooses the axis
+    // with the largest fourth divided difference for the next bisection.
+    fn genz_malik_rule<F: Fn(&[f64]) -> f64>(
+        integrand: &F,
+        center: Vec<f64>,
+        half_width: Vec<f64>,
+    ) -> (Subregion, usize) {
+        let d = center.len();
+        let d_f = d as f64;
+
+        let lambda2 = (9.0_f64 / 70.0).sqrt();
+        let lambda4 = (9.0_f64 / 10.0).sqrt();
+        let lambda5 = (9.0_f64 / 19.0).sqrt();
+        let ratio = lambda2 * lambda2 / (lambda4 * lambda4);
+
+        let mut point = center.clone();
+        let f1 = integrand(&point);
+        let mut evaluations = 1;
+
+        let mut sum2 = 0.0;
+        let mut sum3 = 0.0;
+        let mut split = 0;
+        let mut largest_difference = -1.0;
+
+        for i in 0..d {
+            let mut pair = |lambda: f64| {
+                point[i] = center[i] - lambda * half_width[i];
+                let minus = integrand(&point);
+                point[i] = center[i] + lambda * half_width[i];
+                let plus = integrand(&point);
+                point[i] = center[i];
+                minus + plus
+            };
+
+            let f2 = pair(lambda2);
+            let f3 = pair(lambda4);
+            evaluations += 4;
+
+            sum2 += f2;
+            sum3 += f3;
+
+            let difference = (f2 - 2.0 * f1 - ratio * (f3 - 2.0 * f1)).abs();
+            if difference > largest_difference {
+                largest_difference = difference;
+                split = i;
+            }
+        }
+
+        let mut sum4 = 0.0;
+        for i in 0..d {
+            for j in (i + 1)..d {
+                for (si, sj) in [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)] {
+                    point[i] = center[i] + si * lambda4 * half_width[i];
+                    point[j] = center[j] + sj * lambda4 * half_width[j];
+                    sum4 += integrand(&point);
+                }
+                point[i] = center[i];
+                point[j] = center[j];
+                evaluations += 4;
+            }
+        }
+
+        let mut sum5 = 0.0;
+        for corner in 0..(1_usize << d) {
+            for (k, coordinate) in point.iter_mut().enumerate() {
+                let sign = if (corner >> k) & 1 == 1 { 1.0 } else { -1.0 };
+                *coordinate = center[k] + sign * lambda5 * half_width[k];
+            }
+            sum5 += integrand(&point);
+        }
+        evaluations += 1 << d;
+
+        let volume: f64 = half_width.iter().map(|h| 2.0 * h).product();
+
+        let degree7 = volume
+            * ((12824.0 - 9120.0 * d_f + 400.0 * d_f * d_f) / 19683.0 * f1
+                + 980.0 / 6561.0 * sum2
+                + (1820.0 - 400.0 * d_f) / 19683.0 * sum3
+                + 200.0 / 19683.0 * sum4
+                + 6859.0 / 19683.0 / 2_f64.powi(d as i32) * sum5);
+
+        let degree5 = volume
+            * ((729.0 - 950.0 * d_f + 50.0 * d_f * d_f) / 729.0 * f1
+                + 245.0 / 486.0 * sum2
+                + (265.0 - 100.0 * d_f) / 1458.0 * sum3
+                + 25.0 / 729.0 * sum4);
+
+        let subregion = Subregion {
+            center,
+            half_width,
+            value: degree7,
+            error: (degree7 - degree5).abs(),
+            split,
+        };
+
+        (subregion, evaluations)
+    }
+
+    pub fn genz_malik<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        tolerance: f64,
+        max_evaluations: usize,
+    ) -> IntegrationResult {
+        let (dimension, integrand) = Self::unit_cube(function, region);
+        assert!(
+            dimension >= 2,
+            "Genz-Malik cubature requires at least 2 dimensions!"
+        );
+
+        let (first, mut evaluations) =
+            Self::genz_malik_rule(&integrand, vec![0.5; dimension], vec![0.5; dimension]);
+        let rule_evaluations = evaluations;
+
+        let mut value = first.value;
+        let mut abs_error = first.error;
+        let mut heap = BinaryHeap::from([first]);
+
+        while abs_error > tolerance.max(tolerance * value.abs())
+            && evaluations + 2 * rule_evaluations <= max_evaluations
+        {
+            let Some(largest) = heap.pop() else {
+                break;
+            };
+
+            let mut half_width = largest.half_width.clone();
+            half_width[largest.split] *= 0.5;
+
+            let mut left_center = largest.center.clone();
+            left_center[largest.split] -= half_width[largest.split];
+            let mut right_center = largest.center;
+            right_center[largest.split] += half_width[largest.split];
+
+            let (left, left_evaluations) =
+                Self::genz_malik_rule(&integrand, left_center, half_width.clone());
+            let (right, right_evaluations) =
+                Self::genz_malik_rule(&integrand, right_center, half_width);
+            evaluations += left_evaluations + right_evaluations;
+
+            value += left.value + right.value - largest.value;
+            heap.push(left);
+            heap.push(right);
+
+            // The sums are recomputed to avoid the accumulation of rounding errors.
+            abs_error = heap.iter().map(|subregion| subregion.error).sum();
+        }
+
+        IntegrationResult {
+            value: heap.iter().map(|subregion| subregion.value).sum(),
+            abs_error,
+            evaluations,
+            converged: abs_error <= tolerance.max(tolerance * value.abs()),
+        }
+    }
+
+    pub fn monte_carlo<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        tolerance: f64,
+        max_evaluations: usize,
+        seed: u64,
+    ) -> IntegrationResult {
+        let (dimension, integrand) = Self::unit_cube(function, region);
+        let mut generator = SplitMix64(seed);
+        let mut point = vec![0.0; dimension];
+
+        // Welford's running mean and sum of squared deviations.
+        let mut mean = 0.0;
+        let mut squares = 0.0;
+        let mut evaluations = 0;
+        let mut abs_error = f64::INFINITY;
+        let mut converged = false;
+
+        while evaluations < max_evaluations {
+            let batch = Self::MC_BATCH.min(max_evaluations - evaluations);
+
+            for _ in 0..batch {
+                point
+                    .iter_mut()
+                    .for_each(|coordinate| *coordinate = generator.next_f64());
+                let value = integrand(&point);
+
+                evaluations += 1;
+                let delta = value - mean;
+                mean += delta / evaluations as f64;
+                squares += delta * (value - mean);
+            }
+
+            if evaluations > 1 {
+                abs_error = (squares / ((evaluations - 1) * evaluations) as f64).sqrt();
+            }
+
+            if abs_error <= tolerance.max(tolerance * mean.abs()) {
+                converged = true;
+                break;
+            }
+        }
+
+        IntegrationResult {
+            value: mean,
+            abs_error,
+            evaluations,
+            converged,
+        }
+    }
+
+    pub fn quasi_monte_carlo<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        sequence: QuasiRandomSequence,
+        tolerance: f64,
+        max_evaluations: usize,
+        seed: u64,
+    ) -> IntegrationResult {
+        let (dimension, integrand) = Self::unit_cube(function, region);
+
+        let mut random = SplitMix64(seed);
+        let shifts: Vec<Vec<f64>> = (0..Self::QMC_SHIFTS)
+            .map(|_| (0..dimension).map(|_| random.next_f64()).collect())
+            .collect();
+
+        let mut generator = match sequence {
+            QuasiRandomSequence::Sobol => PointGenerator::Sobol(Sobol::new(dimension)),
+            QuasiRandomSequence::Halton => PointGenerator::Halton(Halton::new(dimension)),
+        };
+
+        // Each randomly shifted copy (Cranley-Patterson rotation) gives an independent unbiased estimate, and
+        // the spread of the estimates gives the error. The number of points is doubled until it is small enough.
+        let mut sums = [0.0; Self::QMC_SHIFTS];
+        let mut base = vec![0.0; dimension];
+        let mut point = vec![0.0; dimension];
+        let mut points = 0;
+        // The first batch has 64 points per shift, or fewer when the budget is smaller, but at least one.
+        let mut target = (max_evaluations / Self::QMC_SHIFTS).clamp(1, 64);
+
+        let mut value;
+        let mut abs_error;
+        let mut converged = false;
+
+        loop {
+            while points < target {
+                generator.next_point(&mut base);
+                for (sum, shift) in sums.iter_mut().zip(&shifts) {
+                    for ((coordinate, b), s) in point.iter_mut().zip(&base).zip(shift) {
+                        *coordinate = (b + s).fract();
+                    }
+                    *sum += integrand(&point);
+                }
+                points += 1;
+            }
+
+            let estimates: Vec<f64> = sums.iter().map(|sum| sum / points as f64).collect();
+            let shifts = Self::QMC_SHIFTS as f64;
+            value = estimates.iter().sum::<f64>() / shifts;
+            let variance = estimates
+                .iter()
+                .map(|estimate| (estimate - value).powi(2))
+                .sum::<f64>()
+                / (shifts - 1.0);
+            abs_error = (variance / shifts).sqrt();
+
+            if abs_error <= tolerance.max(tolerance * value.abs()) {
+                converged = true;
+                break;
+            }
+
+            target *= 2;
+            if target * Self::QMC_SHIFTS > max_evaluations {
+                break;
+            }
+        }
+
+        IntegrationResult {
+            value,
+            abs_error,
+            evaluations: points * Self::QMC_SHIFTS,
+            converged,
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{Complex, Matrix, Vector};
+
+/// The direction of the points sampled by a finite difference stencil.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stencil {
+    /// Samples `x, x + h, x + 2h, ...`
+    Forward,
+    /// Samples `x, x - h, x - 2h, ...`
+    Backward,
+    /// Samples points symmetrically around `x`.
+    Central,
+}
+
+/// The result of a derivative computed with an error estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DerivativeResult {
+    /// The estimated value of the derivative.
+    pub value: f64,
+    /// An estimate of the absolute error of `value`.
+    pub abs_error: f64,
+    /// The number of times the function was evaluated.
+    pub evaluations: usize,
+}
+
+pub struct Differentiation;
+
+impl Differentiation {
+    // Step shrink factor of Ridders' tableau, and the factor by which the error may grow before it stops.
+    const RIDDERS_SHRINK: f64 = 1.4;
+    const RIDDERS_SAFE: f64 = 2.0;
+    const RIDDERS_TABLEAU: usize = 10;
+    // The imaginary step of the complex-step derivative, small enough that the truncation error vanishes.
+    const COMPLEX_STEP: f64 = 1e-20;
+
+    // Fornberg's algorithm for the weights of the `order`th derivative at 0 sampled at `offsets`.
+    pub fn weights(offsets: &[f64], order: usize) -> Vec<f64> {
+        let points = offsets.len();
+        if points == 0 {
+            return Vec::new();
+        }
+
+        let mut c = vec![vec![0.0; order + 1]; points];
+        let mut c1 = 1.0;
+        let mut c4 = offsets[0];
+        c[0][0] = 1.0;
+
+        for i in 1..points {
+            let highest = i.min(order);
+            let mut c2 = 1.0;
+            let c5 = c4;
+            c4 = offsets[i];
+
+            for j in 0..i {
+                let c3 = offsets[i] - offsets[j];
+                c2 *= c3;
+
+                if j == i - 1 {
+                    for k in (1..=highest).rev() {
+                        c[i][k] = c1 * (k as f64 * c[i - 1][k - 1] - c5 * c[i - 1][k]) / c2;
+                    }
+                    c[i][0] = -c1 * c5 * c[i - 1][0] / c2;
+                }
+
+                for k in (1..=highest).rev() {
+                    c[j][k] = (c4 * c[j][k] - k as f64 * c[j][k - 1]) / c3;
+                }
+                c[j][0] = c4 * c[j][0] / c3;
+            }
+
+            c1 = c2;
+        }
+
+        c.into_iter().map(|row| row[order]).collect()
+    }
+
+    // The integer offsets of a stencil for the `order`th derivative with the given order of accuracy.
+    fn offsets(order: usize, stencil: Stencil, accuracy: usize) -> Vec<f64> {
+        let accuracy = accuracy.max(1);
+
+        match stencil {
+            Stencil::Forward => (0..order + accuracy).map(|i| i as f64).collect(),
+            Stencil::Backward => (0..order + accuracy).map(|i| -(i as f64)).collect(),
+            Stencil::Central => {
+                let half = order.div_ceil(2) + accuracy.div_ceil(2) - 1;
+                (-(half as isize)..=half as isize)
+                    .map(|i| i as f64)
+                    .collect()
+            }
+        }
+    }
+
+    // A step of roughly ε^(1 / (order + accuracy)) scaled to `x` that is exactly representable
+    // once added to `x`.
+    fn step(x: f64, order: usize, accuracy: usize) -> f64 {
+        let scale = x.abs().max(1.0);
+        let h = f64::EPSILON.powf(1.0 / (order + accuracy) as f64) * scale;
+
+        (x + h) - x
+    }
+
+    // Applies the stencil, skipping the points with a zero weight. Returns the value and the
+    // number of evaluations.
+    fn apply<F: Fn(f64) -> f64>(
+        f: &F,
+        x: f64,
+        h: f64,
+        offsets: &[f64],
+        weights: &[f64],
+        order: usize,
+    ) -> (f64, usize) {
+        let mut sum = 0.0;
+        let mut evaluations = 0;
+
+        for (offset, weight) in offsets.iter().zip(weights) {
+            if *weight != 0.0 {
+                sum += weight * f(x + offset * h);
+                evaluations += 1;
+            }
+        }
+
+        (sum / h.powi(order as i32), evaluations)
+    }
+
+    pub fn finite_difference<F: Fn(f64) -> f64>(
+        f: F,
+        x: f64,
+        order: usize,
+        stencil: Stencil,
+        accuracy: usize,
+    ) -> f64 {
+        if order == 0 {
+            return f(x);
+        }
+
+        let accuracy = match stencil {
+            Stencil::Central => accuracy.max(1).next_multiple_of(2),
+            _ => accuracy.max(1),
+        };
+
+        let offsets = Self::offsets(order, stencil, accuracy);
+        let weights = Self::weights(&offsets, order);
+        let h = Self::step(x, order, accuracy);
+
+        Self::apply(&f, x, h, &offsets, &weights, order).0
+    }
+
+    // Ridders' method: the second order central difference is extrapolated to h = 0 with a
+    // Richardson tableau, stopping once the error estimate starts to grow.
+    pub fn richardson<F: Fn(f64) -> f64>(f: F, x: f64, order: usize) -> DerivativeResult {
+        if order == 0 {
+            return DerivativeResult {
+                value: f(x),
+                abs_error: 0.0,
+                evaluations: 1,
+            };
+        }
+
+        let offsets = Self::offsets(order, Stencil::Central, 2);
+        let weights = Self::weights(&offsets, order);
+        let shrink_squared = Self::RIDDERS_SHRINK * Self::RIDDERS_SHRINK;
+
+        let mut h = 0.1 * x.abs().max(1.0);
+        let mut tableau = vec![vec![0.0; Self::RIDDERS_TABLEAU]; Self::RIDDERS_TABLEAU];
+        let (first, mut evaluations) = Self::apply(&f, x, h, &offsets, &weights, order);
+        tableau[0][0] = first;
+
+        let mut value = first;
+        let mut abs_error = f64::INFINITY;
+
+        for i in 1..Self::RIDDERS_TABLEAU {
+            h /= Self::RIDDERS_SHRINK;
+            let (estimate, count) = Self::apply(&f, x, h, &offsets, &weights, order);
+            tableau[0][i] = estimate;
+            evaluations += count;
+
+            let mut factor = shrink_squared;
+            for j in 1..=i {
+                tableau[j][i] =
+                    (tableau[j - 1][i] * factor - tableau[j - 1][i - 1]) / (factor - 1.0);
+                factor *= shrink_squared;
+
+                let error = (tableau[j][i] - tableau[j - 1][i])
+                    .abs()
+                    .max((tableau[j][i] - tableau[j - 1][i - 1]).abs());
+
+                if error <= abs_error {
+                    abs_error = error;
+                    value = tableau[j][i];
+                }
+            }
+
+            if (tableau[i][i] - tableau[i - 1][i - 1]).abs() >= Self::RIDDERS_SAFE * abs_error {
+                break;
+            }
+        }
+
+        DerivativeResult {
+            value,
+            abs_error,
+            evaluations,
+        }
+    }
+
+    // One step per variable, scaled to the magnitude of that variable.
+    fn steps(x: &[f64], order: usize, accuracy: usize) -> Vec<f64> {
+        x.iter()
+            .map(|&xi| Self::step(xi, order, accuracy))
+            .collect()
+    }
+
+    fn shifted(x: &[f64], shifts: &[(usize, f64)]) -> Vec<f64> {
+        let mut point = x.to_vec();
+        for &(index, shift) in shifts {
+            point[index] += shift;
+        }
+        point
+    }
+
+    // The partial derivatives of every output of `f` with respect to every variable, one column per variable.
+    fn columns<F: Fn(&[f64]) -> Vec<f64>>(f: &F, x: &[f64], stencil: Stencil) -> Vec<Vec<f64>> {
+        let accuracy = if stencil == Stencil::Central { 2 } else { 1 };
+        let steps = Self::steps(x, 1, accuracy);
+        let center = match stencil {
+            Stencil::Central => Vec::new(),
+            _ => f(x),
+        };
+
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, &h)| {
+                let (upper, lower, width) = match stencil {
+                    Stencil::Forward => (f(&Self::shifted(x, &[(i, h)])), center.clone(), h),
+                    Stencil::Backward => (center.clone(), f(&Self::shifted(x, &[(i, -h)])), h),
+                    Stencil::Central => (
+                        f(&Self::shifted(x, &[(i, h)])),
+                        f(&Self::shifted(x, &[(i, -h)])),
+                        2.0 * h,
+                    ),
+                };
+
+                upper
+                    .iter()
+                    .zip(&lower)
+                    .map(|(a, b)| (a - b) / width)
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn gradient<const N: usize, F: Fn(&[f64]) -> f64>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Vector<N> {
+        let columns = Self::columns(&|point: &[f64]| vec![f(point)], x, stencil);
+        let mut gradient = Vector::new([0.0; N]);
+
+        for (i, column) in columns.iter().enumerate() {
+            gradient[i] = column[0];
+        }
+
+        gradient
+    }
+
+    pub fn jacobian<const M: usize, const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Matrix<M, N> {
+        let checked = |point: &[f64]| {
+            let values = f(point);
+            assert_eq!(values.len(), M, "The function must return {} values!", M);
+            values
+        };
+        let columns = Self::columns(&checked, x, stencil);
+        let mut jacobian = Matrix::new([[0.0; N]; M]);
+
+        for (j, column) in columns.iter().enumerate() {
+            for (i, value) in column.iter().enumerate() {
+                jacobian[i][j] = *value;
+            }
+        }
+
+        jacobian
+    }
+
+    pub fn hessian<const N: usize, F: Fn(&[f64]) -> f64>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Matrix<N, N> {
+        let mut hessian = Matrix::new([[0.0; N]; N]);
+        let center = f(x);
+
+        if stencil == Stencil::Central {
+            let h = Self::steps(x, 2, 2);
+
+            for i in 0..N {
+                hessian[i][i] = (f(&Self::shifted(x, &[(i, h[i])])) - 2.0 * center
+                    + f(&Self::shifted(x, &[(i, -h[i])])))
+                    / (h[i] * h[i]);
+
+                for j in 0..i {
+                    let value = (f(&Self::shifted(x, &[(i, h[i]), (j, h[j])]))
+                        - f(&Self::shifted(x, &[(i, h[i]), (j, -h[j])]))
+                        - f(&Self::shifted(x, &[(i, -h[i]), (j, h[j])]))
+                        + f(&Self::shifted(x, &[(i, -h[i]), (j, -h[j])])))
+                        / (4.0 * h[i] * h[j]);
+                    hessian[i][j] = value;
+                    hessian[j][i] = value;
+                }
+            }
+
+            return hessian;
+        }
+
+        let sign = if stencil == Stencil::Forward {
+            1.0
+        } else {
+            -1.0
+        };
+        let h: Vec<f64> = Self::steps(x, 2, 1).iter().map(|h| sign * h).collect();
+        let single: Vec<f64> = (0..N).map(|i| f(&Self::shifted(x, &[(i, h[i])]))).collect();
+
+        for i in 0..N {
+            for j in 0..=i {
+                let value = (f(&Self::shifted(x, &[(i, h[i]), (j, h[j])])) - single[i] - single[j]
+                    + center)
+                    / (h[i] * h[j]);
+                hessian[i][j] = value;
+                hessian[j][i] = value;
+            }
+        }
+
+        hessian
+    }
+
+    fn complex_shifted(x: &[f64], index: usize, shift: Complex) -> Vec<Complex> {
+        let mut point: Vec<Complex> = x.iter().map(|&xi| Complex::from(xi)).collect();
+        point[index] += shift;
+        point
+    }
+
+    pub fn complex_step_gradient<const N: usize, F: Fn(&[Complex]) -> Complex>(
+        f: F,
+        x: &[f64; N],
+    ) -> Vector<N> {
+        let mut gradient = Vector::new([0.0; N]);
+
+        for i in 0..N {
+            let step = Complex::new(0.0, Self::COMPLEX_STEP);
+            gradient[i] = f(&Self::complex_shifted(x, i, step)).imag_part() / Self::COMPLEX_STEP;
+        }
+
+        gradient
+    }
+
+    pub fn complex_step_jacobian<
+        const M: usize,
+        const N: usize,
+        F: Fn(&[Complex]) -> Vec<Complex>,
+    >(
+        f: F,
+        x: &[f64; N],
+    ) -> Matrix<M, N> {
+        let mut jacobian = Matrix::new([[0.0; N]; M]);
+
+        for j in 0..N {
+            let step = Complex::new(0.0, Self::COMPLEX_STEP);
+            let values = f(&Self::complex_shifted(x, j, step));
+            assert_eq!(values.len(), M, "The function must return {} values!", M);
+
+            for (i, value) in values.iter().enumerate() {
+                jacobian[i][j] = value.imag_part() / Self::COMPLEX_STEP;
+            }
+        }
+
+        jacobian
+    }
+
+    // Each column is the central difference of the complex-step gradient, which is then symmetrized.
+    pub fn complex_step_hessian<const N: usize, F: Fn(&[Complex]) -> Complex>(
+        f: F,
+        x: &[f64; N],
+    ) -> Matrix<N, N> {
+        let h = Self::steps(x, 1, 2);
+        let mut hessian = Matrix::new([[0.0; N]; N]);
+
+        for j in 0..N {
+            let (mut upper_point, mut lower_point) = (*x, *x);
+            upper_point[j] += h[j];
+            lower_point[j] -= h[j];
+
+            let upper = Self::complex_step_gradient(&f, &upper_point);
+            let lower = Self::complex_step_gradient(&f, &lower_point);
+
+            for i in 0..N {
+                hessian[i][j] = (upper[i] - lower[i]) / (2.0 * h[j]);
+            }
+        }
+
+        for i in 0..N {
+            for j in 0..i {
+                let average = (hessian[i][j] + hessian[j][i]) / 2.0;
+                hessian[i][j] = average;
+                hessian[j][i] = average;
+            }
+        }
+
+        hessian
+    }
+}
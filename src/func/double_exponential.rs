@@ -0,0 +1,167 @@
+use crate::func::gauss_kronrod::IntegrationResult;
+use std::f64::consts::FRAC_PI_2;
+
+pub struct DoubleExponential;
+
+impl DoubleExponential {
+    // Every level halves the step of the trapezoidal rule, so the last level uses a step of 2^-8.
+    const MAX_LEVELS: usize = 8;
+    const MIN_LEVELS: usize = 3;
+    // Past |t| = 6.5 the weights of all three transformations underflow or the nodes overflow.
+    const T_MAX: f64 = 6.5;
+
+    // Trapezoidal rule on the whole real line for `t -> weight(t) * f(x(t))`, halving the step until
+    // two successive levels agree. `node` returns `(x, weight)`, or `None` when the node is not representable.
+    fn trapezoid<F, N>(function: F, node: N, tolerance: f64) -> IntegrationResult
+    where
+        F: Fn(f64) -> f64,
+        N: Fn(f64) -> Option<(f64, f64)>,
+    {
+        let mut evaluations = 0;
+        let mut term = |t: f64| match node(t) {
+            Some((x, weight)) => {
+                evaluations += 1;
+                let value = weight * function(x);
+                if value.is_finite() {
+                    value
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+
+        let mut h = 1.0;
+        let mut sum = term(0.0);
+        let mut k = 1.0;
+        while k * h <= Self::T_MAX {
+            sum += term(k * h) + term(-k * h);
+            k += 1.0;
+        }
+
+        let mut value = h * sum;
+        let mut abs_error = f64::INFINITY;
+        let mut converged = false;
+
+        for level in 1..=Self::MAX_LEVELS {
+            h /= 2.0;
+
+            let mut t = h;
+            while t <= Self::T_MAX {
+                sum += term(t) + term(-t);
+                t += 2.0 * h;
+            }
+
+            let previous = value;
+            value = h * sum;
+            abs_error = (value - previous).abs();
+
+            if level >= Self::MIN_LEVELS && abs_error <= tolerance.max(tolerance * value.abs()) {
+                converged = true;
+                break;
+            }
+        }
+
+        IntegrationResult {
+            value,
+            abs_error,
+            evaluations,
+            converged,
+        }
+    }
+
+    // x = tanh(π/2 sinh(t)) maps the real line onto (-1, 1). The distance to the nearest endpoint is
+    // computed directly so that endpoint singularities are sampled without cancellation.
+    fn tanh_sinh<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: f64,
+    ) -> IntegrationResult {
+        let half_length = 0.5 * (upper - lower);
+
+        let node = |t: f64| {
+            let u = FRAC_PI_2 * t.sinh();
+            let cosh_u = u.cosh();
+            let complement = 1.0 / (u.abs().exp() * cosh_u);
+            let weight = FRAC_PI_2 * t.cosh() / (cosh_u * cosh_u);
+            let offset = half_length * complement;
+
+            if offset == 0.0 || weight == 0.0 {
+                return None;
+            }
+
+            let x = if t < 0.0 {
+                lower + offset
+            } else {
+                upper - offset
+            };
+            Some((x, half_length * weight))
+        };
+
+        Self::trapezoid(function, node, tolerance)
+    }
+
+    // x = a + exp(π/2 sinh(t)) maps the real line onto (a, ∞).
+    fn exp_sinh<F: Fn(f64) -> f64>(function: F, lower: f64, tolerance: f64) -> IntegrationResult {
+        let node = |t: f64| {
+            let offset = (FRAC_PI_2 * t.sinh()).exp();
+            let weight = FRAC_PI_2 * t.cosh() * offset;
+
+            if offset == 0.0 || !weight.is_finite() {
+                return None;
+            }
+
+            Some((lower + offset, weight))
+        };
+
+        Self::trapezoid(function, node, tolerance)
+    }
+
+    // x = sinh(π/2 sinh(t)) maps the real line onto itself.
+    fn sinh_sinh<F: Fn(f64) -> f64>(function: F, tolerance: f64) -> IntegrationResult {
+        let node = |t: f64| {
+            let u = FRAC_PI_2 * t.sinh();
+            let weight = FRAC_PI_2 * t.cosh() * u.cosh();
+
+            if !weight.is_finite() {
+                return None;
+            }
+
+            Some((u.sinh(), weight))
+        };
+
+        Self::trapezoid(function, node, tolerance)
+    }
+
+    pub fn integrate<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+    ) -> IntegrationResult {
+        if lower_limit == upper_limit {
+            return IntegrationResult {
+                value: 0.0,
+                abs_error: 0.0,
+                evaluations: 0,
+                converged: true,
+            };
+        }
+
+        if lower_limit > upper_limit {
+            let result = Self::integrate(function, upper_limit, lower_limit, tolerance);
+            return IntegrationResult {
+                value: -result.value,
+                ..result
+            };
+        }
+
+        match (lower_limit.is_finite(), upper_limit.is_finite()) {
+            (true, true) => Self::tanh_sinh(function, lower_limit, upper_limit, tolerance),
+            (true, false) => Self::exp_sinh(function, lower_limit, tolerance),
+            (false, true) => Self::exp_sinh(|x: f64| function(-x), -upper_limit, tolerance),
+            (false, false) => Self::sinh_sinh(function, tolerance),
+        }
+    }
+}
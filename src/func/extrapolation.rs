@@ -0,0 +1,57 @@
+/// The limit of a sequence estimated by extrapolation together with an estimate of its error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtrapolationResult {
+    /// The extrapolated limit of the sequence.
+    pub value: f64,
+    /// An estimate of the absolute error of `value`.
+    pub abs_error: f64,
+}
+
+pub struct Extrapolation;
+
+impl Extrapolation {
+    // Computes the next row of the Richardson tableau from the previous row and a new estimate, where the
+    // step of the new estimate is `ratio` times smaller and the error terms have the exponents
+    // `order`, `order + increment`, `order + 2 * increment`, ...
+    pub fn richardson_row(
+        previous: &[f64],
+        estimate: f64,
+        ratio: f64,
+        order: f64,
+        increment: f64,
+    ) -> Vec<f64> {
+        let mut row = Vec::with_capacity(previous.len() + 1);
+        row.push(estimate);
+
+        for (j, &above) in previous.iter().enumerate() {
+            let factor = ratio.powf(order + j as f64 * increment);
+            let current = row[j];
+            row.push(current + (current - above) / (factor - 1.0));
+        }
+
+        row
+    }
+
+    pub fn richardson(
+        sequence: &[f64],
+        ratio: f64,
+        order: f64,
+        increment: f64,
+    ) -> ExtrapolationResult {
+        assert!(!sequence.is_empty(), "The sequence must not be empty!");
+
+        let mut row = vec![sequence[0]];
+        let mut abs_error = f64::INFINITY;
+
+        for &estimate in &sequence[1..] {
+            let next = Self::richardson_row(&row, estimate, ratio, order, increment);
+            abs_error = (next[next.len() - 1] - row[row.len() - 1]).abs();
+            row = next;
+        }
+
+        ExtrapolationResult {
+            value: row[row.len() - 1],
+            abs_error,
+        }
+    }
+}
@@ -1,11 +1,27 @@
 use crate::extra::Extra;
+use crate::func::cubature::{Cubature, QuasiRandomSequence, Region};
+use crate::func::differentiation::{DerivativeResult, Differentiation, Stencil};
+use crate::func::double_exponential::DoubleExponential;
+use crate::func::extrapolation::{Extrapolation, ExtrapolationResult};
+use crate::func::gauss_kronrod::{GaussKronrod, IntegrationResult, KronrodRule};
+use crate::func::gauss_quadrature::{GaussQuadrature, GaussRule};
 use crate::func::integration::Integration;
+use crate::func::minimization::{
+    Minimization, MinimizationError, MinimumBracket, MinimumResult, MinimumTolerance,
+};
+use crate::func::nonlinear_systems::{NonlinearSystems, SystemError, SystemResult};
+use crate::func::root_finding::{RootError, RootFinding, RootResult, RootTolerance};
+use crate::{Complex, Dual, HyperDual, Matrix, Vector};
 
 /// A module containing Regular Mathematics Functions.
 pub struct Functions;
 
 impl Functions {
-    /// Uses the definition of a derivative to calculate the derivative of a function at a specific point of a given function.
+    /// Calculates the derivative of a function at a specific point.
+    ///
+    /// A fourth order central difference is used, with a step of about `1e-3` scaled to the magnitude of `x`,
+    /// so the result is accurate to around `1e-11` relative to the scale of the function.
+    /// Use [`Functions::richardson_derivative`] when an error estimate is needed.
     ///
     /// # Parameters
     ///
@@ -31,14 +47,418 @@ impl Functions {
     /// ```
     /// <hr/>
     pub fn derivative<F: Fn(f64) -> f64>(f: F, x: impl Into<f64> + Copy) -> f64 {
-        let h = 1e-7;
-        let definition = ((f(x.into() + h)) - f(x.into())) / h;
+        Differentiation::finite_difference(f, x.into(), 1, Stencil::Central, 4)
+    }
 
-        if definition.fract().abs() < Extra::EPSILON1 {
-            definition.round()
-        } else {
-            definition
-        }
+    /// Calculates the derivative of a function at a specific point with forward-mode automatic differentiation.
+    ///
+    /// The function is evaluated on `Dual::variable(x)`, so the derivative is exact up to the rounding
+    /// errors of evaluating the function itself, unlike finite differences which lose about half of the digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a single `Dual` argument and returns a `Dual`. This is the function for which the derivative will be calculated.
+    /// - `x`: The point at which the derivative will be calculated.
+    ///
+    /// # Returns
+    ///
+    /// The derivative of the function at the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Dual, Functions};
+    ///
+    /// let function = |x: Dual| x.sin() * x.exp();
+    /// let x = 2_f64;
+    ///
+    /// let derivative = Functions::dual_derivative(function, x);
+    ///
+    /// println!("The Derivative of sin(x)e^x at x=2 is: {}", derivative);
+    /// ```
+    /// <hr/>
+    pub fn dual_derivative<F: Fn(Dual) -> Dual>(f: F, x: f64) -> f64 {
+        f(Dual::variable(x)).dual_part()
+    }
+
+    /// Calculates the second derivative of a function at a specific point with Hyper-Dual numbers.
+    ///
+    /// The function is evaluated on `HyperDual::variable(x)`, so the second derivative is exact up to
+    /// the rounding errors of evaluating the function itself.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a single `HyperDual` argument and returns a `HyperDual`. This is the function for which the derivative will be calculated.
+    /// - `x`: The point at which the second derivative will be calculated.
+    ///
+    /// # Returns
+    ///
+    /// The second derivative of the function at the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, HyperDual};
+    ///
+    /// let function = |x: HyperDual| x.sin() * x.exp();
+    /// let x = 2_f64;
+    ///
+    /// let second = Functions::dual_second_derivative(function, x);
+    ///
+    /// println!("The second Derivative of sin(x)e^x at x=2 is: {}", second);
+    /// ```
+    /// <hr/>
+    pub fn dual_second_derivative<F: Fn(HyperDual) -> HyperDual>(f: F, x: f64) -> f64 {
+        f(HyperDual::variable(x)).mixed_part()
+    }
+
+    /// Calculates the `n`th derivative of a function at a specific point.
+    ///
+    /// A fourth order central difference is used, with a step scaled to the magnitude of `x`.
+    /// Every derivative amplifies rounding errors, so expect around `1e-8` relative accuracy for the 4th derivative.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the derivative will be calculated.
+    /// - `x`: The point at which the derivative will be calculated.
+    /// - `n`: The order of the derivative (`0` evaluates the function).
+    ///
+    /// # Returns
+    ///
+    /// The calculated `n`th derivative of the function at the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let function = |x: f64| x.powi(3);
+    /// let x = 2_f64;
+    ///
+    /// let second = Functions::nth_derivative(function, x, 2);
+    ///
+    /// println!("The second Derivative of x^3 at x=2 is: {}", second);
+    /// ```
+    /// <hr/>
+    pub fn nth_derivative<F: Fn(f64) -> f64>(f: F, x: f64, n: usize) -> f64 {
+        Differentiation::finite_difference(f, x, n, Stencil::Central, 4)
+    }
+
+    /// Calculates the `n`th derivative of a function with a finite difference stencil.
+    ///
+    /// The stencil weights are computed with Fornberg's algorithm, so any derivative order and order of accuracy can be used.
+    /// The step is chosen as `ε^(1 / (n + accuracy))` scaled to the magnitude of `x`, which balances the truncation and rounding errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the derivative will be calculated.
+    /// - `x`: The point at which the derivative will be calculated.
+    /// - `n`: The order of the derivative (`0` evaluates the function).
+    /// - `stencil`: Whether the points are sampled forward, backward or centered around `x`.
+    /// - `accuracy`: The order of accuracy of the stencil (rounded up to an even number for central stencils).
+    ///
+    /// # Returns
+    ///
+    /// The calculated `n`th derivative of the function at the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Stencil};
+    ///
+    /// let function = |x: f64| x.exp();
+    /// let x = 1_f64;
+    ///
+    /// let derivative = Functions::finite_difference(function, x, 1, Stencil::Forward, 4);
+    ///
+    /// println!("The Derivative of e^x at x=1 is: {}", derivative);
+    /// ```
+    /// <hr/>
+    pub fn finite_difference<F: Fn(f64) -> f64>(
+        f: F,
+        x: f64,
+        n: usize,
+        stencil: Stencil,
+        accuracy: usize,
+    ) -> f64 {
+        Differentiation::finite_difference(f, x, n, stencil, accuracy)
+    }
+
+    /// Calculates the finite difference weights for the `n`th derivative with Fornberg's algorithm.
+    ///
+    /// # Parameters
+    ///
+    /// - `offsets`: The points of the stencil, in units of the step `h` (ie: `[-1.0, 0.0, 1.0]`).
+    /// - `n`: The order of the derivative.
+    ///
+    /// # Returns
+    ///
+    /// The weight of each offset; the derivative is `Σ weight * f(x + offset * h) / h^n`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let weights = Functions::stencil_weights(&[-1.0, 0.0, 1.0], 2);
+    ///
+    /// println!("The weights of the second derivative are: {:?}", weights);
+    /// ```
+    /// <hr/>
+    pub fn stencil_weights(offsets: &[f64], n: usize) -> Vec<f64> {
+        Differentiation::weights(offsets, n)
+    }
+
+    /// Calculates the `n`th derivative of a function with Richardson extrapolation (Ridders' method).
+    ///
+    /// Central differences with a decreasing step are extrapolated to a step of zero, and the
+    /// extrapolation stops once rounding errors start to dominate. The first derivative of a smooth
+    /// function is usually accurate to around `1e-13`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the derivative will be calculated.
+    /// - `x`: The point at which the derivative will be calculated.
+    /// - `n`: The order of the derivative (`0` evaluates the function).
+    ///
+    /// # Returns
+    ///
+    /// A `DerivativeResult` with the derivative, an estimate of its absolute error and the number of function evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let function = |x: f64| x.sin();
+    /// let x = 1_f64;
+    ///
+    /// let result = Functions::richardson_derivative(function, x, 1);
+    ///
+    /// println!("The Derivative of sin(x) at x=1 is: {} ± {}", result.value, result.abs_error);
+    /// ```
+    /// <hr/>
+    pub fn richardson_derivative<F: Fn(f64) -> f64>(f: F, x: f64, n: usize) -> DerivativeResult {
+        Differentiation::richardson(f, x, n)
+    }
+
+    /// Calculates the gradient of a multivariate function with finite differences.
+    ///
+    /// The step of each variable is scaled to its magnitude: `√ε` for forward and backward differences, and `∛ε` for central differences.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` values and returns an `f64`.
+    /// - `x`: The point at which the gradient will be calculated.
+    /// - `stencil`: Whether forward, backward or central differences are used.
+    ///
+    /// # Returns
+    ///
+    /// A `Vector` with the partial derivative with respect to each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Stencil};
+    ///
+    /// let function = |x: &[f64]| x[0].powi(2) * x[1];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let gradient = Functions::gradient(function, &point, Stencil::Central);
+    ///
+    /// println!("The Gradient of x^2y at (1, 2) is: {:?}", gradient);
+    /// ```
+    /// <hr/>
+    pub fn gradient<const N: usize, F: Fn(&[f64]) -> f64>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Vector<N> {
+        Differentiation::gradient(f, x, stencil)
+    }
+
+    /// Calculates the Jacobian of a vector valued function with finite differences.
+    ///
+    /// The step of each variable is scaled to its magnitude: `√ε` for forward and backward differences, and `∛ε` for central differences.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` values and returns `M` values.
+    /// - `x`: The point at which the Jacobian will be calculated.
+    /// - `stencil`: Whether forward, backward or central differences are used.
+    ///
+    /// # Returns
+    ///
+    /// A `Matrix` where the element at row `i` and column `j` is the derivative of the `i`th output with respect to the `j`th variable.
+    ///
+    /// # Panics
+    ///
+    /// If `f` does not return `M` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Matrix, Stencil};
+    ///
+    /// let function = |x: &[f64]| vec![x[0] * x[1], x[0] + x[1].sin()];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let jacobian: Matrix<2, 2> = Functions::jacobian(function, &point, Stencil::Central);
+    ///
+    /// println!("The Jacobian at (1, 2) is:\n{:?}", jacobian);
+    /// ```
+    /// <hr/>
+    pub fn jacobian<const M: usize, const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Matrix<M, N> {
+        Differentiation::jacobian(f, x, stencil)
+    }
+
+    /// Calculates the Hessian of a multivariate function with finite differences.
+    ///
+    /// The step of each variable is scaled to its magnitude: `∛ε` for forward and backward differences, and `ε^(1/4)` for central differences.
+    /// Central differences are accurate to around `1e-8` relative to the scale of the function.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` values and returns an `f64`.
+    /// - `x`: The point at which the Hessian will be calculated.
+    /// - `stencil`: Whether forward, backward or central differences are used.
+    ///
+    /// # Returns
+    ///
+    /// A symmetric `Matrix` with the second partial derivatives.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Stencil};
+    ///
+    /// let function = |x: &[f64]| x[0].powi(2) * x[1];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let hessian = Functions::hessian(function, &point, Stencil::Central);
+    ///
+    /// println!("The Hessian of x^2y at (1, 2) is:\n{:?}", hessian);
+    /// ```
+    /// <hr/>
+    pub fn hessian<const N: usize, F: Fn(&[f64]) -> f64>(
+        f: F,
+        x: &[f64; N],
+        stencil: Stencil,
+    ) -> Matrix<N, N> {
+        Differentiation::hessian(f, x, stencil)
+    }
+
+    /// Calculates the gradient of a multivariate function with the complex-step method.
+    ///
+    /// The function is evaluated at `x + ih` with `h = 1e-20`, and the derivative is `Im(f) / h`.
+    /// There is no subtraction, so the gradient is accurate to machine precision, but `f` must be analytic and written for `Complex` arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` Complex values and returns a `Complex`.
+    /// - `x`: The point at which the gradient will be calculated.
+    ///
+    /// # Returns
+    ///
+    /// A `Vector` with the partial derivative with respect to each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Complex, Functions};
+    ///
+    /// let function = |x: &[Complex]| x[0].exp() * x[1];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let gradient = Functions::complex_step_gradient(function, &point);
+    ///
+    /// println!("The Gradient of e^x y at (1, 2) is: {:?}", gradient);
+    /// ```
+    /// <hr/>
+    pub fn complex_step_gradient<const N: usize, F: Fn(&[Complex]) -> Complex>(
+        f: F,
+        x: &[f64; N],
+    ) -> Vector<N> {
+        Differentiation::complex_step_gradient(f, x)
+    }
+
+    /// Calculates the Jacobian of a vector valued function with the complex-step method.
+    ///
+    /// The Jacobian is accurate to machine precision, but `f` must be analytic and written for `Complex` arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` Complex values and returns `M` Complex values.
+    /// - `x`: The point at which the Jacobian will be calculated.
+    ///
+    /// # Returns
+    ///
+    /// A `Matrix` where the element at row `i` and column `j` is the derivative of the `i`th output with respect to the `j`th variable.
+    ///
+    /// # Panics
+    ///
+    /// If `f` does not return `M` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Complex, Functions, Matrix};
+    ///
+    /// let function = |x: &[Complex]| vec![x[0] * x[1], x[0] + x[1].sin()];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let jacobian: Matrix<2, 2> = Functions::complex_step_jacobian(function, &point);
+    ///
+    /// println!("The Jacobian at (1, 2) is:\n{:?}", jacobian);
+    /// ```
+    /// <hr/>
+    pub fn complex_step_jacobian<
+        const M: usize,
+        const N: usize,
+        F: Fn(&[Complex]) -> Vec<Complex>,
+    >(
+        f: F,
+        x: &[f64; N],
+    ) -> Matrix<M, N> {
+        Differentiation::complex_step_jacobian(f, x)
+    }
+
+    /// Calculates the Hessian of a multivariate function with the complex-step method.
+    ///
+    /// Each column is a central difference of complex-step gradients, so the Hessian is accurate to
+    /// around `1e-10` relative to the scale of the function.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a slice of `N` Complex values and returns a `Complex`.
+    /// - `x`: The point at which the Hessian will be calculated.
+    ///
+    /// # Returns
+    ///
+    /// A symmetric `Matrix` with the second partial derivatives.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Complex, Functions};
+    ///
+    /// let function = |x: &[Complex]| x[0].exp() * x[1];
+    /// let point = [1_f64, 2_f64];
+    ///
+    /// let hessian = Functions::complex_step_hessian(function, &point);
+    ///
+    /// println!("The Hessian of e^x y at (1, 2) is:\n{:?}", hessian);
+    /// ```
+    /// <hr/>
+    pub fn complex_step_hessian<const N: usize, F: Fn(&[Complex]) -> Complex>(
+        f: F,
+        x: &[f64; N],
+    ) -> Matrix<N, N> {
+        Differentiation::complex_step_hessian(f, x)
     }
 
     /// The Right Endpoint method to calculate a definite integral.
@@ -154,11 +574,14 @@ impl Functions {
 
     /// The Trapezoid method to calculate a definite integral.
     ///
+    /// Infinite limits are mapped onto a finite interval with the substitution `x = c + t/(1 - t)`,
+    /// or `x = t/(1 - t²)` when both limits are infinite.
+    ///
     /// # Parameters
     ///
     /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
-    /// - `lower_limit`: The lower limit of integration.
-    /// - `upper_limit`: The upper limit of integration.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
     /// - `intervals`: The number of intervals for the Trapezoidal rule.
     ///
     /// # Returns
@@ -186,16 +609,22 @@ impl Functions {
         upper_limit: f64,
         intervals: f64,
     ) -> f64 {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
         Integration::trapezoid(function, lower_limit, upper_limit, intervals)
     }
 
     /// Uses the Composite Simpson's 1/3rd Rule to calculate a definite integral.
     ///
+    /// Infinite limits are mapped onto a finite interval with the substitution `x = c + t/(1 - t)`,
+    /// or `x = t/(1 - t²)` when both limits are infinite.
+    ///
     /// # Parameters
     ///
     /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
-    /// - `lower_limit`: The lower limit of integration.
-    /// - `upper_limit`: The upper limit of integration.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
     /// - `intervals`: The number of intervals for the Simpson's Rule.
     ///
     /// # Returns
@@ -223,16 +652,22 @@ impl Functions {
         upper_limit: f64,
         intervals: f64,
     ) -> f64 {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
         Integration::simpson(function, lower_limit, upper_limit, intervals)
     }
 
     /// Uses Boole's Rule to calculate a definite integral.
     ///
+    /// Infinite limits are mapped onto a finite interval with the substitution `x = c + t/(1 - t)`,
+    /// or `x = t/(1 - t²)` when both limits are infinite.
+    ///
     /// # Parameters
     ///
     /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
-    /// - `lower_limit`: The lower limit of integration.
-    /// - `upper_limit`: The upper limit of integration.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
     ///
     /// # Returns
     ///
@@ -253,16 +688,22 @@ impl Functions {
     /// ```
     /// <hr/>
     pub fn boole_rule<F: Fn(f64) -> f64>(function: F, lower_limit: f64, upper_limit: f64) -> f64 {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
         Integration::boole_rule(function, lower_limit, upper_limit)
     }
 
     /// Uses Adaptive Quadrature to calculate a definite integral.
     ///
+    /// Infinite limits are mapped onto a finite interval with the substitution `x = c + t/(1 - t)`,
+    /// or `x = t/(1 - t²)` when both limits are infinite.
+    ///
     /// # Parameters
     ///
     /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
-    /// - `lower_limit`: The lower limit of integration.
-    /// - `upper_limit`: The upper limit of integration.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
     /// - `tolerance`: The level of precision (ie: `1e-6`) that is passed.
     ///
     /// # Returns
@@ -290,115 +731,1024 @@ impl Functions {
         upper_limit: f64,
         tolerance: f64,
     ) -> f64 {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
         Integration::adaptive_quadrature(function, lower_limit, upper_limit, tolerance)
     }
 
-    /// Summations in Rust.
+    /// Uses globally adaptive Gauss-Kronrod quadrature (QUADPACK's QAGS) to calculate a definite integral.
+    ///
+    /// The subinterval with the largest error estimate is bisected until the total error is below the
+    /// tolerance, and the sequence of estimates is extrapolated with Wynn's epsilon algorithm, which
+    /// handles integrable singularities at the endpoints (ie: `1/√x` on `[0, 1]`).
+    /// At most 1000 subintervals are used.
+    ///
+    /// Infinite limits are mapped onto a finite interval with the substitution `x = c + t/(1 - t)`,
+    /// or `x = t/(1 - t²)` when both limits are infinite.
     ///
     /// # Parameters
     ///
-    /// - `start`: The starting value for the summation.
-    /// - `limit`: The ending value for the summation.
-    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be summed.
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
+    /// - `tolerance`: The requested accuracy (ie: `1e-10`); the error target is `max(tolerance, tolerance * |integral|)`.
+    /// - `rule`: The Gauss-Kronrod pair applied to each subinterval.
     ///
     /// # Returns
     ///
-    /// The sum of applying the function to each value in the range `[start, limit]`.
+    /// An `IntegrationResult` with the integral, an estimate of its absolute error, the number of evaluations and whether the tolerance was reached.
     ///
-    /// # Example #1: Constant
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, KronrodRule};
+    ///
+    /// let lower_bound = 0_f64;
+    /// let upper_bound = 1_f64;
+    /// let tolerance = 1e-10;
+    /// let function = |x: f64| x.ln() / x.sqrt();
+    ///
+    /// let integral = Functions::gauss_kronrod(function, lower_bound, upper_bound, tolerance, KronrodRule::G10K21);
+    ///
+    /// println!("The Integral of ln(x)/√x at [0,1] is: {} ± {}", integral.value, integral.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn gauss_kronrod<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+        rule: KronrodRule,
+    ) -> IntegrationResult {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
+        GaussKronrod::integrate(function, lower_limit, upper_limit, tolerance, rule)
+    }
+
+    /// Uses Romberg integration to calculate a definite integral.
+    ///
+    /// The trapezoid rule is refined by halving the step, reusing the previous evaluations, and the
+    /// estimates are combined in a Richardson tableau until successive diagonal entries agree.
+    /// Infinite limits are mapped onto a finite interval as in [`Functions::trapezoid`].
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
+    /// - `tolerance`: The requested accuracy (ie: `1e-10`); the error target is `max(tolerance, tolerance * |integral|)`.
+    ///
+    /// # Returns
+    ///
+    /// An `IntegrationResult` with the integral, the difference between the last two diagonal entries of the tableau, the number of evaluations and whether the tolerance was reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let lower_bound = 0_f64;
+    /// let upper_bound = std::f64::consts::PI;
+    /// let tolerance = 1e-12;
+    /// let function = |x: f64| x.sin();
+    ///
+    /// let integral = Functions::romberg(function, lower_bound, upper_bound, tolerance);
+    ///
+    /// println!("The Integral of sin(x) at [0,π] is: {} ± {}", integral.value, integral.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn romberg<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+    ) -> IntegrationResult {
+        let (function, lower_limit, upper_limit) =
+            Integration::finite_limits(function, lower_limit, upper_limit);
+
+        Integration::romberg(function, lower_limit, upper_limit, tolerance)
+    }
+
+    /// Estimates the limit of a sequence with Richardson extrapolation.
+    ///
+    /// The entries of `sequence` are approximations `A(h)` computed with steps that shrink by `ratio` from one
+    /// entry to the next, whose error has the form `A(h) - A = c₁ h^p + c₂ h^(p+q) + c₃ h^(p+2q) + ...`
+    /// with `p = order` and `q = increment`. Each column of the tableau eliminates one of these terms.
+    /// For example, the trapezoid rule with halved steps has `ratio = 2`, `order = 2` and `increment = 2`.
+    ///
+    /// # Parameters
+    ///
+    /// - `sequence`: The approximations, starting from the largest step.
+    /// - `ratio`: The factor by which the step shrinks between successive approximations.
+    /// - `order`: The exponent of the leading error term.
+    /// - `increment`: The difference between the exponents of successive error terms.
+    ///
+    /// # Returns
+    ///
+    /// An `ExtrapolationResult` with the last diagonal entry of the tableau and its difference to the previous diagonal entry as an error estimate (infinite for a single approximation).
+    ///
+    /// # Panics
+    ///
+    /// If `sequence` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// // Forward differences of e^x at 0 with steps 0.1, 0.05 and 0.025 have an error of order h, h², ...
+    /// let sequence: Vec<f64> = [0.1_f64, 0.05, 0.025]
+    ///     .iter()
+    ///     .map(|h| (h.exp() - 1_f64) / h)
+    ///     .collect();
+    ///
+    /// let limit = Functions::richardson_extrapolation(&sequence, 2_f64, 1_f64, 1_f64);
+    ///
+    /// println!("The derivative of e^x at 0 is: {} ± {}", limit.value, limit.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn richardson_extrapolation(
+        sequence: &[f64],
+        ratio: f64,
+        order: f64,
+        increment: f64,
+    ) -> ExtrapolationResult {
+        Extrapolation::richardson(sequence, ratio, order, increment)
+    }
+
+    /// Uses double-exponential quadrature to calculate a definite integral over a finite or infinite interval.
+    ///
+    /// The rule is chosen from the limits: tanh-sinh for `[a, b]`, exp-sinh for `[a, ∞)` and `(-∞, b]`,
+    /// and sinh-sinh for `(-∞, ∞)`. Each substitution makes the integrand decay double-exponentially, so
+    /// the trapezoidal rule converges quickly even for integrable endpoint singularities. The step is
+    /// halved until two successive estimates agree, for at most 8 levels.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
+    /// - `lower_limit`: The lower limit of integration, which may be infinite.
+    /// - `upper_limit`: The upper limit of integration, which may be infinite.
+    /// - `tolerance`: The requested accuracy (ie: `1e-10`); the error target is `max(tolerance, tolerance * |integral|)`.
+    ///
+    /// # Returns
+    ///
+    /// An `IntegrationResult` with the integral, the difference between the last two estimates, the number of evaluations and whether the tolerance was reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let lower_bound = f64::NEG_INFINITY;
+    /// let upper_bound = f64::INFINITY;
+    /// let tolerance = 1e-12;
+    /// let function = |x: f64| (-x * x).exp();
+    ///
+    /// let integral = Functions::double_exponential(function, lower_bound, upper_bound, tolerance);
+    ///
+    /// println!("The Integral of e^(-x^2) at (-∞,∞) is: {}", integral.value)
+    /// ```
+    /// <hr/>
+    pub fn double_exponential<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+    ) -> IntegrationResult {
+        DoubleExponential::integrate(function, lower_limit, upper_limit, tolerance)
+    }
+
+    /// Computes the nodes and weights of an `n` point Gaussian quadrature rule.
+    ///
+    /// The nodes are the eigenvalues of the Jacobi matrix of the orthogonal polynomials (Golub-Welsch),
+    /// polished with Newton's method, and the weights are the Christoffel numbers at the nodes. The
+    /// Chebyshev rules use their closed forms. The rule is exact for polynomials of degree `2n - 1`
+    /// multiplied by the weight function of the rule.
+    ///
+    /// # Parameters
+    ///
+    /// - `rule`: The Gaussian rule, which determines the weight function and the interval.
+    /// - `n`: The number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// The nodes in ascending order and their weights.
+    ///
+    /// # Panics
+    ///
+    /// If a Laguerre or Jacobi parameter is not greater than `-1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, GaussRule};
+    ///
+    /// let (nodes, weights) = Functions::gauss_nodes(GaussRule::Legendre, 3);
+    ///
+    /// println!("The nodes are: {:?}", nodes);
+    /// println!("The weights are: {:?}", weights);
+    /// ```
+    /// <hr/>
+    pub fn gauss_nodes(rule: GaussRule, n: usize) -> (Vec<f64>, Vec<f64>) {
+        GaussQuadrature::nodes(rule, n)
+    }
+
+    /// Uses `n` point Gaussian quadrature to calculate the integral of a function against the weight function of a rule.
+    ///
+    /// The integrals are `∫ w(x) f(x) dx` over the interval of the rule, ie: `∫ e^(-x²) f(x) dx` over `(-∞, ∞)`
+    /// for `GaussRule::Hermite`, so the weight function must not be included in `function`.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
+    /// - `rule`: The Gaussian rule, which determines the weight function and the interval.
+    /// - `n`: The number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// The calculated integral using Gaussian quadrature.
+    ///
+    /// # Panics
+    ///
+    /// If a Laguerre or Jacobi parameter is not greater than `-1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, GaussRule};
+    ///
+    /// let function = |x: f64| x.powi(2);
+    ///
+    /// let integral = Functions::gauss_quadrature(function, GaussRule::Hermite, 20);
+    ///
+    /// println!("The Integral of x^2 e^(-x^2) at (-∞,∞) is: {}", integral)
+    /// ```
+    /// <hr/>
+    pub fn gauss_quadrature<F: Fn(f64) -> f64>(function: F, rule: GaussRule, n: usize) -> f64 {
+        GaussQuadrature::integrate(function, rule, n)
+    }
+
+    /// Uses `n` point Gauss-Legendre quadrature to calculate a definite integral.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be integrated.
+    /// - `lower_limit`: The lower limit of integration.
+    /// - `upper_limit`: The upper limit of integration.
+    /// - `n`: The number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// The calculated definite integral using Gauss-Legendre quadrature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let lower_bound = 0_f64;
+    /// let upper_bound = 6_f64;
+    /// let function = |x: f64| x.powi(2);
+    ///
+    /// let integral = Functions::gauss_legendre(function, lower_bound, upper_bound, 2);
+    ///
+    /// println!("The Integral of x^2 at [0,6] is: {}", integral)
+    /// ```
+    /// <hr/>
+    pub fn gauss_legendre<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        n: usize,
+    ) -> f64 {
+        GaussQuadrature::legendre(function, lower_limit, upper_limit, n)
+    }
+
+    /// Uses nested (tensor product) Gauss-Legendre quadrature to calculate an integral over a box or a simplex.
+    ///
+    /// The rule uses `n^d` evaluations in `d` dimensions, so it is meant for low dimensions and smooth integrands.
+    /// A simplex is mapped onto the unit cube with the Duffy transformation, whose Jacobian is included in the integrand.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `f64` coordinates and returns an `f64`. This is the function to be integrated.
+    /// - `region`: The box or simplex of integration.
+    /// - `n`: The number of nodes along each axis.
+    ///
+    /// # Returns
+    ///
+    /// The calculated integral using nested Gauss-Legendre quadrature.
+    ///
+    /// # Panics
+    ///
+    /// If the limits of a box have different lengths, or the vertices of a simplex do not have one coordinate fewer than their number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Region};
+    ///
+    /// let region = Region::Cuboid { lower: vec![0_f64, 0_f64], upper: vec![1_f64, 2_f64] };
+    /// let function = |x: &[f64]| x[0] * x[1];
+    ///
+    /// let integral = Functions::nested_gauss(function, &region, 5);
+    ///
+    /// println!("The Integral of xy over [0,1]×[0,2] is: {}", integral)
+    /// ```
+    /// <hr/>
+    pub fn nested_gauss<F: Fn(&[f64]) -> f64>(function: F, region: &Region, n: usize) -> f64 {
+        Cubature::nested_gauss(function, region, n)
+    }
+
+    /// Uses adaptive Genz-Malik cubature to calculate an integral over a box or a simplex.
+    ///
+    /// Each subregion is integrated with the degree 7 Genz-Malik rule and its embedded degree 5 rule, whose
+    /// difference is the error estimate. The subregion with the largest error is bisected along the axis
+    /// where the integrand has the largest fourth difference, until the total error is below the tolerance.
+    /// A rule costs `2^d + 2d² + 2d + 1` evaluations in `d` dimensions.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `f64` coordinates and returns an `f64`. This is the function to be integrated.
+    /// - `region`: The box or simplex of integration, in at least 2 dimensions.
+    /// - `tolerance`: The requested accuracy (ie: `1e-8`); the error target is `max(tolerance, tolerance * |integral|)`.
+    /// - `max_evaluations`: The maximum number of evaluations of the integrand.
+    ///
+    /// # Returns
+    ///
+    /// An `IntegrationResult` with the integral, an estimate of its absolute error, the number of evaluations and whether the tolerance was reached.
+    ///
+    /// # Panics
+    ///
+    /// If the region has fewer than 2 dimensions, or is malformed as described in [`Functions::nested_gauss`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Region};
+    ///
+    /// let region = Region::Simplex { vertices: vec![vec![0_f64, 0_f64], vec![1_f64, 0_f64], vec![0_f64, 1_f64]] };
+    /// let function = |x: &[f64]| (x[0] + x[1]).exp();
+    ///
+    /// let integral = Functions::genz_malik(function, &region, 1e-10, 100_000);
+    ///
+    /// println!("The Integral of e^(x+y) over the unit triangle is: {} ± {}", integral.value, integral.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn genz_malik<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        tolerance: f64,
+        max_evaluations: usize,
+    ) -> IntegrationResult {
+        Cubature::genz_malik(function, region, tolerance, max_evaluations)
+    }
+
+    /// Uses plain Monte Carlo sampling to calculate an integral over a box or a simplex.
+    ///
+    /// Uniform pseudo-random points are drawn in batches of 1024 until the standard error of the mean,
+    /// `σ/√N`, is below the tolerance. The same seed always gives the same result.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `f64` coordinates and returns an `f64`. This is the function to be integrated.
+    /// - `region`: The box or simplex of integration.
+    /// - `tolerance`: The requested accuracy (ie: `1e-3`); the error target is `max(tolerance, tolerance * |integral|)`.
+    /// - `max_evaluations`: The maximum number of evaluations of the integrand.
+    /// - `seed`: The seed of the pseudo-random number generator.
+    ///
+    /// # Returns
+    ///
+    /// An `IntegrationResult` with the integral, its standard error, the number of evaluations and whether the tolerance was reached.
+    ///
+    /// # Panics
+    ///
+    /// If the region is malformed as described in [`Functions::nested_gauss`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Region};
+    ///
+    /// let region = Region::Cuboid { lower: vec![-1_f64; 3], upper: vec![1_f64; 3] };
+    /// let function = |x: &[f64]| if x.iter().map(|v| v * v).sum::<f64>() <= 1_f64 { 1_f64 } else { 0_f64 };
+    ///
+    /// let integral = Functions::monte_carlo(function, &region, 1e-3, 1_000_000, 42);
+    ///
+    /// println!("The volume of the unit ball is: {} ± {}", integral.value, integral.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn monte_carlo<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        tolerance: f64,
+        max_evaluations: usize,
+        seed: u64,
+    ) -> IntegrationResult {
+        Cubature::monte_carlo(function, region, tolerance, max_evaluations, seed)
+    }
+
+    /// Uses randomized quasi-Monte Carlo integration to calculate an integral over a box or a simplex.
+    ///
+    /// The points of a Sobol or Halton sequence are shifted by 16 independent random vectors (modulo 1), and
+    /// the standard error of the 16 estimates is the error estimate. The number of points is doubled until the
+    /// error is below the tolerance. For smooth integrands the error decreases almost like `1/N`, instead of
+    /// the `1/√N` of plain Monte Carlo.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `f64` coordinates and returns an `f64`. This is the function to be integrated.
+    /// - `region`: The box or simplex of integration.
+    /// - `sequence`: The low-discrepancy sequence.
+    /// - `tolerance`: The requested accuracy (ie: `1e-6`); the error target is `max(tolerance, tolerance * |integral|)`.
+    /// - `max_evaluations`: The maximum number of evaluations of the integrand. At least one point is evaluated
+    ///   for every shift, so a budget below 16 still evaluates the integrand 16 times.
+    /// - `seed`: The seed of the pseudo-random number generator for the shifts.
+    ///
+    /// # Returns
+    ///
+    /// An `IntegrationResult` with the integral, its standard error, the number of evaluations and whether the tolerance was reached.
+    ///
+    /// # Panics
+    ///
+    /// If the Sobol sequence is used in more than 10 dimensions, or the region is malformed as described in [`Functions::nested_gauss`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, QuasiRandomSequence, Region};
+    ///
+    /// let region = Region::Cuboid { lower: vec![0_f64; 6], upper: vec![1_f64; 6] };
+    /// let function = |x: &[f64]| x.iter().map(|v| v.cos()).product::<f64>();
+    ///
+    /// let integral = Functions::quasi_monte_carlo(function, &region, QuasiRandomSequence::Sobol, 1e-6, 1_000_000, 42);
+    ///
+    /// println!("The Integral is: {} ± {}", integral.value, integral.abs_error)
+    /// ```
+    /// <hr/>
+    pub fn quasi_monte_carlo<F: Fn(&[f64]) -> f64>(
+        function: F,
+        region: &Region,
+        sequence: QuasiRandomSequence,
+        tolerance: f64,
+        max_evaluations: usize,
+        seed: u64,
+    ) -> IntegrationResult {
+        Cubature::quasi_monte_carlo(function, region, sequence, tolerance, max_evaluations, seed)
+    }
+
+    /// Summations in Rust.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: The starting value for the summation.
+    /// - `limit`: The ending value for the summation.
+    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be summed.
+    ///
+    /// # Returns
+    ///
+    /// The sum of applying the function to each value in the range `[start, limit]`.
+    ///
+    /// # Example #1: Constant
     ///
     /// ```rust
     /// use numerilib::Functions;
     ///
-    /// let start = 0_f64;
-    /// let limit = 9_f64;
-    /// let function = |x: f64| 3_f64;    
+    /// let start = 0_f64;
+    /// let limit = 9_f64;
+    /// let function = |x: f64| 3_f64;    
+    ///
+    /// let summation = Functions::summation(start, limit, function);    ///
+    ///
+    /// println!("The summation of the constant 3 from [0,9] is: {}", summation);
+    /// ```
+    ///
+    /// # Example #2: Function
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let start = 4.5;    
+    /// let limit = 100_f64;    
+    /// let function = |x: f64| 1_f64 / x;
+    ///
+    /// let summation = Functions::summation(start, limit, function);
+    ///
+    /// println!("The summation of the function 1/x from [4.5,100] is: {}", summation);
+    /// ```
+    /// <hr/>
+    pub fn summation<F: Fn(f64) -> f64>(start: f64, limit: f64, f: F) -> f64 {
+        let mut result = 0.0;
+        let mut x = start;
+
+        while x <= limit {
+            result += f(x);
+            x += 1.0; // You can adjust the step size if needed
+        }
+
+        result
+    }
+
+    /// Calculates the product of a function. a.k.a Capital Pi Notation.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: The starting value for the product.
+    /// - `limit`: The ending value for the product.
+    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be multiplied in the product.
+    ///
+    /// # Returns
+    ///
+    /// The product of applying the function to each value in the range `[start, limit]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let start = 2_f64;
+    /// let limit = 7_f64;
+    /// let f = |x: f64| 3_f64;
+    ///
+    /// let product_series = Functions::product(start, limit, f);
+    ///
+    /// println!("The Product Series of the Constant 3 from [2,7] is: {}", product_series);
+    /// ```
+    ///
+    /// # Example #2: Function
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let start = 3_f64;
+    /// let limit = 7_f64;
+    /// let function = |x: f64| x.powi(2);
+    ///
+    /// let summation = Functions::product(start, limit, function);
+    ///
+    /// println!("The Product Series of the Function x^2 from [3,7] is: {}", summation);
+    /// ```
+    /// <hr/>
+    pub fn product<F: Fn(f64) -> f64>(start: f64, limit: f64, f: F) -> f64 {
+        let mut result = 1.0;
+        let mut x = start;
+
+        while x <= limit {
+            result *= f(x);
+            x += 1.0; // You can adjust the step size if needed
+        }
+
+        result
+    }
+
+    /// Finds a root of a function in a bracket with the bisection method.
+    ///
+    /// The bracket is halved until it is smaller than the tolerance, which takes about `log2((upper - lower) / tolerance)`
+    /// iterations for any continuous function.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `lower`: One end of the bracket.
+    /// - `upper`: The other end of the bracket, where the function has the opposite sign.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the root is not bracketed or the function is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+    ///
+    /// let result = Functions::bisection(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of x^3-2x-5 in [2,3] is: {}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn bisection<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::bisection(function, lower, upper, tolerance)
+    }
+
+    /// Finds a root of a function in a bracket with Brent's method.
+    ///
+    /// Inverse quadratic interpolation and secant steps are used while they shrink the bracket fast enough,
+    /// and bisection otherwise, so the method converges superlinearly for smooth functions and is never much slower than bisection.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `lower`: One end of the bracket.
+    /// - `upper`: The other end of the bracket, where the function has the opposite sign.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the root is not bracketed or the function is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+    ///
+    /// let result = Functions::brent(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of x^3-2x-5 in [2,3] is: {}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn brent<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::brent(function, lower, upper, tolerance)
+    }
+
+    /// Finds a root of a function in a bracket with Ridders' method.
+    ///
+    /// Each iteration evaluates the midpoint and fits an exponential through the bracket, whose root is
+    /// the next estimate. The convergence is quadratic and the estimate always stays in the bracket.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `lower`: One end of the bracket.
+    /// - `upper`: The other end of the bracket, where the function has the opposite sign.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the root is not bracketed or the function is not finite.
+    ///
+    /// # Example
     ///
-    /// let summation = Functions::summation(start, limit, function);    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
     ///
-    /// println!("The summation of the constant 3 from [0,9] is: {}", summation);
+    /// let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+    ///
+    /// let result = Functions::ridders(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of x^3-2x-5 in [2,3] is: {}", result.root);
     /// ```
+    /// <hr/>
+    pub fn ridders<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::ridders(function, lower, upper, tolerance)
+    }
+
+    /// Finds a root of a function in a bracket with the Illinois variant of regula falsi.
     ///
-    /// # Example #2: Function
+    /// The estimate is the root of the secant through the ends of the bracket, and the function value at an end that
+    /// is retained twice in a row is halved, which avoids the slow one-sided convergence of plain regula falsi.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `lower`: One end of the bracket.
+    /// - `upper`: The other end of the bracket, where the function has the opposite sign.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the root is not bracketed or the function is not finite.
+    ///
+    /// # Example
     ///
     /// ```rust
-    /// use numerilib::Functions;
+    /// use numerilib::{Functions, RootTolerance};
     ///
-    /// let start = 4.5;    
-    /// let limit = 100_f64;    
-    /// let function = |x: f64| 1_f64 / x;
+    /// let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
     ///
-    /// let summation = Functions::summation(start, limit, function);
+    /// let result = Functions::illinois(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
     ///
-    /// println!("The summation of the function 1/x from [4.5,100] is: {}", summation);
+    /// println!("The root of x^3-2x-5 in [2,3] is: {}", result.root);
     /// ```
     /// <hr/>
-    pub fn summation<F: Fn(f64) -> f64>(start: f64, limit: f64, f: F) -> f64 {
-        let mut result = 0.0;
-        let mut x = start;
+    pub fn illinois<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::illinois(function, lower, upper, tolerance)
+    }
 
-        while x <= limit {
-            result += f(x);
-            x += 1.0; // You can adjust the step size if needed
-        }
+    /// Finds a root of a function in a bracket with the ITP (Interpolate, Truncate and Project) method.
+    ///
+    /// A regula falsi estimate is truncated towards the midpoint and projected into an interval around it, so the
+    /// method never needs more than one iteration more than bisection while converging superlinearly for smooth functions.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `lower`: One end of the bracket.
+    /// - `upper`: The other end of the bracket, where the function has the opposite sign.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the root is not bracketed or the function is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+    ///
+    /// let result = Functions::itp(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of x^3-2x-5 in [2,3] is: {}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn itp<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::itp(function, lower, upper, tolerance)
+    }
 
-        result
+    /// Finds a root of a function with the secant method.
+    ///
+    /// The derivative of Newton's method is replaced by the slope through the last two estimates. No bracket is
+    /// needed, but the iteration may diverge for poor starting values.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `x0`: The first starting value.
+    /// - `x1`: The second starting value.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the function is not finite or the slope vanishes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: f64| x.cos() - x;
+    ///
+    /// let result = Functions::secant(function, 0_f64, 1_f64, RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of cos(x)-x is: {}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn secant<F: Fn(f64) -> f64>(
+        function: F,
+        x0: f64,
+        x1: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::secant(function, x0, x1, tolerance)
     }
 
-    /// Calculates the product of a function. a.k.a Capital Pi Notation.
+    /// Finds a root of a function with Steffensen's method.
+    ///
+    /// The derivative of Newton's method is replaced by the slope `(f(x + f(x)) - f(x)) / f(x)`, which gives
+    /// quadratic convergence without derivatives once the estimate is close to the root.
     ///
     /// # Parameters
     ///
-    /// - `start`: The starting value for the product.
-    /// - `limit`: The ending value for the product.
-    /// - `f`: A function that takes a single `f64` argument and returns an `f64`. This is the function to be multiplied in the product.
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function for which the root is found.
+    /// - `guess`: An initial guess for the root.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
     ///
     /// # Returns
     ///
-    /// The product of applying the function to each value in the range `[start, limit]`.
+    /// A `RootResult` with the root, the function value there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `RootError` if the function is not finite or the slope vanishes.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use numerilib::Functions;
+    /// use numerilib::{Functions, RootTolerance};
     ///
-    /// let start = 2_f64;
-    /// let limit = 7_f64;
-    /// let f = |x: f64| 3_f64;
+    /// let function = |x: f64| x.powi(2) - 2_f64;
     ///
-    /// let product_series = Functions::product(start, limit, f);
+    /// let result = Functions::steffensen(function, 1.5, RootTolerance::default()).unwrap();
     ///
-    /// println!("The Product Series of the Constant 3 from [2,7] is: {}", product_series);
+    /// println!("The root of x^2-2 is: {}", result.root);
     /// ```
+    /// <hr/>
+    pub fn steffensen<F: Fn(f64) -> f64>(
+        function: F,
+        guess: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        RootFinding::steffensen(function, guess, tolerance)
+    }
+
+    /// Solves a system of nonlinear equations `F(x) = 0` with Newton's method and a finite difference Jacobian.
     ///
-    /// # Example #2: Function
+    /// The Jacobian is approximated with central differences and every step solves the linear system with the
+    /// LU decomposition of a `Matrix`. The step is halved until the residual norm `|F(x)|` decreases, which
+    /// keeps the iteration from diverging when the guess is poor.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `N` values and returns the `N` values of `F`.
+    /// - `guess`: The starting point.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`. The absolute and relative tolerances apply to the norm of the step,
+    ///   and `residual` to the norm of `F`.
+    ///
+    /// # Returns
+    ///
+    /// A `SystemResult` with the root, the value of `F` and its norm there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `SystemError` if `F` is not finite or the Jacobian is singular.
+    ///
+    /// # Panics
+    ///
+    /// If `function` does not return `N` values.
+    ///
+    /// # Example
     ///
     /// ```rust
-    /// use numerilib::Functions;
+    /// use numerilib::{Functions, RootTolerance};
     ///
-    /// let start = 3_f64;
-    /// let limit = 7_f64;
-    /// let function = |x: f64| x.powi(2);
+    /// let function = |x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4_f64, x[0] - x[1]];
     ///
-    /// let summation = Functions::product(start, limit, function);
+    /// let result = Functions::newton_system(function, &[1_f64, 2_f64], RootTolerance::default()).unwrap();
     ///
-    /// println!("The Product Series of the Function x^2 from [3,7] is: {}", summation);
+    /// println!("The intersection of the circle and the line is: {:?}", result.root);
     /// ```
     /// <hr/>
-    pub fn product<F: Fn(f64) -> f64>(start: f64, limit: f64, f: F) -> f64 {
-        let mut result = 1.0;
-        let mut x = start;
+    pub fn newton_system<const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        function: F,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        NonlinearSystems::newton(
+            function,
+            None::<fn(&[f64]) -> Matrix<N, N>>,
+            guess,
+            tolerance,
+        )
+    }
 
-        while x <= limit {
-            result *= f(x);
-            x += 1.0; // You can adjust the step size if needed
-        }
+    /// Solves a system of nonlinear equations `F(x) = 0` with Newton's method and an analytic Jacobian.
+    ///
+    /// This is [`Functions::newton_system`] with the Jacobian given by a function instead of finite differences,
+    /// which saves `2N` evaluations of `F` per iteration and keeps the quadratic convergence up to machine precision.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `N` values and returns the `N` values of `F`.
+    /// - `jacobian`: A function that takes a slice of `N` values and returns the Jacobian of `F` as a `Matrix`.
+    /// - `guess`: The starting point.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `SystemResult` with the root, the value of `F` and its norm there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `SystemError` if `F` is not finite or the Jacobian is singular.
+    ///
+    /// # Panics
+    ///
+    /// If `function` does not return `N` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, Matrix, RootTolerance};
+    ///
+    /// let function = |x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4_f64, x[0] - x[1]];
+    /// let jacobian = |x: &[f64]| Matrix::new([[2_f64 * x[0], 2_f64 * x[1]], [1_f64, -1_f64]]);
+    ///
+    /// let result =
+    ///     Functions::newton_system_jacobian(function, jacobian, &[1_f64, 2_f64], RootTolerance::default()).unwrap();
+    ///
+    /// println!("The intersection of the circle and the line is: {:?}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn newton_system_jacobian<
+        const N: usize,
+        F: Fn(&[f64]) -> Vec<f64>,
+        J: Fn(&[f64]) -> Matrix<N, N>,
+    >(
+        function: F,
+        jacobian: J,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        NonlinearSystems::newton(function, Some(jacobian), guess, tolerance)
+    }
 
-        result
+    /// Solves a system of nonlinear equations `F(x) = 0` with Broyden's quasi-Newton method.
+    ///
+    /// The Jacobian is approximated with finite differences once at the guess and then updated with a rank one
+    /// correction after every step, so each iteration costs a single evaluation of `F`. The convergence is
+    /// superlinear instead of quadratic and the guess should be close to the root.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `N` values and returns the `N` values of `F`.
+    /// - `guess`: The starting point.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `SystemResult` with the root, the value of `F` and its norm there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `SystemError` if `F` is not finite or the Jacobian is singular.
+    ///
+    /// # Panics
+    ///
+    /// If `function` does not return `N` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4_f64, x[0] - x[1]];
+    ///
+    /// let result = Functions::broyden(function, &[1_f64, 2_f64], RootTolerance::default()).unwrap();
+    ///
+    /// println!("The intersection of the circle and the line is: {:?}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn broyden<const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        function: F,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        NonlinearSystems::broyden(function, guess, tolerance)
+    }
+
+    /// Solves a system of nonlinear equations `F(x) = 0` with Powell's dogleg trust region method.
+    ///
+    /// Each step minimizes the linear model `|F(x) + J p|` within a trust region, moving along the steepest descent
+    /// direction and then towards the Newton step. The radius adapts to how well the model predicts the decrease
+    /// of the residual, which makes the method more robust than [`Functions::newton_system`] far from the root.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a slice of `N` values and returns the `N` values of `F`.
+    /// - `guess`: The starting point.
+    /// - `tolerance`: The stopping criteria, ie: `RootTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `SystemResult` with the root, the value of `F` and its norm there, the number of iterations and evaluations and whether the tolerance was reached,
+    /// or a `SystemError` if `F` is not finite at the guess or the iteration reached a stationary point of `|F|` that is not a root.
+    ///
+    /// # Panics
+    ///
+    /// If `function` does not return `N` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, RootTolerance};
+    ///
+    /// let function = |x: &[f64]| vec![10_f64 * (x[1] - x[0].powi(2)), 1_f64 - x[0]];
+    ///
+    /// let result = Functions::dogleg(function, &[-1.2, 1_f64], RootTolerance::default()).unwrap();
+    ///
+    /// println!("The root of the Rosenbrock system is: {:?}", result.root);
+    /// ```
+    /// <hr/>
+    pub fn dogleg<const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        function: F,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        NonlinearSystems::dogleg(function, guess, tolerance)
     }
 
     /// A rust implementation of the Newton–Raphson method for finding roots.
     ///
+    /// The iteration runs for at most 200 steps and returns the last guess even when it did not converge.
+    /// Use [`crate::Newton`] to supply derivatives, tolerances or damping and to find out whether it converged.
+    ///
     /// # Parameters
     ///
     /// - `guess`: An initial guess for the root.
@@ -436,4 +1786,155 @@ impl Functions {
 
         guess
     }
+
+    /// Finds a minimum of a function in an interval with the golden section search.
+    ///
+    /// Every iteration shrinks the interval by the factor `0.618` with one evaluation, whatever the
+    /// function, so the search is slow but reliable for any unimodal function, even if it is not smooth.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function that is minimized.
+    /// - `lower`: One end of the interval.
+    /// - `upper`: The other end of the interval.
+    /// - `tolerance`: The stopping criteria, ie: `MinimumTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `MinimumResult` with the minimizer, the minimum, the length of the final interval, the number of iterations and evaluations and whether the
+    /// tolerance was reached, or a `MinimizationError` if the function is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, MinimumTolerance};
+    ///
+    /// let function = |x: f64| (x - 2_f64).abs() + 1_f64;
+    ///
+    /// let result = Functions::golden_section(function, 0_f64, 5_f64, MinimumTolerance::default()).unwrap();
+    ///
+    /// println!("The minimum of |x-2|+1 is {} at x = {}", result.minimum, result.argmin);
+    /// ```
+    /// <hr/>
+    pub fn golden_section<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        Minimization::golden_section(function, lower, upper, tolerance)
+    }
+
+    /// Finds a minimum of a function in an interval with Brent's method.
+    ///
+    /// Parabolic interpolation through the three best points is used while it makes progress, and golden section steps otherwise,
+    /// so the method converges superlinearly for smooth functions and is never much slower than the golden section search.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function that is minimized.
+    /// - `lower`: One end of the interval.
+    /// - `upper`: The other end of the interval.
+    /// - `tolerance`: The stopping criteria, ie: `MinimumTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `MinimumResult` with the minimizer, the minimum, the length of the final interval, the number of iterations and evaluations and whether the
+    /// tolerance was reached, or a `MinimizationError` if the function is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, MinimumTolerance};
+    ///
+    /// // The negative log-likelihood of an exponential rate λ for a sample with mean 2.
+    /// let function = |rate: f64| -rate.ln() + 2_f64 * rate;
+    ///
+    /// let result = Functions::brent_minimize(function, 0.01, 10_f64, MinimumTolerance::default()).unwrap();
+    ///
+    /// println!("The maximum likelihood estimate of λ is: {}", result.argmin);
+    /// ```
+    /// <hr/>
+    pub fn brent_minimize<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        Minimization::brent(function, lower, upper, tolerance)
+    }
+
+    /// Finds three points that bracket a minimum of a function, starting from two points.
+    ///
+    /// The search walks downhill from `a` and `b` with steps that grow by the golden ratio, or jumps to the minimum of the parabola through
+    /// the last three points when it is not too far, until the function increases again.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function whose minimum is bracketed.
+    /// - `a`: The first starting point.
+    /// - `b`: The second starting point, which sets the initial step and direction together with `a`.
+    ///
+    /// # Returns
+    ///
+    /// A `MinimumBracket` with `lower < middle < upper` and `f(middle)` not larger than the values at the ends, or a `MinimizationError`
+    /// if the function is not finite or keeps decreasing for 100 expansions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Functions;
+    ///
+    /// let function = |x: f64| (x - 50_f64).powi(2);
+    ///
+    /// let bracket = Functions::bracket_minimum(function, 0_f64, 1_f64).unwrap();
+    ///
+    /// println!("The minimum is between {} and {}", bracket.lower, bracket.upper);
+    /// ```
+    /// <hr/>
+    pub fn bracket_minimum<F: Fn(f64) -> f64>(
+        function: F,
+        a: f64,
+        b: f64,
+    ) -> Result<MinimumBracket, MinimizationError> {
+        Minimization::bracket(function, a, b)
+    }
+
+    /// Finds a local minimum of a function from two starting points, without an interval.
+    ///
+    /// The minimum is bracketed with `Functions::bracket_minimum` and then refined with Brent's method.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: A function that takes a single `f64` argument and returns an `f64`. This is the function that is minimized.
+    /// - `a`: The first starting point.
+    /// - `b`: The second starting point, which sets the initial step and direction together with `a`.
+    /// - `tolerance`: The stopping criteria, ie: `MinimumTolerance::default()`.
+    ///
+    /// # Returns
+    ///
+    /// A `MinimumResult` with the minimizer, the minimum, the length of the final interval, the number of iterations and evaluations (including
+    /// those of the bracket search) and whether the tolerance was reached, or a `MinimizationError` if the function is not finite or no minimum
+    /// is bracketed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Functions, MinimumTolerance};
+    ///
+    /// let function = |x: f64| x.cosh() - x;
+    ///
+    /// let result = Functions::minimize(function, 0_f64, 0.1, MinimumTolerance::default()).unwrap();
+    ///
+    /// println!("The minimum of cosh(x)-x is at x = {}", result.argmin);
+    /// ```
+    /// <hr/>
+    pub fn minimize<F: Fn(f64) -> f64>(
+        function: F,
+        a: f64,
+        b: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        Minimization::minimize(function, a, b, tolerance)
+    }
 }
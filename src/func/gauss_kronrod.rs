@@ -0,0 +1,637 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// Nodes and weights of the 15 point Kronrod rule and of the embedded 7 point Gauss rule (QUADPACK QK15).
+// The Gauss nodes are the odd entries of `K15_NODES`.
+const K15_NODES: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+    0.0,
+];
+
+const K15_WEIGHTS: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+
+const G7_WEIGHTS: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+// Nodes and weights of the 21 point Kronrod rule and of the embedded 10 point Gauss rule (QUADPACK QK21).
+const K21_NODES: [f64; 11] = [
+    0.9956571630258081,
+    0.9739065285171717,
+    0.9301574913557082,
+    0.8650633666889845,
+    0.7808177265864169,
+    0.6794095682990244,
+    0.5627571346686047,
+    0.4333953941292472,
+    0.2943928627014602,
+    0.14887433898163122,
+    0.0,
+];
+
+const K21_WEIGHTS: [f64; 11] = [
+    0.011694638867371874,
+    0.032558162307964725,
+    0.054755896574351995,
+    0.07503967481091996,
+    0.0931254545836976,
+    0.10938715880229764,
+    0.12349197626206584,
+    0.13470921731147334,
+    0.14277593857706009,
+    0.14773910490133849,
+    0.1494455540029169,
+];
+
+const G10_WEIGHTS: [f64; 5] = [
+    0.06667134430868814,
+    0.1494513491505806,
+    0.21908636251598204,
+    0.26926671930999635,
+    0.29552422471475287,
+];
+
+/// The result of a numerical integration together with an estimate of its error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntegrationResult {
+    /// The estimated value of the integral.
+    pub value: f64,
+    /// An estimate of the absolute error of `value`.
+    pub abs_error: f64,
+    /// The number of times the integrand was evaluated.
+    pub evaluations: usize,
+    /// Whether the requested tolerance was reached.
+    pub converged: bool,
+}
+
+/// The pair of embedded Gauss-Kronrod rules applied to each subinterval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KronrodRule {
+    /// The 15 point Kronrod rule with the embedded 7 point Gauss rule.
+    G7K15,
+    /// The 21 point Kronrod rule with the embedded 10 point Gauss rule.
+    G10K21,
+}
+
+impl KronrodRule {
+    fn points(&self) -> usize {
+        match self {
+            KronrodRule::G7K15 => 15,
+            KronrodRule::G10K21 => 21,
+        }
+    }
+}
+
+// The Kronrod estimate of a subinterval with its error estimate, and the integrals of |f| and |f - mean|
+// which are used to detect rounding errors.
+struct RuleEstimate {
+    value: f64,
+    error: f64,
+    abs_value: f64,
+    abs_deviation: f64,
+}
+
+struct Subinterval {
+    lower: f64,
+    upper: f64,
+    value: f64,
+    error: f64,
+    level: usize,
+}
+
+impl PartialEq for Subinterval {
+    fn eq(&self, other: &Self) -> bool {
+        self.error.total_cmp(&other.error) == Ordering::Equal
+    }
+}
+
+impl Eq for Subinterval {}
+
+impl PartialOrd for Subinterval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Subinterval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error.total_cmp(&other.error)
+    }
+}
+
+// The table of Wynn's epsilon algorithm, which extrapolates the sequence of integral estimates.
+struct EpsilonTable {
+    estimates: [f64; EpsilonTable::SIZE + 2],
+    length: usize,
+    last_results: [f64; 3],
+    results: usize,
+}
+
+impl EpsilonTable {
+    const SIZE: usize = 50;
+
+    fn new() -> Self {
+        EpsilonTable {
+            estimates: [0.0; EpsilonTable::SIZE + 2],
+            length: 0,
+            last_results: [0.0; 3],
+            results: 0,
+        }
+    }
+
+    fn push(&mut self, estimate: f64) {
+        if self.length < EpsilonTable::SIZE {
+            self.estimates[self.length] = estimate;
+            self.length += 1;
+        }
+    }
+
+    // QUADPACK's QELG: returns the extrapolated value and its error estimate.
+    fn extrapolate(&mut self) -> (f64, f64) {
+        let epsilon = f64::EPSILON;
+        let table = &mut self.estimates;
+        let n = self.length - 1;
+        let current = table[n];
+
+        if n < 2 {
+            return (current, f64::MAX);
+        }
+
+        let new_elements = n / 2;
+        let mut final_n = n;
+        let mut result = current;
+        let mut abs_error = f64::MAX;
+
+        table[n + 2] = table[n];
+        table[n] = f64::MAX;
+
+        for i in 0..new_elements {
+            let mut res = table[n - 2 * i + 2];
+            let e0 = table[n - 2 * i - 2];
+            let e1 = table[n - 2 * i - 1];
+            let e2 = res;
+
+            let delta2 = e2 - e1;
+            let err2 = delta2.abs();
+            let tol2 = e2.abs().max(e1.abs()) * epsilon;
+            let delta3 = e1 - e0;
+            let err3 = delta3.abs();
+            let tol3 = e1.abs().max(e0.abs()) * epsilon;
+
+            if err2 < tol2 && err3 < tol3 {
+                // e0, e1 and e2 are equal to machine accuracy, so convergence is assumed
+                let abs_error = (err2 + err3).max(5.0 * epsilon * res.abs());
+                return (res, abs_error);
+            }
+
+            let e3 = table[n - 2 * i];
+            table[n - 2 * i] = e1;
+            let delta1 = e1 - e3;
+            let err1 = delta1.abs();
+            let tol1 = e1.abs().max(e3.abs()) * epsilon;
+
+            if err1 < tol1 || err2 < tol2 || err3 < tol3 {
+                final_n = 2 * i;
+                break;
+            }
+
+            let ss = 1.0 / delta1 + 1.0 / delta2 - 1.0 / delta3;
+
+            if (ss * e1).abs() <= 1e-4 {
+                final_n = 2 * i;
+                break;
+            }
+
+            res = e1 + 1.0 / ss;
+            table[n - 2 * i] = res;
+
+            let error = err2 + (res - e2).abs() + err3;
+            if error <= abs_error {
+                abs_error = error;
+                result = res;
+            }
+        }
+
+        if final_n == EpsilonTable::SIZE - 1 {
+            final_n = 2 * ((EpsilonTable::SIZE - 1) / 2);
+        }
+
+        if n % 2 == 1 {
+            for i in 0..=new_elements {
+                table[1 + i * 2] = table[i * 2 + 3];
+            }
+        } else {
+            for i in 0..=new_elements {
+                table[i * 2] = table[i * 2 + 2];
+            }
+        }
+
+        if n != final_n {
+            for i in 0..=final_n {
+                table[i] = table[n - final_n + i];
+            }
+        }
+
+        self.length = final_n + 1;
+
+        if self.results < 3 {
+            self.last_results[self.results] = result;
+            abs_error = f64::MAX;
+        } else {
+            abs_error = (result - self.last_results[2]).abs()
+                + (result - self.last_results[1]).abs()
+                + (result - self.last_results[0]).abs();
+            self.last_results = [self.last_results[1], self.last_results[2], result];
+        }
+        self.results += 1;
+
+        (result, abs_error.max(5.0 * epsilon * result.abs()))
+    }
+}
+
+pub struct GaussKronrod;
+
+impl GaussKronrod {
+    const MAX_SUBINTERVALS: usize = 1000;
+
+    fn apply<F: Fn(f64) -> f64>(
+        function: &F,
+        lower: f64,
+        upper: f64,
+        rule: KronrodRule,
+    ) -> RuleEstimate {
+        let (nodes, kronrod, gauss): (&[f64], &[f64], &[f64]) = match rule {
+            KronrodRule::G7K15 => (&K15_NODES, &K15_WEIGHTS, &G7_WEIGHTS),
+            KronrodRule::G10K21 => (&K21_NODES, &K21_WEIGHTS, &G10_WEIGHTS),
+        };
+
+        let center = 0.5 * (lower + upper);
+        let half_length = 0.5 * (upper - lower);
+        let last = nodes.len() - 1;
+
+        let f_center = function(center);
+        // The center is a Gauss node of the 7 point rule, but not of the 10 point rule
+        let mut gauss_sum = if last % 2 == 1 {
+            f_center * gauss[last / 2]
+        } else {
+            0.0
+        };
+        let mut kronrod_sum = f_center * kronrod[last];
+        let mut abs_sum = kronrod_sum.abs();
+        let mut values = Vec::with_capacity(last);
+
+        for (j, node) in nodes.iter().take(last).enumerate() {
+            let offset = half_length * node;
+            let f1 = function(center - offset);
+            let f2 = function(center + offset);
+
+            if j % 2 == 1 {
+                gauss_sum += gauss[j / 2] * (f1 + f2);
+            }
+            kronrod_sum += kronrod[j] * (f1 + f2);
+            abs_sum += kronrod[j] * (f1.abs() + f2.abs());
+            values.push((f1, f2));
+        }
+
+        let mean = 0.5 * kronrod_sum;
+        let deviation = values.iter().zip(kronrod).fold(
+            kronrod[last] * (f_center - mean).abs(),
+            |acc, ((f1, f2), w)| acc + w * ((f1 - mean).abs() + (f2 - mean).abs()),
+        );
+
+        let value = kronrod_sum * half_length;
+        let abs_value = abs_sum * half_length.abs();
+        let abs_deviation = deviation * half_length.abs();
+        let mut error = ((kronrod_sum - gauss_sum) * half_length).abs();
+
+        if abs_deviation != 0.0 && error != 0.0 {
+            error = abs_deviation * (200.0 * error / abs_deviation).powf(1.5).min(1.0);
+        }
+        if abs_value > f64::MIN_POSITIVE / (50.0 * f64::EPSILON) {
+            error = error.max(50.0 * f64::EPSILON * abs_value);
+        }
+
+        RuleEstimate {
+            value,
+            error,
+            abs_value,
+            abs_deviation,
+        }
+    }
+
+    // Removes the subinterval with the largest error, only considering the subintervals that have
+    // been bisected fewer than `maximum_level` times when `large_only` is set.
+    fn pop(
+        heap: &mut BinaryHeap<Subinterval>,
+        large_only: bool,
+        maximum_level: usize,
+    ) -> Option<Subinterval> {
+        if !large_only {
+            return heap.pop();
+        }
+
+        let mut skipped = Vec::new();
+        let mut found = None;
+
+        while let Some(interval) = heap.pop() {
+            if interval.level < maximum_level {
+                found = Some(interval);
+                break;
+            }
+            skipped.push(interval);
+        }
+
+        heap.extend(skipped);
+        found
+    }
+
+    // QUADPACK's QAGS: globally adaptive bisection of the subinterval with the largest error, where
+    // the sequence of estimates is extrapolated with the epsilon algorithm to handle endpoint singularities.
+    pub fn integrate<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+        rule: KronrodRule,
+    ) -> IntegrationResult {
+        let epsilon = f64::EPSILON;
+        let target = |value: f64| tolerance.max(tolerance * value.abs());
+        let evaluations = |intervals: usize| rule.points() * intervals;
+
+        let first = Self::apply(&function, lower_limit, upper_limit, rule);
+        let tolerance0 = target(first.value);
+
+        if first.error <= 100.0 * epsilon * first.abs_value && first.error > tolerance0 {
+            return IntegrationResult {
+                value: first.value,
+                abs_error: first.error,
+                evaluations: evaluations(1),
+                converged: false,
+            };
+        }
+
+        if (first.error <= tolerance0 && first.error != first.abs_deviation) || first.error == 0.0 {
+            return IntegrationResult {
+                value: first.value,
+                abs_error: first.error,
+                evaluations: evaluations(1),
+                converged: true,
+            };
+        }
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Subinterval {
+            lower: lower_limit,
+            upper: upper_limit,
+            value: first.value,
+            error: first.error,
+            level: 0,
+        });
+
+        let mut table = EpsilonTable::new();
+        table.push(first.value);
+
+        let positive_integrand = first.value.abs() >= (1.0 - 50.0 * epsilon) * first.abs_value;
+
+        let mut area = first.value;
+        let mut error_sum = first.error;
+        let mut extrapolated = first.value;
+        let mut extrapolated_error = f64::MAX;
+        let mut error_over_large_intervals = 0.0;
+        let mut extrapolation_tolerance = 0.0;
+        let mut correction = 0.0;
+
+        let mut maximum_level = 0;
+        let mut large_only = false;
+        let mut extrapolate = false;
+        let mut disallow_extrapolation = false;
+
+        let mut roundoff_type1 = 0;
+        let mut roundoff_type2 = 0;
+        let mut roundoff_type3 = 0;
+        let mut error_type = 0;
+        let mut error_type2 = false;
+        let mut stalled_extrapolations = 0;
+        let mut iteration = 1;
+
+        while iteration < Self::MAX_SUBINTERVALS {
+            let interval = match Self::pop(&mut heap, large_only, maximum_level) {
+                Some(interval) => interval,
+                None => break,
+            };
+            let level = interval.level + 1;
+            let middle = 0.5 * (interval.lower + interval.upper);
+
+            iteration += 1;
+
+            let left = Self::apply(&function, interval.lower, middle, rule);
+            let right = Self::apply(&function, middle, interval.upper, rule);
+
+            let area12 = left.value + right.value;
+            let error12 = left.error + right.error;
+
+            error_sum += error12 - interval.error;
+            area += area12 - interval.value;
+
+            if left.abs_deviation != left.error && right.abs_deviation != right.error {
+                let delta = interval.value - area12;
+
+                if delta.abs() <= 1e-5 * area12.abs() && error12 >= 0.99 * interval.error {
+                    if extrapolate {
+                        roundoff_type2 += 1;
+                    } else {
+                        roundoff_type1 += 1;
+                    }
+                }
+                if iteration > 10 && error12 > interval.error {
+                    roundoff_type3 += 1;
+                }
+            }
+
+            if roundoff_type1 + roundoff_type2 >= 10 || roundoff_type3 >= 20 {
+                error_type = 2;
+            }
+            if roundoff_type2 >= 5 {
+                error_type2 = true;
+            }
+
+            let too_small = interval.lower.abs().max(interval.upper.abs())
+                <= (1.0 + 100.0 * epsilon) * (middle.abs() + 1000.0 * f64::MIN_POSITIVE);
+            if too_small {
+                error_type = 4;
+            }
+
+            maximum_level = maximum_level.max(level);
+            heap.push(Subinterval {
+                lower: interval.lower,
+                upper: middle,
+                value: left.value,
+                error: left.error,
+                level,
+            });
+            heap.push(Subinterval {
+                lower: middle,
+                upper: interval.upper,
+                value: right.value,
+                error: right.error,
+                level,
+            });
+
+            if error_sum <= target(area) {
+                return IntegrationResult {
+                    value: heap.iter().map(|i| i.value).sum(),
+                    abs_error: error_sum,
+                    evaluations: evaluations(2 * iteration - 1),
+                    converged: true,
+                };
+            }
+
+            if error_type != 0 {
+                break;
+            }
+
+            if iteration >= Self::MAX_SUBINTERVALS - 1 {
+                error_type = 1;
+                break;
+            }
+
+            if iteration == 2 {
+                error_over_large_intervals = error_sum;
+                extrapolation_tolerance = target(area);
+                table.push(area);
+                continue;
+            }
+
+            if disallow_extrapolation {
+                continue;
+            }
+
+            error_over_large_intervals -= interval.error;
+            if level < maximum_level {
+                error_over_large_intervals += error12;
+            }
+
+            let has_large =
+                |heap: &BinaryHeap<Subinterval>| heap.iter().any(|i| i.level < maximum_level);
+
+            if !extrapolate {
+                // Keep bisecting while the interval with the largest error is not one of the smallest
+                if heap.peek().is_some_and(|i| i.level < maximum_level) {
+                    continue;
+                }
+                extrapolate = true;
+                large_only = true;
+            }
+
+            if !error_type2
+                && error_over_large_intervals > extrapolation_tolerance
+                && has_large(&heap)
+            {
+                continue;
+            }
+
+            table.push(area);
+            let (result, abs_error) = table.extrapolate();
+            stalled_extrapolations += 1;
+
+            if stalled_extrapolations > 5 && extrapolated_error < 1e-3 * error_sum {
+                error_type = 5;
+            }
+
+            if abs_error < extrapolated_error {
+                stalled_extrapolations = 0;
+                extrapolated_error = abs_error;
+                extrapolated = result;
+                correction = error_over_large_intervals;
+                extrapolation_tolerance = target(result);
+
+                if extrapolated_error <= extrapolation_tolerance {
+                    break;
+                }
+            }
+
+            if table.length == 1 {
+                disallow_extrapolation = true;
+            }
+
+            if error_type == 5 {
+                break;
+            }
+
+            large_only = false;
+            extrapolate = false;
+            error_over_large_intervals = error_sum;
+        }
+
+        let evaluations = evaluations(2 * iteration - 1);
+        let sum = |heap: &BinaryHeap<Subinterval>| -> f64 { heap.iter().map(|i| i.value).sum() };
+        let unextrapolated = IntegrationResult {
+            value: sum(&heap),
+            abs_error: error_sum,
+            evaluations,
+            converged: error_type == 0 && error_sum <= target(area),
+        };
+
+        if extrapolated_error == f64::MAX {
+            return unextrapolated;
+        }
+
+        if error_type != 0 || error_type2 {
+            if error_type2 {
+                extrapolated_error += correction;
+            }
+            if error_type == 0 {
+                error_type = 3;
+            }
+
+            if extrapolated != 0.0 && area != 0.0 {
+                if extrapolated_error / extrapolated.abs() > error_sum / area.abs() {
+                    return unextrapolated;
+                }
+            } else if extrapolated_error > error_sum {
+                return unextrapolated;
+            } else if area == 0.0 {
+                return IntegrationResult {
+                    value: extrapolated,
+                    abs_error: extrapolated_error,
+                    evaluations,
+                    converged: false,
+                };
+            }
+        }
+
+        // Test on divergence
+        let max_area = extrapolated.abs().max(area.abs());
+        let diverges = if !positive_integrand && max_area < 0.01 * first.abs_value {
+            false
+        } else {
+            let ratio = extrapolated / area;
+            !(0.01..=100.0).contains(&ratio) || error_sum > area.abs()
+        };
+
+        IntegrationResult {
+            value: extrapolated,
+            abs_error: extrapolated_error,
+            evaluations,
+            converged: error_type == 0 && !diverges && extrapolated_error <= target(extrapolated),
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::special::Gamma;
+use crate::Complex;
+use std::f64::consts::PI;
+
+/// The classical Gaussian quadrature rules and their weight functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GaussRule {
+    /// Weight `1` on `[-1, 1]`.
+    Legendre,
+    /// Weight `1/√(1 - x²)` on `[-1, 1]`.
+    ChebyshevFirstKind,
+    /// Weight `√(1 - x²)` on `[-1, 1]`.
+    ChebyshevSecondKind,
+    /// Weight `x^α e^(-x)` on `[0, ∞)` for the given `α > -1`.
+    Laguerre(f64),
+    /// Weight `e^(-x²)` on `(-∞, ∞)`.
+    Hermite,
+    /// Weight `(1 - x)^α (1 + x)^β` on `[-1, 1]` for the given `α > -1` and `β > -1`.
+    Jacobi(f64, f64),
+}
+
+pub struct GaussQuadrature;
+
+impl GaussQuadrature {
+    const NEWTON_ITERATIONS: usize = 3;
+
+    // The Lanczos approximation of `Gamma::lanczosln` is only accurate to about 1e-11, which would bias every
+    // weight, so the more accurate complex log-gamma is used.
+    fn ln_gamma(x: f64) -> f64 {
+        Gamma::complex_lngamma(Complex::new(x, 0.0)).real_part()
+    }
+
+    // The coefficients of the three-term recurrence p_{k+1}(x) = (x - a_k) p_k(x) - b_k p_{k-1}(x) of the
+    // monic orthogonal polynomials for k = 0..=n, and the integral of the weight function (b_0).
+    fn recurrence(rule: GaussRule, n: usize) -> (Vec<f64>, Vec<f64>) {
+        let mut a = vec![0.0; n + 1];
+        let mut b = vec![0.0; n + 1];
+
+        match rule {
+            GaussRule::Legendre => {
+                b[0] = 2.0;
+                for (k, b_k) in b.iter_mut().enumerate().skip(1) {
+                    let k = k as f64;
+                    *b_k = k * k / (4.0 * k * k - 1.0);
+                }
+            }
+            GaussRule::ChebyshevFirstKind => {
+                b[0] = PI;
+                for (k, b_k) in b.iter_mut().enumerate().skip(1) {
+                    *b_k = if k == 1 { 0.5 } else { 0.25 };
+                }
+            }
+            GaussRule::ChebyshevSecondKind => {
+                b[0] = PI / 2.0;
+                for b_k in b.iter_mut().skip(1) {
+                    *b_k = 0.25;
+                }
+            }
+            GaussRule::Laguerre(alpha) => {
+                assert!(
+                    alpha > -1.0,
+                    "The Laguerre parameter must be greater than -1!"
+                );
+
+                b[0] = Self::ln_gamma(alpha + 1.0).exp();
+                for k in 0..=n {
+                    let k_f = k as f64;
+                    a[k] = 2.0 * k_f + alpha + 1.0;
+                    if k > 0 {
+                        b[k] = k_f * (k_f + alpha);
+                    }
+                }
+            }
+            GaussRule::Hermite => {
+                b[0] = PI.sqrt();
+                for (k, b_k) in b.iter_mut().enumerate().skip(1) {
+                    *b_k = k as f64 / 2.0;
+                }
+            }
+            GaussRule::Jacobi(alpha, beta) => {
+                assert!(
+                    alpha > -1.0 && beta > -1.0,
+                    "The Jacobi parameters must be greater than -1!"
+                );
+
+                let sum = alpha + beta;
+                b[0] = ((sum + 1.0) * 2_f64.ln()
+                    + Self::ln_gamma(alpha + 1.0)
+                    + Self::ln_gamma(beta + 1.0)
+                    - Self::ln_gamma(sum + 2.0))
+                .exp();
+                a[0] = (beta - alpha) / (sum + 2.0);
+
+                for k in 1..=n {
+                    let k_f = k as f64;
+                    let s = 2.0 * k_f + sum;
+                    a[k] = (beta * beta - alpha * alpha) / (s * (s + 2.0));
+                    b[k] = if k == 1 {
+                        4.0 * (1.0 + alpha) * (1.0 + beta) / ((2.0 + sum).powi(2) * (3.0 + sum))
+                    } else {
+                        4.0 * k_f * (k_f + alpha) * (k_f + beta) * (k_f + sum)
+                            / (s * s * (s + 1.0) * (s - 1.0))
+                    };
+                }
+            }
+        }
+
+        (a, b)
+    }
+
+    // Eigenvalues of the symmetric tridiagonal matrix with the given diagonal and off-diagonal
+    // (`off_diagonal[i]` couples rows `i` and `i + 1`) by the implicit QL algorithm with Wilkinson shifts.
+    fn tridiagonal_eigenvalues(mut diagonal: Vec<f64>, off_diagonal: &[f64]) -> Vec<f64> {
+        let n = diagonal.len();
+        let mut e = off_diagonal.to_vec();
+        e.resize(n, 0.0);
+
+        for l in 0..n {
+            let mut iterations = 0;
+
+            loop {
+                let mut m = l;
+                while m + 1 < n {
+                    let dd = diagonal[m].abs() + diagonal[m + 1].abs();
+                    if e[m].abs() <= f64::EPSILON * dd {
+                        break;
+                    }
+                    m += 1;
+                }
+
+                if m == l || iterations == 50 {
+                    break;
+                }
+                iterations += 1;
+
+                let mut g = (diagonal[l + 1] - diagonal[l]) / (2.0 * e[l]);
+                let mut r = g.hypot(1.0);
+                g = diagonal[m] - diagonal[l] + e[l] / (g + r.copysign(g));
+
+                let mut s = 1.0;
+                let mut c = 1.0;
+                let mut p = 0.0;
+                let mut underflow = false;
+
+                for i in (l..m).rev() {
+                    let f = s * e[i];
+                    let b = c * e[i];
+                    r = f.hypot(g);
+                    e[i + 1] = r;
+
+                    if r == 0.0 {
+                        diagonal[i + 1] -= p;
+                        e[m] = 0.0;
+                        underflow = true;
+                        break;
+                    }
+
+                    s = f / r;
+                    c = g / r;
+                    g = diagonal[i + 1] - p;
+                    r = (diagonal[i] - g) * s + 2.0 * c * b;
+                    p = s * r;
+                    diagonal[i + 1] = g + p;
+                    g = c * r - b;
+                }
+
+                if !underflow {
+                    diagonal[l] -= p;
+                    e[l] = g;
+                    e[m] = 0.0;
+                }
+            }
+        }
+
+        diagonal.sort_by(f64::total_cmp);
+        diagonal
+    }
+
+    // Evaluates the orthonormal polynomials q_0..=q_n at `x`, returning q_n, its derivative and the sum of
+    // q_k² for k < n, whose reciprocal is the Christoffel number (the Gauss weight) at a node.
+    fn orthonormal(x: f64, a: &[f64], b: &[f64], n: usize) -> (f64, f64, f64) {
+        let mut q_previous = 0.0;
+        let mut dq_previous = 0.0;
+        let mut q = 1.0 / b[0].sqrt();
+        let mut dq = 0.0;
+        let mut sum = 0.0;
+
+        for k in 0..n {
+            sum += q * q;
+
+            let scale = b[k + 1].sqrt();
+            let coupling = if k == 0 { 0.0 } else { b[k].sqrt() };
+            let q_next = ((x - a[k]) * q - coupling * q_previous) / scale;
+            let dq_next = (q + (x - a[k]) * dq - coupling * dq_previous) / scale;
+
+            q_previous = q;
+            dq_previous = dq;
+            q = q_next;
+            dq = dq_next;
+        }
+
+        (q, dq, sum)
+    }
+
+    pub fn nodes(rule: GaussRule, n: usize) -> (Vec<f64>, Vec<f64>) {
+        if n == 0 {
+            return (Vec::new(), Vec::new());
+        }
+
+        // Both Chebyshev rules have closed forms.
+        match rule {
+            GaussRule::ChebyshevFirstKind => {
+                let nodes = (1..=n)
+                    .rev()
+                    .map(|k| ((2 * k - 1) as f64 * PI / (2 * n) as f64).cos())
+                    .collect();
+                return (nodes, vec![PI / n as f64; n]);
+            }
+            GaussRule::ChebyshevSecondKind => {
+                let angles: Vec<f64> = (1..=n)
+                    .rev()
+                    .map(|k| k as f64 * PI / (n + 1) as f64)
+                    .collect();
+                let nodes = angles.iter().map(|angle| angle.cos()).collect();
+                let weights = angles
+                    .iter()
+                    .map(|angle| PI / (n + 1) as f64 * angle.sin().powi(2))
+                    .collect();
+                return (nodes, weights);
+            }
+            _ => {}
+        }
+
+        let (a, b) = Self::recurrence(rule, n);
+
+        // Golub-Welsch: the nodes are the eigenvalues of the Jacobi matrix, which are then polished
+        // with Newton's method on the orthonormal polynomial.
+        let off_diagonal: Vec<f64> = b[1..n].iter().map(|b_k| b_k.sqrt()).collect();
+        let mut nodes = Self::tridiagonal_eigenvalues(a[..n].to_vec(), &off_diagonal);
+
+        let weights = nodes
+            .iter_mut()
+            .map(|x| {
+                for _ in 0..Self::NEWTON_ITERATIONS {
+                    let (q, dq, _) = Self::orthonormal(*x, &a, &b, n);
+                    let step = q / dq;
+                    if !step.is_finite() {
+                        break;
+                    }
+                    *x -= step;
+                }
+
+                1.0 / Self::orthonormal(*x, &a, &b, n).2
+            })
+            .collect();
+
+        (nodes, weights)
+    }
+
+    pub fn integrate<F: Fn(f64) -> f64>(function: F, rule: GaussRule, n: usize) -> f64 {
+        let (nodes, weights) = Self::nodes(rule, n);
+
+        nodes
+            .iter()
+            .zip(&weights)
+            .map(|(&x, &w)| w * function(x))
+            .sum()
+    }
+
+    pub fn legendre<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        n: usize,
+    ) -> f64 {
+        let half_length = 0.5 * (upper_limit - lower_limit);
+        let center = 0.5 * (upper_limit + lower_limit);
+
+        half_length
+            * Self::integrate(
+                |t: f64| function(center + half_length * t),
+                GaussRule::Legendre,
+                n,
+            )
+    }
+}
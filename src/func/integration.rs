@@ -1,8 +1,51 @@
+use crate::func::extrapolation::Extrapolation;
+use crate::func::gauss_kronrod::IntegrationResult;
 use crate::Functions;
 
 pub struct Integration;
 
 impl Integration {
+    // Maps an interval with infinite limits onto a finite one (QUADPACK's QAGI substitution).
+    // With one infinite limit, x = c ± t/(1 - t) on t ∈ [0, 1] where c is the finite limit; with two,
+    // x = t/(1 - t²) on t ∈ [-1, 1]. The transformed integrand is taken to vanish where x is infinite.
+    // Finite limits are returned unchanged.
+    pub fn finite_limits<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+    ) -> (impl Fn(f64) -> f64, f64, f64) {
+        let lower_finite = lower_limit.is_finite();
+        let upper_finite = upper_limit.is_finite();
+
+        let (finite, direction, t_lower, t_upper) = match (lower_finite, upper_finite) {
+            (true, true) => (0.0, 0.0, lower_limit, upper_limit),
+            (true, false) => (lower_limit, upper_limit.signum(), 0.0, 1.0),
+            (false, true) => (upper_limit, lower_limit.signum(), 1.0, 0.0),
+            (false, false) => (0.0, 0.0, lower_limit.signum(), upper_limit.signum()),
+        };
+
+        let transformed = move |t: f64| match (lower_finite, upper_finite) {
+            (true, true) => function(t),
+            (false, false) => {
+                if t.abs() >= 1.0 {
+                    return 0.0;
+                }
+                let denominator = 1.0 - t * t;
+                function(t / denominator) * (1.0 + t * t) / (denominator * denominator)
+            }
+            _ => {
+                if t >= 1.0 {
+                    return 0.0;
+                }
+                let complement = 1.0 - t;
+                direction * function(finite + direction * t / complement)
+                    / (complement * complement)
+            }
+        };
+
+        (transformed, t_lower, t_upper)
+    }
+
     pub fn right_riemann<F: Fn(f64) -> f64>(
         function: F,
         lower_limit: f64,
@@ -151,4 +194,52 @@ impl Integration {
 
         adaptive_quadrature_recursive(&function, lower_limit, upper_limit, fa, fb, fab, tolerance)
     }
+
+    pub fn romberg<F: Fn(f64) -> f64>(
+        function: F,
+        lower_limit: f64,
+        upper_limit: f64,
+        tolerance: f64,
+    ) -> IntegrationResult {
+        // The trapezoid rule is refined at least 4 times so that a periodic integrand sampled at a few
+        // points cannot converge early, and at most 20 times (2^20 intervals).
+        const MIN_LEVELS: usize = 4;
+        const MAX_LEVELS: usize = 20;
+
+        let mut h = upper_limit - lower_limit;
+        let mut trapezoid = 0.5 * h * (function(lower_limit) + function(upper_limit));
+        let mut row = vec![trapezoid];
+        let mut evaluations = 2;
+        let mut abs_error = f64::INFINITY;
+        let mut converged = false;
+
+        for level in 1..=MAX_LEVELS {
+            let new_points = 1_usize << (level - 1);
+            h /= 2.0;
+
+            let midpoints: f64 = (0..new_points)
+                .map(|i| function(lower_limit + (2 * i + 1) as f64 * h))
+                .sum();
+            evaluations += new_points;
+            trapezoid = 0.5 * trapezoid + h * midpoints;
+
+            // The error of the trapezoid rule only has even powers of the step.
+            let next = Extrapolation::richardson_row(&row, trapezoid, 2.0, 2.0, 2.0);
+            let value = next[next.len() - 1];
+            abs_error = (value - row[row.len() - 1]).abs();
+            row = next;
+
+            if level >= MIN_LEVELS && abs_error <= tolerance.max(tolerance * value.abs()) {
+                converged = true;
+                break;
+            }
+        }
+
+        IntegrationResult {
+            value: row[row.len() - 1],
+            abs_error,
+            evaluations,
+            converged,
+        }
+    }
 }
@@ -0,0 +1,426 @@
+use std::fmt;
+
+/// The stopping criteria of the one-dimensional minimizers.
+///
+/// An iteration stops when the minimizer is known to within `absolute + relative * |x|`. A function is
+/// flat near its minimum, so its values only determine the minimizer to about `√ε` relative accuracy,
+/// which is the `Default` relative tolerance, together with `1e-10` and 500 iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimumTolerance {
+    /// The absolute tolerance on the minimizer.
+    pub absolute: f64,
+    /// The tolerance on the minimizer relative to its magnitude.
+    pub relative: f64,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for MinimumTolerance {
+    fn default() -> Self {
+        Self {
+            absolute: 1e-10,
+            relative: f64::EPSILON.sqrt(),
+            max_iterations: 500,
+        }
+    }
+}
+
+impl MinimumTolerance {
+    fn width(&self, x: f64) -> f64 {
+        self.absolute + self.relative * x.abs()
+    }
+}
+
+/// The result of a one-dimensional minimizer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimumResult {
+    /// The approximation of the minimizer.
+    pub argmin: f64,
+    /// The value of the function at `argmin`.
+    pub minimum: f64,
+    /// The length of the last interval known to contain the minimizer.
+    pub width: f64,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The number of times the function was evaluated.
+    pub evaluations: usize,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+/// Three points `lower < middle < upper` with `f(middle) <= f(lower)` and `f(middle) <= f(upper)`, so a
+/// continuous function has a local minimum between `lower` and `upper`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimumBracket {
+    /// The left end of the bracket.
+    pub lower: f64,
+    /// The point inside the bracket with the smallest known value.
+    pub middle: f64,
+    /// The right end of the bracket.
+    pub upper: f64,
+    /// The value of the function at `lower`.
+    pub f_lower: f64,
+    /// The value of the function at `middle`.
+    pub f_middle: f64,
+    /// The value of the function at `upper`.
+    pub f_upper: f64,
+    /// The number of times the function was evaluated.
+    pub evaluations: usize,
+}
+
+/// The reasons a one-dimensional minimizer can fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MinimizationError {
+    /// The function returned NaN or an infinite value at `x`.
+    NonFiniteValue { x: f64 },
+    /// The function kept decreasing while the search for a bracket expanded up to `x`.
+    NotBracketed { x: f64 },
+}
+
+impl fmt::Display for MinimizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MinimizationError::NonFiniteValue { x } => {
+                write!(f, "the function is not finite at x = {}", x)
+            }
+            MinimizationError::NotBracketed { x } => {
+                write!(
+                    f,
+                    "no minimum was bracketed: the function kept decreasing up to x = {}",
+                    x
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MinimizationError {}
+
+pub struct Minimization;
+
+impl Minimization {
+    // (3 - √5) / 2, the fraction of an interval that golden section steps cut off.
+    const GOLDEN_SECTION: f64 = 0.3819660112501051;
+    // (1 + √5) / 2, the factor by which the bracket search expands its steps.
+    const GOLDEN_RATIO: f64 = 1.618033988749895;
+    // The parabolic extrapolation of the bracket search goes at most this many steps further.
+    const MAX_MAGNIFICATION: f64 = 100.0;
+    const MAX_EXPANSIONS: usize = 100;
+
+    fn evaluate<F: Fn(f64) -> f64>(
+        function: &F,
+        x: f64,
+        evaluations: &mut usize,
+    ) -> Result<f64, MinimizationError> {
+        *evaluations += 1;
+        let value = function(x);
+
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(MinimizationError::NonFiniteValue { x })
+        }
+    }
+
+    // The golden section search keeps two interior points that divide the interval in the golden ratio, so
+    // every iteration reuses one of them and shrinks the interval by the factor 0.618.
+    pub fn golden_section<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        let mut evaluations = 0;
+        let mut a = lower.min(upper);
+        let mut b = lower.max(upper);
+
+        let mut x1 = a + Self::GOLDEN_SECTION * (b - a);
+        let mut x2 = b - Self::GOLDEN_SECTION * (b - a);
+        let mut f1 = Self::evaluate(&function, x1, &mut evaluations)?;
+        let mut f2 = Self::evaluate(&function, x2, &mut evaluations)?;
+
+        let mut iterations = tolerance.max_iterations;
+        let mut converged = false;
+
+        for iteration in 1..=tolerance.max_iterations {
+            if f1 <= f2 {
+                b = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = a + Self::GOLDEN_SECTION * (b - a);
+                f1 = Self::evaluate(&function, x1, &mut evaluations)?;
+            } else {
+                a = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = b - Self::GOLDEN_SECTION * (b - a);
+                f2 = Self::evaluate(&function, x2, &mut evaluations)?;
+            }
+
+            if 0.5 * (b - a) <= tolerance.width(0.5 * (a + b)) {
+                iterations = iteration;
+                converged = true;
+                break;
+            }
+        }
+
+        let (argmin, minimum) = if f1 <= f2 { (x1, f1) } else { (x2, f2) };
+
+        Ok(MinimumResult {
+            argmin,
+            minimum,
+            width: b - a,
+            iterations,
+            evaluations,
+            converged,
+        })
+    }
+
+    pub fn brent<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        let a = lower.min(upper);
+        let b = lower.max(upper);
+        let x = a + Self::GOLDEN_SECTION * (b - a);
+
+        Self::brent_from(function, a, b, x, None, 0, tolerance)
+    }
+
+    // Brent's method (fmin): parabolas through the three best points, with a golden section step whenever
+    // the parabola is not trusted because its step lies outside of the interval or is not at least half as
+    // short as the one before last. `x` is the starting point inside [a, b] and `f_x` its value if known.
+    fn brent_from<F: Fn(f64) -> f64>(
+        function: F,
+        mut a: f64,
+        mut b: f64,
+        mut x: f64,
+        f_x: Option<f64>,
+        mut evaluations: usize,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        let mut f_x = match f_x {
+            Some(value) => value,
+            None => Self::evaluate(&function, x, &mut evaluations)?,
+        };
+        let (mut w, mut f_w) = (x, f_x);
+        let (mut v, mut f_v) = (x, f_x);
+        // The last step and the one before.
+        let mut d: f64 = 0.0;
+        let mut e: f64 = 0.0;
+
+        for iteration in 0..=tolerance.max_iterations {
+            let middle = 0.5 * (a + b);
+            let tolerance_1 = 0.5 * tolerance.width(x);
+            let tolerance_2 = 2.0 * tolerance_1;
+
+            if (x - middle).abs() <= tolerance_2 - 0.5 * (b - a) {
+                return Ok(MinimumResult {
+                    argmin: x,
+                    minimum: f_x,
+                    width: b - a,
+                    iterations: iteration,
+                    evaluations,
+                    converged: true,
+                });
+            }
+
+            if iteration == tolerance.max_iterations {
+                break;
+            }
+
+            let mut golden = true;
+
+            if e.abs() > tolerance_1 {
+                let r = (x - w) * (f_x - f_v);
+                let mut q = (x - v) * (f_x - f_w);
+                let mut p = (x - v) * q - (x - w) * r;
+                q = 2.0 * (q - r);
+                if q > 0.0 {
+                    p = -p;
+                } else {
+                    q = -q;
+                }
+
+                let before_last = e;
+                e = d;
+
+                if p.abs() < (0.5 * q * before_last).abs() && p > q * (a - x) && p < q * (b - x) {
+                    golden = false;
+                    d = p / q;
+                    let u = x + d;
+
+                    // The function is not evaluated too close to the ends of the interval.
+                    if u - a < tolerance_2 || b - u < tolerance_2 {
+                        d = tolerance_1.copysign(middle - x);
+                    }
+                }
+            }
+
+            if golden {
+                e = if x >= middle { a - x } else { b - x };
+                d = Self::GOLDEN_SECTION * e;
+            }
+
+            let u = if d.abs() >= tolerance_1 {
+                x + d
+            } else {
+                x + tolerance_1.copysign(d)
+            };
+            let f_u = Self::evaluate(&function, u, &mut evaluations)?;
+
+            if f_u <= f_x {
+                if u >= x {
+                    a = x;
+                } else {
+                    b = x;
+                }
+                (v, f_v) = (w, f_w);
+                (w, f_w) = (x, f_x);
+                (x, f_x) = (u, f_u);
+            } else {
+                if u < x {
+                    a = u;
+                } else {
+                    b = u;
+                }
+
+                if f_u <= f_w || w == x {
+                    (v, f_v) = (w, f_w);
+                    (w, f_w) = (u, f_u);
+                } else if f_u <= f_v || v == x || v == w {
+                    (v, f_v) = (u, f_u);
+                }
+            }
+        }
+
+        Ok(MinimumResult {
+            argmin: x,
+            minimum: f_x,
+            width: b - a,
+            iterations: tolerance.max_iterations,
+            evaluations,
+            converged: false,
+        })
+    }
+
+    // Walks downhill from a and b with steps that grow by the golden ratio, or with parabolic extrapolation
+    // when it is within reach, until the function increases again (Numerical Recipes' `mnbrak`).
+    pub fn bracket<F: Fn(f64) -> f64>(
+        function: F,
+        a: f64,
+        b: f64,
+    ) -> Result<MinimumBracket, MinimizationError> {
+        let mut evaluations = 0;
+        let mut a = a;
+        let mut b = b;
+        let mut f_a = Self::evaluate(&function, a, &mut evaluations)?;
+        let mut f_b = Self::evaluate(&function, b, &mut evaluations)?;
+
+        if f_b > f_a {
+            (a, b) = (b, a);
+            (f_a, f_b) = (f_b, f_a);
+        }
+
+        let mut c = b + Self::GOLDEN_RATIO * (b - a);
+        let mut f_c = Self::evaluate(&function, c, &mut evaluations)?;
+        let mut expansions = 0;
+
+        while f_b > f_c {
+            expansions += 1;
+            if expansions > Self::MAX_EXPANSIONS {
+                return Err(MinimizationError::NotBracketed { x: c });
+            }
+
+            // The minimum of the parabola through a, b and c.
+            let r = (b - a) * (f_b - f_c);
+            let q = (b - c) * (f_b - f_a);
+            let denominator = 2.0 * (q - r).abs().max(f64::MIN_POSITIVE).copysign(q - r);
+            let mut u = b - ((b - c) * q - (b - a) * r) / denominator;
+            let limit = b + Self::MAX_MAGNIFICATION * (c - b);
+            let mut f_u;
+
+            if (b - u) * (u - c) > 0.0 {
+                f_u = Self::evaluate(&function, u, &mut evaluations)?;
+
+                if f_u < f_c {
+                    return Ok(Self::ordered(b, u, c, f_b, f_u, f_c, evaluations));
+                } else if f_u > f_b {
+                    return Ok(Self::ordered(a, b, u, f_a, f_b, f_u, evaluations));
+                }
+
+                u = c + Self::GOLDEN_RATIO * (c - b);
+                f_u = Self::evaluate(&function, u, &mut evaluations)?;
+            } else if (c - u) * (u - limit) > 0.0 {
+                f_u = Self::evaluate(&function, u, &mut evaluations)?;
+
+                if f_u < f_c {
+                    (b, f_b) = (c, f_c);
+                    (c, f_c) = (u, f_u);
+                    u = c + Self::GOLDEN_RATIO * (c - b);
+                    f_u = Self::evaluate(&function, u, &mut evaluations)?;
+                }
+            } else if (u - limit) * (limit - c) >= 0.0 {
+                u = limit;
+                f_u = Self::evaluate(&function, u, &mut evaluations)?;
+            } else {
+                u = c + Self::GOLDEN_RATIO * (c - b);
+                f_u = Self::evaluate(&function, u, &mut evaluations)?;
+            }
+
+            (a, f_a) = (b, f_b);
+            (b, f_b) = (c, f_c);
+            (c, f_c) = (u, f_u);
+        }
+
+        Ok(Self::ordered(a, b, c, f_a, f_b, f_c, evaluations))
+    }
+
+    fn ordered(
+        a: f64,
+        b: f64,
+        c: f64,
+        f_a: f64,
+        f_b: f64,
+        f_c: f64,
+        evaluations: usize,
+    ) -> MinimumBracket {
+        let ((lower, f_lower), (upper, f_upper)) = if a <= c {
+            ((a, f_a), (c, f_c))
+        } else {
+            ((c, f_c), (a, f_a))
+        };
+
+        MinimumBracket {
+            lower,
+            middle: b,
+            upper,
+            f_lower,
+            f_middle: f_b,
+            f_upper,
+            evaluations,
+        }
+    }
+
+    // Brackets a minimum from a and b and refines it with Brent's method from the middle of the bracket.
+    pub fn minimize<F: Fn(f64) -> f64>(
+        function: F,
+        a: f64,
+        b: f64,
+        tolerance: MinimumTolerance,
+    ) -> Result<MinimumResult, MinimizationError> {
+        let bracket = Self::bracket(&function, a, b)?;
+
+        Self::brent_from(
+            function,
+            bracket.lower,
+            bracket.upper,
+            bracket.middle,
+            Some(bracket.f_middle),
+            bracket.evaluations,
+            tolerance,
+        )
+    }
+}
@@ -1,4 +1,25 @@
+mod cubature;
+mod differentiation;
+mod double_exponential;
+mod extrapolation;
 mod functions;
+mod gauss_kronrod;
+mod gauss_quadrature;
 mod integration;
+mod minimization;
+mod newton;
+mod nonlinear_systems;
+mod polynomial;
+mod root_finding;
 
+pub use cubature::{QuasiRandomSequence, Region};
+pub use differentiation::{DerivativeResult, Stencil};
+pub use extrapolation::ExtrapolationResult;
 pub use functions::*;
+pub use gauss_kronrod::{IntegrationResult, KronrodRule};
+pub use gauss_quadrature::GaussRule;
+pub use minimization::{MinimizationError, MinimumBracket, MinimumResult, MinimumTolerance};
+pub use newton::Newton;
+pub use nonlinear_systems::{SystemError, SystemResult};
+pub use polynomial::*;
+pub use root_finding::{RootError, RootResult, RootTolerance};
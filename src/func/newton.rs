@@ -0,0 +1,204 @@
+use crate::func::root_finding::{RootError, RootFinding, RootResult, RootTolerance};
+use crate::Functions;
+use std::cell::Cell;
+
+/// A configurable Newton–Raphson root finder.
+///
+/// The solver is built from the function alone and then configured with the builder methods. Without an
+/// analytic derivative the derivative is approximated with [`Functions::derivative`]. Supplying a second
+/// derivative switches to Halley's method, which converges cubically instead of quadratically.
+///
+/// Every step can be damped by a constant factor, and the optional backtracking line search halves the step
+/// until `|f|` decreases, which keeps the iteration from diverging on functions like `atan` where a full
+/// Newton step overshoots.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::Newton;
+///
+/// let result = Newton::new(|x: f64| x.powi(3) - 2.0 * x - 5.0)
+///     .derivative(|x: f64| 3.0 * x.powi(2) - 2.0)
+///     .halley(|x: f64| 6.0 * x)
+///     .max_iterations(20)
+///     .solve(2.0)
+///     .unwrap();
+///
+/// assert!(result.converged);
+/// println!("The root of x^3 - 2x - 5 is: {}", result.root);
+/// ```
+/// <hr/>
+pub struct Newton<'a> {
+    function: Box<dyn Fn(f64) -> f64 + 'a>,
+    derivative: Option<Box<dyn Fn(f64) -> f64 + 'a>>,
+    second_derivative: Option<Box<dyn Fn(f64) -> f64 + 'a>>,
+    tolerance: RootTolerance,
+    damping: f64,
+    line_search: bool,
+}
+
+impl<'a> Newton<'a> {
+    const MAX_HALVINGS: usize = 30;
+
+    /// Creates a solver for the roots of `function` with the default [`RootTolerance`], no damping and no
+    /// line search.
+    pub fn new<F: Fn(f64) -> f64 + 'a>(function: F) -> Self {
+        Self {
+            function: Box::new(function),
+            derivative: None,
+            second_derivative: None,
+            tolerance: RootTolerance::default(),
+            damping: 1.0,
+            line_search: false,
+        }
+    }
+
+    /// Uses the given analytic derivative instead of a finite difference approximation.
+    pub fn derivative<D: Fn(f64) -> f64 + 'a>(mut self, derivative: D) -> Self {
+        self.derivative = Some(Box::new(derivative));
+        self
+    }
+
+    /// Uses Halley's method with the given second derivative.
+    pub fn halley<S: Fn(f64) -> f64 + 'a>(mut self, second_derivative: S) -> Self {
+        self.second_derivative = Some(Box::new(second_derivative));
+        self
+    }
+
+    /// Sets the stopping criteria.
+    pub fn tolerance(mut self, tolerance: RootTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the maximum number of iterations.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.tolerance.max_iterations = max_iterations;
+        self
+    }
+
+    /// Multiplies every step by `damping`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `damping` is not in `(0, 1]`.
+    pub fn damping(mut self, damping: f64) -> Self {
+        assert!(
+            damping > 0.0 && damping <= 1.0,
+            "The damping factor must be in (0, 1]!"
+        );
+
+        self.damping = damping;
+        self
+    }
+
+    /// Enables or disables the backtracking line search.
+    pub fn line_search(mut self, line_search: bool) -> Self {
+        self.line_search = line_search;
+        self
+    }
+
+    /// Runs the iteration from `guess`.
+    ///
+    /// # Returns
+    ///
+    /// The last iterate together with the iteration and function evaluation counts. `converged` is `false`
+    /// when the maximum number of iterations was reached or the line search could not decrease `|f|`.
+    ///
+    /// # Errors
+    ///
+    /// [`RootError::NonFiniteValue`] when the function is not finite at an iterate, and
+    /// [`RootError::ZeroSlope`] when the derivative vanishes.
+    pub fn solve(&self, guess: f64) -> Result<RootResult, RootError> {
+        // Evaluations made by the finite difference derivative are counted as well.
+        let evaluations = Cell::new(0);
+        let function = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            (self.function)(x)
+        };
+        let finite = |x: f64, value: f64| {
+            if value.is_finite() {
+                Ok(value)
+            } else {
+                Err(RootError::NonFiniteValue { x })
+            }
+        };
+
+        let mut x = guess;
+        let mut f_x = finite(x, function(x))?;
+
+        if f_x == 0.0 {
+            return Ok(RootFinding::result(x, f_x, 0, evaluations.get(), true));
+        }
+
+        for iteration in 1..=self.tolerance.max_iterations {
+            let slope = match &self.derivative {
+                Some(derivative) => derivative(x),
+                None => Functions::derivative(function, x),
+            };
+            if slope == 0.0 || !slope.is_finite() {
+                return Err(RootError::ZeroSlope { x });
+            }
+
+            let mut step = f_x / slope;
+
+            // Halley's correction, skipped when its denominator vanishes.
+            if let Some(second_derivative) = &self.second_derivative {
+                let denominator = 1.0 - 0.5 * step * second_derivative(x) / slope;
+                if denominator != 0.0 && denominator.is_finite() {
+                    step /= denominator;
+                }
+            }
+
+            let mut scale = self.damping;
+            let mut x_next = x - scale * step;
+            let mut f_next = function(x_next);
+
+            if self.line_search {
+                let decreased = |value: f64| value.abs() < f_x.abs();
+                let mut halvings = 0;
+                while !decreased(f_next) && halvings < Self::MAX_HALVINGS {
+                    scale *= 0.5;
+                    x_next = x - scale * step;
+                    f_next = function(x_next);
+                    halvings += 1;
+                }
+
+                // Close to the root rounding can keep |f| from decreasing.
+                if !decreased(f_next) {
+                    let converged = step.abs() <= self.tolerance.width(x);
+                    return Ok(RootFinding::result(
+                        x,
+                        f_x,
+                        iteration,
+                        evaluations.get(),
+                        converged,
+                    ));
+                }
+            }
+
+            let f_next = finite(x_next, f_next)?;
+            x = x_next;
+            f_x = f_next;
+
+            // The full step estimates the distance to the root even when only part of it was taken.
+            if f_x.abs() <= self.tolerance.residual || step.abs() <= self.tolerance.width(x) {
+                return Ok(RootFinding::result(
+                    x,
+                    f_x,
+                    iteration,
+                    evaluations.get(),
+                    true,
+                ));
+            }
+        }
+
+        Ok(RootFinding::result(
+            x,
+            f_x,
+            self.tolerance.max_iterations,
+            evaluations.get(),
+            false,
+        ))
+    }
+}
@@ -0,0 +1,353 @@
+use crate::func::differentiation::{Differentiation, Stencil};
+use crate::func::root_finding::RootTolerance;
+use crate::Matrix;
+use std::cell::Cell;
+use std::fmt;
+
+/// The result of a solver for a system of nonlinear equations `F(x) = 0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemResult {
+    /// The approximation of the root.
+    pub root: Vec<f64>,
+    /// The value of `F` at `root`.
+    pub function_value: Vec<f64>,
+    /// The Euclidean norm of `function_value`.
+    pub residual_norm: f64,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The number of times `F` was evaluated, including the evaluations of finite difference Jacobians.
+    pub evaluations: usize,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+/// The reasons a solver for a system of nonlinear equations can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SystemError {
+    /// `F` returned NaN or an infinite value at `x`.
+    NonFiniteValue { x: Vec<f64> },
+    /// The Jacobian is singular at `x`.
+    SingularJacobian { x: Vec<f64> },
+}
+
+impl fmt::Display for SystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemError::NonFiniteValue { x } => {
+                write!(f, "the function is not finite at x = {:?}", x)
+            }
+            SystemError::SingularJacobian { x } => {
+                write!(f, "the Jacobian is singular at x = {:?}", x)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SystemError {}
+
+fn norm(x: &[f64]) -> f64 {
+    x.iter().fold(0.0, |norm: f64, value| norm.hypot(*value))
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn multiply<const N: usize>(matrix: &Matrix<N, N>, x: &[f64]) -> Vec<f64> {
+    (0..N).map(|i| dot(&matrix[i], x)).collect()
+}
+
+fn multiply_transpose<const N: usize>(matrix: &Matrix<N, N>, x: &[f64]) -> Vec<f64> {
+    (0..N)
+        .map(|j| (0..N).map(|i| matrix[i][j] * x[i]).sum())
+        .collect()
+}
+
+// Counts the evaluations of `F` and checks the length and finiteness of its values.
+struct System<F> {
+    function: F,
+    evaluations: Cell<usize>,
+}
+
+impl<F: Fn(&[f64]) -> Vec<f64>> System<F> {
+    fn new(function: F) -> Self {
+        Self {
+            function,
+            evaluations: Cell::new(0),
+        }
+    }
+
+    fn call<const N: usize>(&self, x: &[f64]) -> Vec<f64> {
+        self.evaluations.set(self.evaluations.get() + 1);
+        let values = (self.function)(x);
+        assert_eq!(values.len(), N, "The function must return {} values!", N);
+        values
+    }
+
+    fn evaluate<const N: usize>(&self, x: &[f64]) -> Result<Vec<f64>, SystemError> {
+        let values = self.call::<N>(x);
+
+        if values.iter().all(|value| value.is_finite()) {
+            Ok(values)
+        } else {
+            Err(SystemError::NonFiniteValue { x: x.to_vec() })
+        }
+    }
+
+    fn jacobian<const N: usize>(&self, x: &[f64]) -> Matrix<N, N> {
+        let point: [f64; N] = x.try_into().unwrap();
+        Differentiation::jacobian(|x: &[f64]| self.call::<N>(x), &point, Stencil::Central)
+    }
+
+    fn result(
+        &self,
+        root: Vec<f64>,
+        function_value: Vec<f64>,
+        iterations: usize,
+        converged: bool,
+    ) -> Result<SystemResult, SystemError> {
+        Ok(SystemResult {
+            residual_norm: norm(&function_value),
+            root,
+            function_value,
+            iterations,
+            evaluations: self.evaluations.get(),
+            converged,
+        })
+    }
+}
+
+pub struct NonlinearSystems;
+
+impl NonlinearSystems {
+    const MAX_HALVINGS: usize = 30;
+
+    fn width(tolerance: &RootTolerance, x: &[f64]) -> f64 {
+        tolerance.width(norm(x))
+    }
+
+    // Newton's method with a backtracking line search on the residual norm, using the Jacobian from
+    // `jacobian` or finite differences when it is `None`.
+    pub fn newton<const N: usize, F, J>(
+        function: F,
+        jacobian: Option<J>,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError>
+    where
+        F: Fn(&[f64]) -> Vec<f64>,
+        J: Fn(&[f64]) -> Matrix<N, N>,
+    {
+        let system = System::new(function);
+        let mut x = guess.to_vec();
+        let mut f_x = system.evaluate::<N>(&x)?;
+
+        if norm(&f_x) <= tolerance.residual {
+            return system.result(x, f_x, 0, true);
+        }
+
+        for iteration in 1..=tolerance.max_iterations {
+            let matrix = match &jacobian {
+                Some(jacobian) => jacobian(&x),
+                None => system.jacobian::<N>(&x),
+            };
+            let lu = matrix
+                .lu()
+                .ok_or_else(|| SystemError::SingularJacobian { x: x.clone() })?;
+
+            let step = lu.solve(&f_x);
+            let step_norm = norm(&step);
+            let residual = norm(&f_x);
+
+            let mut scale = 1.0;
+            let mut x_next: Vec<f64> = x.iter().zip(&step).map(|(x, s)| x - s).collect();
+            let mut f_next = system.call::<N>(&x_next);
+            let decreased = |values: &[f64]| norm(values) < residual;
+            let mut halvings = 0;
+
+            while !decreased(&f_next) && halvings < Self::MAX_HALVINGS {
+                scale *= 0.5;
+                x_next = x.iter().zip(&step).map(|(x, s)| x - scale * s).collect();
+                f_next = system.call::<N>(&x_next);
+                halvings += 1;
+            }
+
+            // Close to the root rounding can keep the residual from decreasing.
+            if !decreased(&f_next) {
+                let converged = step_norm <= Self::width(&tolerance, &x);
+                return system.result(x, f_x, iteration, converged);
+            }
+
+            x = x_next;
+            f_x = f_next;
+
+            if norm(&f_x) <= tolerance.residual || step_norm <= Self::width(&tolerance, &x) {
+                return system.result(x, f_x, iteration, true);
+            }
+        }
+
+        system.result(x, f_x, tolerance.max_iterations, false)
+    }
+
+    // Broyden's "good" method: the finite difference Jacobian at the guess is updated with the secant
+    // condition J_new s = F(x + s) - F(x), and recomputed whenever the update makes it singular.
+    pub fn broyden<const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        function: F,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        let system = System::new(function);
+        let mut x = guess.to_vec();
+        let mut f_x = system.evaluate::<N>(&x)?;
+
+        if norm(&f_x) <= tolerance.residual {
+            return system.result(x, f_x, 0, true);
+        }
+
+        let mut jacobian = system.jacobian::<N>(&x);
+
+        for iteration in 1..=tolerance.max_iterations {
+            let lu = match jacobian.lu() {
+                Some(lu) => lu,
+                None => {
+                    jacobian = system.jacobian::<N>(&x);
+                    jacobian
+                        .lu()
+                        .ok_or_else(|| SystemError::SingularJacobian { x: x.clone() })?
+                }
+            };
+
+            let step: Vec<f64> = lu.solve(&f_x).iter().map(|s| -s).collect();
+            let x_next: Vec<f64> = x.iter().zip(&step).map(|(x, s)| x + s).collect();
+            let f_next = system.evaluate::<N>(&x_next)?;
+
+            let predicted = multiply(&jacobian, &step);
+            let step_squared = dot(&step, &step);
+            if step_squared > 0.0 {
+                for i in 0..N {
+                    let mismatch = (f_next[i] - f_x[i] - predicted[i]) / step_squared;
+                    for j in 0..N {
+                        jacobian[i][j] += mismatch * step[j];
+                    }
+                }
+            }
+
+            x = x_next;
+            f_x = f_next;
+
+            if norm(&f_x) <= tolerance.residual
+                || step_squared.sqrt() <= Self::width(&tolerance, &x)
+            {
+                return system.result(x, f_x, iteration, true);
+            }
+        }
+
+        system.result(x, f_x, tolerance.max_iterations, false)
+    }
+
+    // Powell's dogleg trust region method on the model |F(x) + J p|² / 2. The step follows the steepest
+    // descent direction up to the Cauchy point and then turns towards the Newton step, and the trust radius
+    // grows or shrinks with the ratio between the actual and predicted reductions.
+    pub fn dogleg<const N: usize, F: Fn(&[f64]) -> Vec<f64>>(
+        function: F,
+        guess: &[f64; N],
+        tolerance: RootTolerance,
+    ) -> Result<SystemResult, SystemError> {
+        let system = System::new(function);
+        let mut x = guess.to_vec();
+        let mut f_x = system.evaluate::<N>(&x)?;
+
+        if norm(&f_x) <= tolerance.residual {
+            return system.result(x, f_x, 0, true);
+        }
+
+        let mut radius = norm(&x).max(1.0);
+        let mut jacobian = system.jacobian::<N>(&x);
+
+        for iteration in 1..=tolerance.max_iterations {
+            let gradient = multiply_transpose(&jacobian, &f_x);
+            let gradient_norm = norm(&gradient);
+            if gradient_norm == 0.0 {
+                return Err(SystemError::SingularJacobian { x });
+            }
+
+            let newton: Option<Vec<f64>> = jacobian
+                .lu()
+                .map(|lu| lu.solve(&f_x).iter().map(|s| -s).collect());
+
+            if let Some(newton) = &newton {
+                if norm(newton) <= Self::width(&tolerance, &x) {
+                    let x_next: Vec<f64> = x.iter().zip(newton).map(|(x, s)| x + s).collect();
+                    let f_next = system.evaluate::<N>(&x_next)?;
+                    return system.result(x_next, f_next, iteration, true);
+                }
+            }
+
+            let curvature = norm(&multiply(&jacobian, &gradient)).powi(2);
+            let cauchy: Vec<f64> = gradient
+                .iter()
+                .map(|g| -gradient_norm.powi(2) / curvature * g)
+                .collect();
+
+            let step: Vec<f64> = match newton {
+                Some(newton) if norm(&newton) <= radius => newton,
+                Some(newton) if norm(&cauchy) < radius => {
+                    // Solves |cauchy + τ (newton - cauchy)| = radius for τ in [0, 1].
+                    let difference: Vec<f64> =
+                        newton.iter().zip(&cauchy).map(|(n, c)| n - c).collect();
+                    let a = dot(&difference, &difference);
+                    let b = 2.0 * dot(&cauchy, &difference);
+                    let c = dot(&cauchy, &cauchy) - radius * radius;
+                    let tau = (-b + (b * b - 4.0 * a * c).sqrt()) / (2.0 * a);
+                    cauchy
+                        .iter()
+                        .zip(&difference)
+                        .map(|(c, d)| c + tau * d)
+                        .collect()
+                }
+                _ => gradient
+                    .iter()
+                    .map(|g| -radius / gradient_norm * g)
+                    .collect(),
+            };
+
+            let step_norm = norm(&step);
+            let linear: Vec<f64> = multiply(&jacobian, &step)
+                .iter()
+                .zip(&f_x)
+                .map(|(js, f)| js + f)
+                .collect();
+            let predicted = 0.5 * (norm(&f_x).powi(2) - norm(&linear).powi(2));
+
+            let x_next: Vec<f64> = x.iter().zip(&step).map(|(x, s)| x + s).collect();
+            let f_next = system.call::<N>(&x_next);
+            let actual = if f_next.iter().all(|value| value.is_finite()) {
+                0.5 * (norm(&f_x).powi(2) - norm(&f_next).powi(2))
+            } else {
+                f64::NEG_INFINITY
+            };
+            let ratio = actual / predicted;
+
+            if ratio < 0.25 {
+                radius = 0.25 * step_norm;
+            } else if ratio > 0.75 && step_norm >= 0.99 * radius {
+                radius *= 2.0;
+            }
+
+            if ratio > 1e-4 {
+                x = x_next;
+                f_x = f_next;
+
+                if norm(&f_x) <= tolerance.residual || step_norm <= Self::width(&tolerance, &x) {
+                    return system.result(x, f_x, iteration, true);
+                }
+
+                jacobian = system.jacobian::<N>(&x);
+            } else if radius <= Self::width(&tolerance, &x) {
+                return system.result(x, f_x, iteration, false);
+            }
+        }
+
+        system.result(x, f_x, tolerance.max_iterations, false)
+    }
+}
@@ -0,0 +1,563 @@
+use crate::Complex;
+
+/// A module containing functions to evaluate polynomials and find their roots.
+///
+/// Polynomials are given as a slice of coefficients ordered from the highest degree term down to
+/// the constant term, so `[1, 0, -2]` is `x^2 - 2`. Real (`f64`) and `Complex` coefficients are both accepted.
+pub struct Polynomial;
+
+const MAX_ABERTH_ITERATIONS: usize = 500;
+
+const MAX_QR_ITERATIONS: usize = 60;
+
+const MAX_POLISH_ITERATIONS: usize = 10;
+
+impl Polynomial {
+    /// Evaluates a polynomial at a Complex point using Horner's method.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    /// - `z`: The point at which the polynomial will be evaluated.
+    ///
+    /// # Returns
+    ///
+    /// The value of the polynomial at `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Complex, Polynomial};
+    ///
+    /// let coefficients = [1_f64, 0_f64, 1_f64];
+    /// let z = Complex::new(0.0, 1.0);
+    ///
+    /// let value = Polynomial::evaluate(&coefficients, z);
+    ///
+    /// println!("z^2 + 1 at z = {} is: {}", z, value);
+    /// ```
+    /// <hr/>
+    pub fn evaluate<T: Into<Complex> + Copy>(coefficients: &[T], z: Complex) -> Complex {
+        coefficients
+            .iter()
+            .fold(Complex::ZERO, |acc, &coefficient| {
+                acc * z + coefficient.into()
+            })
+    }
+
+    /// Finds every root, real and complex, of a polynomial.
+    ///
+    /// The roots are found with the Aberth-Ehrlich method, falling back to the eigenvalues of the
+    /// companion matrix if it does not converge. Clusters of roots that belong to a repeated root are
+    /// then detected and refined, and every root is polished with Newton's method.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the roots, with repeated roots listed once per multiplicity. Polynomials of degree
+    /// zero (or with only zero coefficients) have no roots and return an empty vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Polynomial;
+    ///
+    /// let coefficients = [1_f64, -3_f64, 4_f64, -2_f64];
+    ///
+    /// let roots = Polynomial::roots(&coefficients);
+    ///
+    /// for root in roots {
+    ///     println!("Root of x^3 - 3x^2 + 4x - 2: {}", root);
+    /// }
+    /// ```
+    /// <hr/>
+    pub fn roots<T: Into<Complex> + Copy>(coefficients: &[T]) -> Vec<Complex> {
+        Self::roots_with_multiplicity(coefficients, 0.0)
+            .into_iter()
+            .flat_map(|(root, multiplicity)| std::iter::repeat_n(root, multiplicity))
+            .collect()
+    }
+
+    /// Finds every distinct root of a polynomial along with its multiplicity.
+    ///
+    /// Repeated roots are only accurate to roughly `ε^(1/m)` when found individually, so roots whose
+    /// inclusion disks overlap (or that are closer than `tolerance` relative to their magnitude) are grouped
+    /// into one root of multiplicity `m`, which is then refined with Newton's method on the `(m - 1)`th derivative.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    /// - `tolerance`: An additional relative distance (ie: `1e-8`) under which two roots are considered equal, `0` relies on the inclusion disks alone.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(root, multiplicity)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Polynomial;
+    ///
+    /// // (x - 1)^3 (x + 2)
+    /// let coefficients = [1_f64, -1_f64, -3_f64, 5_f64, -2_f64];
+    ///
+    /// let roots = Polynomial::roots_with_multiplicity(&coefficients, 0.0);
+    ///
+    /// for (root, multiplicity) in roots {
+    ///     println!("Root {} with multiplicity {}", root, multiplicity);
+    /// }
+    /// ```
+    /// <hr/>
+    pub fn roots_with_multiplicity<T: Into<Complex> + Copy>(
+        coefficients: &[T],
+        tolerance: f64,
+    ) -> Vec<(Complex, usize)> {
+        let (coefficients, zero_roots) = Self::normalize(coefficients);
+        let mut result = Vec::new();
+
+        if zero_roots > 0 {
+            result.push((Complex::ZERO, zero_roots));
+        }
+
+        if coefficients.len() < 2 {
+            return result;
+        }
+
+        let roots = match Self::aberth_ehrlich(&coefficients, MAX_ABERTH_ITERATIONS) {
+            (roots, true) => roots,
+            (_, false) => Self::companion_eigenvalues(&coefficients),
+        };
+
+        for (center, multiplicity) in Self::cluster(&coefficients, &roots, tolerance) {
+            let derivative = Self::nth_derivative(&coefficients, multiplicity - 1);
+            result.push((Self::newton(&derivative, center), multiplicity));
+        }
+
+        result
+    }
+
+    /// Finds every root of a polynomial with the Aberth-Ehrlich method.
+    ///
+    /// The roots are refined simultaneously, starting from points spread on a circle, and are not polished afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    /// - `max_iterations`: The maximum number of sweeps over all of the roots.
+    ///
+    /// # Returns
+    ///
+    /// The approximate roots, or `None` if the iteration did not converge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Polynomial;
+    ///
+    /// let coefficients = [1_f64, 0_f64, 1_f64];
+    ///
+    /// let roots = Polynomial::aberth(&coefficients, 100).unwrap();
+    ///
+    /// println!("Roots of x^2 + 1: {:?}", roots);
+    /// ```
+    /// <hr/>
+    pub fn aberth<T: Into<Complex> + Copy>(
+        coefficients: &[T],
+        max_iterations: usize,
+    ) -> Option<Vec<Complex>> {
+        let (coefficients, zero_roots) = Self::normalize(coefficients);
+        let mut roots = vec![Complex::ZERO; zero_roots];
+
+        if coefficients.len() >= 2 {
+            let (found, converged) = Self::aberth_ehrlich(&coefficients, max_iterations);
+            if !converged {
+                return None;
+            }
+            roots.extend(found);
+        }
+
+        Some(roots)
+    }
+
+    /// Finds every root of a polynomial as the eigenvalues of its companion matrix.
+    ///
+    /// The eigenvalues are found with the shifted QR algorithm, which is slower than [`Polynomial::aberth`]
+    /// (`O(n^3)` instead of `O(n^2)` per sweep) but is very robust.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    ///
+    /// # Returns
+    ///
+    /// The approximate roots of the polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::Polynomial;
+    ///
+    /// let coefficients = [1_f64, -6_f64, 11_f64, -6_f64];
+    ///
+    /// let roots = Polynomial::companion_roots(&coefficients);
+    ///
+    /// println!("Roots of x^3 - 6x^2 + 11x - 6: {:?}", roots);
+    /// ```
+    /// <hr/>
+    pub fn companion_roots<T: Into<Complex> + Copy>(coefficients: &[T]) -> Vec<Complex> {
+        let (coefficients, zero_roots) = Self::normalize(coefficients);
+        let mut roots = vec![Complex::ZERO; zero_roots];
+
+        if coefficients.len() >= 2 {
+            roots.extend(Self::companion_eigenvalues(&coefficients));
+        }
+
+        roots
+    }
+
+    /// Polishes approximate roots of a polynomial with Newton's method.
+    ///
+    /// Each root is refined until the Newton step stops shrinking, which is usually one or two iterations.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficients`: The coefficients of the polynomial, from the highest degree term down to the constant term.
+    /// - `roots`: The approximate roots to polish.
+    ///
+    /// # Returns
+    ///
+    /// The polished roots, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::{Complex, Polynomial};
+    ///
+    /// let coefficients = [1_f64, 0_f64, -2_f64];
+    /// let roots = [Complex::new(1.4, 0.0), Complex::new(-1.4, 0.0)];
+    ///
+    /// let polished = Polynomial::polish(&coefficients, &roots);
+    ///
+    /// println!("Polished Roots of x^2 - 2: {:?}", polished);
+    /// ```
+    /// <hr/>
+    pub fn polish<T: Into<Complex> + Copy>(coefficients: &[T], roots: &[Complex]) -> Vec<Complex> {
+        let coefficients: Vec<Complex> = coefficients.iter().map(|&c| c.into()).collect();
+
+        roots
+            .iter()
+            .map(|&root| Self::newton(&coefficients, root))
+            .collect()
+    }
+
+    // Converts the coefficients to Complex numbers, strips leading zeros and factors out roots at zero.
+    fn normalize<T: Into<Complex> + Copy>(coefficients: &[T]) -> (Vec<Complex>, usize) {
+        let mut coefficients: Vec<Complex> = coefficients
+            .iter()
+            .map(|&c| c.into())
+            .skip_while(|c| *c == Complex::ZERO)
+            .collect();
+
+        let mut zero_roots = 0;
+        while coefficients.len() > 1 && coefficients[coefficients.len() - 1] == Complex::ZERO {
+            coefficients.pop();
+            zero_roots += 1;
+        }
+
+        (coefficients, zero_roots)
+    }
+
+    // Evaluates the polynomial and its derivative at `z`, along with a bound on the rounding error of the value.
+    fn evaluate_with_derivative(coefficients: &[Complex], z: Complex) -> (Complex, Complex, f64) {
+        let magnitude = z.magnitude();
+        let mut value = coefficients[0];
+        let mut derivative = Complex::ZERO;
+        let mut error_bound = coefficients[0].magnitude();
+
+        for &coefficient in &coefficients[1..] {
+            derivative = derivative * z + value;
+            value = value * z + coefficient;
+            error_bound = error_bound * magnitude + value.magnitude();
+        }
+
+        (value, derivative, 4.0 * f64::EPSILON * error_bound)
+    }
+
+    fn aberth_ehrlich(coefficients: &[Complex], max_iterations: usize) -> (Vec<Complex>, bool) {
+        let degree = coefficients.len() - 1;
+
+        if degree == 1 {
+            return (vec![-coefficients[1] / coefficients[0]], true);
+        }
+
+        // Start on a circle whose radius is the geometric mean of the root magnitudes, slightly
+        // rotated so that no starting point lies on a symmetry axis of a real polynomial.
+        let radius = (coefficients[degree] / coefficients[0])
+            .magnitude()
+            .powf(1.0 / degree as f64);
+        let radius = if radius.is_finite() && radius > 0.0 {
+            radius
+        } else {
+            1.0
+        };
+
+        let mut roots: Vec<Complex> = (0..degree)
+            .map(|k| {
+                let angle = std::f64::consts::TAU * k as f64 / degree as f64 + 0.4;
+                Complex::from_polar(radius, angle)
+            })
+            .collect();
+        let mut converged = vec![false; degree];
+
+        for _ in 0..max_iterations {
+            for i in 0..degree {
+                if converged[i] {
+                    continue;
+                }
+
+                let (value, derivative, error_bound) =
+                    Self::evaluate_with_derivative(coefficients, roots[i]);
+
+                if value.magnitude() <= error_bound {
+                    converged[i] = true;
+                    continue;
+                }
+
+                let ratio = value / derivative;
+                let repulsion: Complex = (0..degree)
+                    .filter(|&j| j != i)
+                    .map(|j| (roots[i] - roots[j]).recip())
+                    .sum();
+                let correction = ratio / (1.0 - ratio * repulsion);
+
+                if !correction.real_part().is_finite() || !correction.imag_part().is_finite() {
+                    continue;
+                }
+
+                roots[i] -= correction;
+
+                if correction.magnitude() <= f64::EPSILON * roots[i].magnitude() {
+                    converged[i] = true;
+                }
+            }
+
+            if converged.iter().all(|&c| c) {
+                return (roots, true);
+            }
+        }
+
+        (roots, false)
+    }
+
+    fn companion_eigenvalues(coefficients: &[Complex]) -> Vec<Complex> {
+        let degree = coefficients.len() - 1;
+        let mut h = vec![vec![Complex::ZERO; degree]; degree];
+
+        for (j, coefficient) in coefficients[1..].iter().enumerate() {
+            h[0][j] = -*coefficient / coefficients[0];
+        }
+        for (i, row) in h.iter_mut().enumerate().skip(1) {
+            row[i - 1] = Complex::ONE;
+        }
+
+        Self::hessenberg_eigenvalues(h)
+    }
+
+    // Shifted QR algorithm on a complex upper Hessenberg matrix, using Givens rotations and Wilkinson shifts.
+    fn hessenberg_eigenvalues(mut h: Vec<Vec<Complex>>) -> Vec<Complex> {
+        let n = h.len();
+        let mut eigenvalues = Vec::with_capacity(n);
+        let mut high = n;
+        let mut iterations = 0;
+
+        while high > 0 {
+            let last = high - 1;
+
+            let mut low = last;
+            while low > 0 {
+                let scale = h[low][low].magnitude() + h[low - 1][low - 1].magnitude();
+                if h[low][low - 1].magnitude() <= f64::EPSILON * scale {
+                    h[low][low - 1] = Complex::ZERO;
+                    break;
+                }
+                low -= 1;
+            }
+
+            if low == last || iterations >= MAX_QR_ITERATIONS {
+                eigenvalues.push(h[last][last]);
+                high -= 1;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+
+            let shift = if iterations % 11 == 0 {
+                // Exceptional shift to break cycles
+                h[last][last] + h[last][last - 1].magnitude() * 0.75
+            } else {
+                let a = h[last - 1][last - 1];
+                let b = h[last - 1][last];
+                let c = h[last][last - 1];
+                let d = h[last][last];
+                let half_trace = (a + d) / 2.0;
+                let discriminant = (((a - d) / 2.0) * ((a - d) / 2.0) + b * c).sqrt();
+                let first = half_trace + discriminant;
+                let second = half_trace - discriminant;
+                if (first - d).magnitude() < (second - d).magnitude() {
+                    first
+                } else {
+                    second
+                }
+            };
+
+            for (i, row) in h.iter_mut().enumerate().take(high).skip(low) {
+                row[i] -= shift;
+            }
+
+            let mut rotations = Vec::with_capacity(last - low);
+            for j in low..last {
+                let a = h[j][j];
+                let b = h[j + 1][j];
+                let norm = a.magnitude().hypot(b.magnitude());
+                let (c, s) = if norm == 0.0 {
+                    (1.0, Complex::ZERO)
+                } else if a.magnitude() == 0.0 {
+                    (0.0, Complex::ONE)
+                } else {
+                    let phase = a / a.magnitude();
+                    (a.magnitude() / norm, phase * b.conj() / norm)
+                };
+
+                let (upper, lower) = h.split_at_mut(j + 1);
+                for (x, y) in upper[j][j..high].iter_mut().zip(&mut lower[0][j..high]) {
+                    let (top, bottom) = (*x, *y);
+                    *x = c * top + s * bottom;
+                    *y = -(s.conj() * top) + c * bottom;
+                }
+
+                rotations.push((c, s));
+            }
+
+            for (offset, &(c, s)) in rotations.iter().enumerate() {
+                let j = low + offset;
+                for row in h.iter_mut().take((j + 2).min(last) + 1).skip(low) {
+                    let x = row[j];
+                    let y = row[j + 1];
+                    row[j] = c * x + s.conj() * y;
+                    row[j + 1] = -(s * x) + c * y;
+                }
+            }
+
+            for (i, row) in h.iter_mut().enumerate().take(high).skip(low) {
+                row[i] += shift;
+            }
+        }
+
+        eigenvalues
+    }
+
+    // Groups roots whose inclusion disks overlap, or that are within `tolerance` of each other, into
+    // (centroid, multiplicity) pairs. The inclusion disk of a root has radius n|W|, where W is its
+    // Weierstrass correction, and the union of these disks is guaranteed to contain every root.
+    fn cluster(
+        coefficients: &[Complex],
+        roots: &[Complex],
+        tolerance: f64,
+    ) -> Vec<(Complex, usize)> {
+        let degree = roots.len();
+        let radii: Vec<f64> = (0..degree)
+            .map(|i| {
+                let (value, _, error_bound) =
+                    Self::evaluate_with_derivative(coefficients, roots[i]);
+                let denominator: Complex = (0..degree)
+                    .filter(|&j| j != i)
+                    .map(|j| roots[i] - roots[j])
+                    .product::<Complex>()
+                    * coefficients[0];
+                degree as f64 * value.magnitude().max(error_bound) / denominator.magnitude()
+            })
+            .collect();
+
+        let mut assigned = vec![false; degree];
+        let mut clusters = Vec::new();
+
+        for i in 0..degree {
+            if assigned[i] {
+                continue;
+            }
+
+            assigned[i] = true;
+            let mut members = vec![i];
+
+            // Grow the cluster until no unassigned root overlaps any of its members
+            let mut index = 0;
+            while index < members.len() {
+                let member = members[index];
+                let scale = roots[member].magnitude().max(1.0);
+                for j in 0..degree {
+                    let distance = (roots[j] - roots[member]).magnitude();
+                    if !assigned[j]
+                        && (distance <= radii[j] + radii[member] || distance <= tolerance * scale)
+                    {
+                        assigned[j] = true;
+                        members.push(j);
+                    }
+                }
+                index += 1;
+            }
+
+            let multiplicity = members.len();
+            let centroid = members.iter().map(|&j| roots[j]).sum::<Complex>() / multiplicity as f64;
+            clusters.push((centroid, multiplicity));
+        }
+
+        clusters
+    }
+
+    fn nth_derivative(coefficients: &[Complex], order: usize) -> Vec<Complex> {
+        let mut derivative = coefficients.to_vec();
+
+        for _ in 0..order {
+            let degree = derivative.len() - 1;
+            derivative = derivative[..degree]
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| c * (degree - i) as f64)
+                .collect();
+        }
+
+        derivative
+    }
+
+    fn newton(coefficients: &[Complex], mut root: Complex) -> Complex {
+        if coefficients.len() < 2 {
+            return root;
+        }
+
+        let mut previous_step = f64::INFINITY;
+
+        for _ in 0..MAX_POLISH_ITERATIONS {
+            let (value, derivative, error_bound) =
+                Self::evaluate_with_derivative(coefficients, root);
+
+            if value.magnitude() <= error_bound || derivative == Complex::ZERO {
+                break;
+            }
+
+            let step = value / derivative;
+
+            if step.magnitude() >= previous_step {
+                break;
+            }
+
+            root -= step;
+            previous_step = step.magnitude();
+        }
+
+        root
+    }
+}
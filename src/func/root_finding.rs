@@ -0,0 +1,572 @@
+use std::fmt;
+
+/// The stopping criteria of the root finders.
+///
+/// An iteration stops when the root is known to within `absolute + relative * |x|`, or when
+/// `|f(x)| <= residual`. The `Default` tolerances are `2e-12`, `4ε`, `0` and 100 iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootTolerance {
+    /// The absolute tolerance on the root.
+    pub absolute: f64,
+    /// The tolerance on the root relative to its magnitude.
+    pub relative: f64,
+    /// The value of `|f(x)|` below which `x` is accepted as a root.
+    pub residual: f64,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for RootTolerance {
+    fn default() -> Self {
+        Self {
+            absolute: 2e-12,
+            relative: 4.0 * f64::EPSILON,
+            residual: 0.0,
+            max_iterations: 100,
+        }
+    }
+}
+
+impl RootTolerance {
+    pub(crate) fn width(&self, x: f64) -> f64 {
+        self.absolute + self.relative * x.abs()
+    }
+}
+
+/// The result of a root finder.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootResult {
+    /// The approximation of the root.
+    pub root: f64,
+    /// The value of the function at `root`.
+    pub function_value: f64,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The number of times the function was evaluated.
+    pub evaluations: usize,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+/// The reasons a root finder can fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError {
+    /// The function has the same sign at both ends of the bracket.
+    NotBracketed { f_lower: f64, f_upper: f64 },
+    /// The function returned NaN or an infinite value at `x`.
+    NonFiniteValue { x: f64 },
+    /// The slope used for the next step vanished at `x`.
+    ZeroSlope { x: f64 },
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NotBracketed { f_lower, f_upper } => write!(
+                f,
+                "the root is not bracketed: f(lower) = {} and f(upper) = {} have the same sign",
+                f_lower, f_upper
+            ),
+            RootError::NonFiniteValue { x } => {
+                write!(f, "the function is not finite at x = {}", x)
+            }
+            RootError::ZeroSlope { x } => write!(f, "the slope vanished at x = {}", x),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+// The function values at the ends of a valid bracket, or the result when an end is already a root.
+enum Bracket {
+    Values(f64, f64),
+    Endpoint(RootResult),
+}
+
+pub struct RootFinding;
+
+impl RootFinding {
+    fn evaluate<F: Fn(f64) -> f64>(
+        function: &F,
+        x: f64,
+        evaluations: &mut usize,
+    ) -> Result<f64, RootError> {
+        *evaluations += 1;
+        let value = function(x);
+
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(RootError::NonFiniteValue { x })
+        }
+    }
+
+    // Evaluates the function at both ends of a bracket and checks that their signs differ.
+    fn bracket<F: Fn(f64) -> f64>(
+        function: &F,
+        lower: f64,
+        upper: f64,
+        evaluations: &mut usize,
+    ) -> Result<Bracket, RootError> {
+        let f_lower = Self::evaluate(function, lower, evaluations)?;
+        let f_upper = Self::evaluate(function, upper, evaluations)?;
+
+        for (x, value) in [(lower, f_lower), (upper, f_upper)] {
+            if value == 0.0 {
+                return Ok(Bracket::Endpoint(RootResult {
+                    root: x,
+                    function_value: 0.0,
+                    iterations: 0,
+                    evaluations: *evaluations,
+                    converged: true,
+                }));
+            }
+        }
+
+        if f_lower.signum() == f_upper.signum() {
+            return Err(RootError::NotBracketed { f_lower, f_upper });
+        }
+
+        Ok(Bracket::Values(f_lower, f_upper))
+    }
+
+    pub fn result(
+        root: f64,
+        function_value: f64,
+        iterations: usize,
+        evaluations: usize,
+        converged: bool,
+    ) -> RootResult {
+        RootResult {
+            root,
+            function_value,
+            iterations,
+            evaluations,
+            converged,
+        }
+    }
+
+    pub fn bisection<F: Fn(f64) -> f64>(
+        function: F,
+        mut lower: f64,
+        mut upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let (mut f_lower, _) = match Self::bracket(&function, lower, upper, &mut evaluations)? {
+            Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+            Bracket::Endpoint(result) => return Ok(result),
+        };
+
+        let mut middle = lower;
+        let mut f_middle = f_lower;
+
+        for iteration in 1..=tolerance.max_iterations {
+            middle = lower + 0.5 * (upper - lower);
+            f_middle = Self::evaluate(&function, middle, &mut evaluations)?;
+
+            if f_middle.abs() <= tolerance.residual
+                || (0.5 * (upper - lower)).abs() <= tolerance.width(middle)
+            {
+                return Ok(Self::result(middle, f_middle, iteration, evaluations, true));
+            }
+
+            if f_middle.signum() == f_lower.signum() {
+                lower = middle;
+                f_lower = f_middle;
+            } else {
+                upper = middle;
+            }
+        }
+
+        Ok(Self::result(
+            middle,
+            f_middle,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+
+    // Brent's method (zeroin): inverse quadratic interpolation or secant steps that fall back to bisection
+    // whenever they do not shrink the bracket quickly enough.
+    pub fn brent<F: Fn(f64) -> f64>(
+        function: F,
+        lower: f64,
+        upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let (f_lower, f_upper) = match Self::bracket(&function, lower, upper, &mut evaluations)? {
+            Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+            Bracket::Endpoint(result) => return Ok(result),
+        };
+
+        // `current` is the best estimate, `previous` the last one and `block` the other end of the bracket.
+        let (mut previous, mut f_previous) = (lower, f_lower);
+        let (mut current, mut f_current) = (upper, f_upper);
+        let (mut block, mut f_block) = (lower, f_lower);
+        let mut step_previous = upper - lower;
+        let mut step_current = step_previous;
+
+        for iteration in 1..=tolerance.max_iterations {
+            if f_previous.signum() != f_current.signum() {
+                block = previous;
+                f_block = f_previous;
+                step_previous = current - previous;
+                step_current = step_previous;
+            }
+
+            if f_block.abs() < f_current.abs() {
+                previous = current;
+                current = block;
+                block = previous;
+                f_previous = f_current;
+                f_current = f_block;
+                f_block = f_previous;
+            }
+
+            let delta = 0.5 * tolerance.width(current);
+            let bisect = 0.5 * (block - current);
+
+            if f_current.abs() <= tolerance.residual || bisect.abs() <= delta {
+                return Ok(Self::result(
+                    current,
+                    f_current,
+                    iteration - 1,
+                    evaluations,
+                    true,
+                ));
+            }
+
+            if step_previous.abs() > delta && f_current.abs() < f_previous.abs() {
+                let trial = if previous == block {
+                    -f_current * (current - previous) / (f_current - f_previous)
+                } else {
+                    let d_previous = (f_previous - f_current) / (previous - current);
+                    let d_block = (f_block - f_current) / (block - current);
+                    -f_current * (f_block * d_block - f_previous * d_previous)
+                        / (d_block * d_previous * (f_block - f_previous))
+                };
+
+                if 2.0 * trial.abs() < step_previous.abs().min(3.0 * bisect.abs() - delta) {
+                    step_previous = step_current;
+                    step_current = trial;
+                } else {
+                    step_previous = bisect;
+                    step_current = bisect;
+                }
+            } else {
+                step_previous = bisect;
+                step_current = bisect;
+            }
+
+            previous = current;
+            f_previous = f_current;
+
+            current += if step_current.abs() > delta {
+                step_current
+            } else {
+                delta.copysign(bisect)
+            };
+            f_current = Self::evaluate(&function, current, &mut evaluations)?;
+        }
+
+        let converged = f_current.abs() <= tolerance.residual;
+        Ok(Self::result(
+            current,
+            f_current,
+            tolerance.max_iterations,
+            evaluations,
+            converged,
+        ))
+    }
+
+    pub fn ridders<F: Fn(f64) -> f64>(
+        function: F,
+        mut lower: f64,
+        mut upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let (mut f_lower, mut f_upper) =
+            match Self::bracket(&function, lower, upper, &mut evaluations)? {
+                Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+                Bracket::Endpoint(result) => return Ok(result),
+            };
+
+        let mut x = lower;
+        let mut f_x = f_lower;
+
+        for iteration in 1..=tolerance.max_iterations {
+            let middle = 0.5 * (lower + upper);
+            let f_middle = Self::evaluate(&function, middle, &mut evaluations)?;
+
+            // Fits an exponential through the three points; the root of the fit is the new estimate.
+            let s = (f_middle * f_middle - f_lower * f_upper).sqrt();
+            if s == 0.0 {
+                return Ok(Self::result(middle, f_middle, iteration, evaluations, true));
+            }
+
+            let previous = x;
+            x = middle + (middle - lower) * (f_lower - f_upper).signum() * f_middle / s;
+            f_x = Self::evaluate(&function, x, &mut evaluations)?;
+
+            if f_middle.signum() != f_x.signum() {
+                lower = middle;
+                f_lower = f_middle;
+                upper = x;
+                f_upper = f_x;
+            } else if f_lower.signum() != f_x.signum() {
+                upper = x;
+                f_upper = f_x;
+            } else {
+                lower = x;
+                f_lower = f_x;
+            }
+
+            if f_x.abs() <= tolerance.residual
+                || (x - previous).abs() <= tolerance.width(x)
+                || (upper - lower).abs() <= tolerance.width(x)
+            {
+                return Ok(Self::result(x, f_x, iteration, evaluations, true));
+            }
+        }
+
+        Ok(Self::result(
+            x,
+            f_x,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+
+    // The Illinois variant of regula falsi: the function value kept at an end of the bracket is halved whenever
+    // that end is retained twice in a row, which prevents one end from getting stuck.
+    pub fn illinois<F: Fn(f64) -> f64>(
+        function: F,
+        mut lower: f64,
+        mut upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let (mut f_lower, mut f_upper) =
+            match Self::bracket(&function, lower, upper, &mut evaluations)? {
+                Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+                Bracket::Endpoint(result) => return Ok(result),
+            };
+
+        let mut x = lower;
+        let mut f_x = f_lower;
+        let mut retained = 0_i8;
+
+        for iteration in 1..=tolerance.max_iterations {
+            let previous = x;
+            x = (lower * f_upper - upper * f_lower) / (f_upper - f_lower);
+            f_x = Self::evaluate(&function, x, &mut evaluations)?;
+
+            if f_x.signum() == f_upper.signum() {
+                upper = x;
+                f_upper = f_x;
+                if retained == -1 {
+                    f_lower *= 0.5;
+                }
+                retained = -1;
+            } else {
+                lower = x;
+                f_lower = f_x;
+                if retained == 1 {
+                    f_upper *= 0.5;
+                }
+                retained = 1;
+            }
+
+            if f_x.abs() <= tolerance.residual
+                || (iteration > 1 && (x - previous).abs() <= tolerance.width(x))
+                || (upper - lower).abs() <= tolerance.width(x)
+            {
+                return Ok(Self::result(x, f_x, iteration, evaluations, true));
+            }
+        }
+
+        Ok(Self::result(
+            x,
+            f_x,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+
+    // The ITP method (Oliveira and Takahashi): a regula falsi estimate, truncated towards the midpoint and
+    // projected into a shrinking interval around it, so that it never needs more iterations than bisection
+    // plus one, while converging superlinearly for smooth functions.
+    pub fn itp<F: Fn(f64) -> f64>(
+        function: F,
+        mut lower: f64,
+        mut upper: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let (mut f_lower, mut f_upper) =
+            match Self::bracket(&function, lower, upper, &mut evaluations)? {
+                Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+                Bracket::Endpoint(result) => return Ok(result),
+            };
+
+        let epsilon = (0.5 * tolerance.width(lower.abs().max(upper.abs()))).max(f64::MIN_POSITIVE);
+        let kappa1 = 0.2 / (upper - lower).abs();
+        let kappa2 = 2.0;
+        let slack = 1.0;
+        let max_steps = ((upper - lower).abs() / (2.0 * epsilon))
+            .log2()
+            .ceil()
+            .max(0.0)
+            + slack;
+
+        let mut x = 0.5 * (lower + upper);
+        let mut f_x = f_lower;
+
+        for iteration in 1..=tolerance.max_iterations {
+            let width = upper - lower;
+            let middle = 0.5 * (lower + upper);
+
+            if width.abs() <= 2.0 * epsilon {
+                let f_middle = Self::evaluate(&function, middle, &mut evaluations)?;
+                return Ok(Self::result(
+                    middle,
+                    f_middle,
+                    iteration - 1,
+                    evaluations,
+                    true,
+                ));
+            }
+
+            let radius = (epsilon * 2_f64.powf(max_steps - (iteration - 1) as f64)
+                - 0.5 * width.abs())
+            .max(0.0);
+            let delta = kappa1 * width.abs().powf(kappa2);
+
+            // Interpolation, truncation and projection.
+            let falsi = (f_upper * lower - f_lower * upper) / (f_upper - f_lower);
+            let sigma = (middle - falsi).signum();
+            let truncated = if delta <= (middle - falsi).abs() {
+                falsi + sigma * delta
+            } else {
+                middle
+            };
+
+            let previous = x;
+            x = if (truncated - middle).abs() <= radius {
+                truncated
+            } else {
+                middle - sigma * radius
+            };
+            f_x = Self::evaluate(&function, x, &mut evaluations)?;
+
+            // Once the interpolation lands on the root from the same side, the bracket only shrinks through the
+            // projection, so a negligible step is also accepted.
+            if f_x.abs() <= tolerance.residual
+                || (iteration > 1 && (x - previous).abs() <= tolerance.width(x))
+            {
+                return Ok(Self::result(x, f_x, iteration, evaluations, true));
+            }
+
+            if f_x.signum() == f_lower.signum() {
+                lower = x;
+                f_lower = f_x;
+            } else {
+                upper = x;
+                f_upper = f_x;
+            }
+        }
+
+        Ok(Self::result(
+            x,
+            f_x,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+
+    pub fn secant<F: Fn(f64) -> f64>(
+        function: F,
+        mut x0: f64,
+        mut x1: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let mut f0 = Self::evaluate(&function, x0, &mut evaluations)?;
+        let mut f1 = Self::evaluate(&function, x1, &mut evaluations)?;
+
+        if f1 == 0.0 {
+            return Ok(Self::result(x1, f1, 0, evaluations, true));
+        }
+
+        for iteration in 1..=tolerance.max_iterations {
+            if f1 == f0 {
+                return Err(RootError::ZeroSlope { x: x1 });
+            }
+
+            let x2 = x1 - f1 * (x1 - x0) / (f1 - f0);
+            let f2 = Self::evaluate(&function, x2, &mut evaluations)?;
+
+            x0 = x1;
+            f0 = f1;
+            x1 = x2;
+            f1 = f2;
+
+            if f1.abs() <= tolerance.residual || (x1 - x0).abs() <= tolerance.width(x1) {
+                return Ok(Self::result(x1, f1, iteration, evaluations, true));
+            }
+        }
+
+        Ok(Self::result(
+            x1,
+            f1,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+
+    // Steffensen's method replaces the derivative of Newton's method with the slope (f(x + f(x)) - f(x)) / f(x),
+    // which keeps quadratic convergence without derivatives once the iterate is close to the root.
+    pub fn steffensen<F: Fn(f64) -> f64>(
+        function: F,
+        mut x: f64,
+        tolerance: RootTolerance,
+    ) -> Result<RootResult, RootError> {
+        let mut evaluations = 0;
+        let mut f_x = Self::evaluate(&function, x, &mut evaluations)?;
+
+        if f_x == 0.0 {
+            return Ok(Self::result(x, f_x, 0, evaluations, true));
+        }
+
+        for iteration in 1..=tolerance.max_iterations {
+            let slope = (Self::evaluate(&function, x + f_x, &mut evaluations)? - f_x) / f_x;
+            if slope == 0.0 {
+                return Err(RootError::ZeroSlope { x });
+            }
+
+            let step = f_x / slope;
+            x -= step;
+            f_x = Self::evaluate(&function, x, &mut evaluations)?;
+
+            if f_x.abs() <= tolerance.residual || step.abs() <= tolerance.width(x) {
+                return Ok(Self::result(x, f_x, iteration, evaluations, true));
+            }
+        }
+
+        Ok(Self::result(
+            x,
+            f_x,
+            tolerance.max_iterations,
+            evaluations,
+            false,
+        ))
+    }
+}
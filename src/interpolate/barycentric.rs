@@ -0,0 +1,194 @@
+use crate::interpolate::extrapolation::{validate, Extrapolation};
+use crate::Functions;
+
+/// The polynomial of degree at most `n - 1` through `n` data points `(x[i], y[i])` with strictly increasing
+/// `x`, evaluated with the second barycentric form of Lagrange interpolation.
+///
+/// The barycentric form evaluates the polynomial stably in O(n) operations after computing the weights in
+/// O(n²), and is well conditioned for nodes that cluster at the ends such as Chebyshev points. On
+/// equispaced nodes high degree polynomials oscillate near the ends (Runge's phenomenon), where one of the
+/// piecewise [`Interpolant`](crate::interpolate::Interpolant)s is the better choice.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::interpolate::Barycentric;
+///
+/// // Chebyshev points of the second kind on [-1, 1].
+/// let x: Vec<f64> = (0..=16).rev().map(|k| (k as f64 * std::f64::consts::PI / 16_f64).cos()).collect();
+/// let y: Vec<f64> = x.iter().map(|x| 1_f64 / (1_f64 + 25_f64 * x * x)).collect();
+///
+/// let polynomial = Barycentric::new(&x, &y);
+///
+/// println!("f(0.3) ≈ {}", polynomial.evaluate(0.3));
+/// ```
+/// <hr/>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Barycentric {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    weights: Vec<f64>,
+    extrapolation: Extrapolation,
+}
+
+impl Barycentric {
+    /// Creates the interpolating polynomial of the data points.
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length, there are less than two points, or `x` is not strictly
+    /// increasing.
+    pub fn new(x: &[f64], y: &[f64]) -> Self {
+        validate(x, y);
+
+        // w[j] = 1 / Π (x[j] - x[k]), with the differences scaled by 4 / (b - a) so the products neither
+        // overflow nor underflow for many nodes. A common factor of the weights cancels in the quotient.
+        let capacity = 4.0 / (x[x.len() - 1] - x[0]);
+        let weights = x
+            .iter()
+            .enumerate()
+            .map(|(j, xj)| {
+                let product = x
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != j)
+                    .fold(1.0, |product, (_, xk)| product * capacity * (xj - xk));
+                1.0 / product
+            })
+            .collect();
+
+        Self {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            weights,
+            extrapolation: Extrapolation::Extrapolate,
+        }
+    }
+
+    /// Sets the [`Extrapolation`] outside the data, which by default continues the polynomial.
+    pub fn extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Evaluates the polynomial at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.derivative(x, 0)
+    }
+
+    /// Evaluates the derivative of the given order at `x`, where the order zero is the polynomial itself.
+    ///
+    /// The derivatives at the nodes are obtained by applying the barycentric differentiation matrix to the
+    /// values `order` times, which costs O(order n²), and then interpolated like the values.
+    pub fn derivative(&self, x: f64, order: usize) -> f64 {
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+
+        if (first..=last).contains(&x) {
+            self.polynomial(x, order)
+        } else {
+            self.extrapolation.derivative(
+                x,
+                order,
+                first,
+                last,
+                |x, order| self.polynomial(x, order),
+                |x, order| self.polynomial(x, order),
+            )
+        }
+    }
+
+    /// Integrates the polynomial from `lower` to `upper` exactly with Gauss–Legendre quadrature of
+    /// `⌈n / 2⌉` nodes, with the [`Extrapolation`] outside the data.
+    pub fn integrate(&self, lower: f64, upper: f64) -> f64 {
+        if lower > upper {
+            return -self.integrate(upper, lower);
+        }
+
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+        let nodes = self.x.len().div_ceil(2);
+        let exact =
+            |a: f64, b: f64| Functions::gauss_legendre(|x: f64| self.polynomial(x, 0), a, b, nodes);
+        let end = |x: f64, order: usize| self.polynomial(x, order);
+        let mut integral = 0.0;
+
+        if lower < first {
+            integral += self
+                .extrapolation
+                .integral(lower, upper.min(first), first, end, exact);
+        }
+        if upper > last {
+            integral += self
+                .extrapolation
+                .integral(lower.max(last), upper, last, end, exact);
+        }
+
+        let (a, b) = (lower.max(first), upper.min(last));
+        if a < b {
+            integral += exact(a, b);
+        }
+
+        integral
+    }
+
+    /// Returns the polynomial as a function of `x`, for example for [`Functions::simpson`].
+    pub fn as_fn(&self) -> impl Fn(f64) -> f64 + '_ {
+        move |x: f64| self.evaluate(x)
+    }
+
+    // The values of the derivative of the given order at the nodes. Row i of the differentiation matrix
+    // holds (w[j] / w[i]) / (x[i] - x[j]) off the diagonal and minus their sum on it.
+    fn nodal_derivative(&self, order: usize) -> Vec<f64> {
+        let mut values = self.y.clone();
+
+        for _ in 0..order {
+            values = (0..self.x.len())
+                .map(|i| {
+                    let (xi, wi, vi) = (self.x[i], self.weights[i], values[i]);
+
+                    self.x
+                        .iter()
+                        .zip(&self.weights)
+                        .zip(&values)
+                        .enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .map(|(_, ((xj, wj), vj))| (wj / wi) / (xi - xj) * (vj - vi))
+                        .sum()
+                })
+                .collect();
+        }
+
+        values
+    }
+
+    // The barycentric formula Σ w[j] v[j] / (x - x[j]) / Σ w[j] / (x - x[j]) for the derivative values v of
+    // the given order, which are themselves a polynomial of degree at most n - 1 - order.
+    fn polynomial(&self, x: f64, order: usize) -> f64 {
+        if order >= self.x.len() {
+            return 0.0;
+        }
+
+        let derivatives;
+        let values = if order == 0 {
+            &self.y
+        } else {
+            derivatives = self.nodal_derivative(order);
+            &derivatives
+        };
+
+        if let Some(j) = self.x.iter().position(|&xj| xj == x) {
+            return values[j];
+        }
+
+        let (numerator, denominator) = self.x.iter().zip(&self.weights).zip(values).fold(
+            (0.0, 0.0),
+            |(numerator, denominator), ((xj, wj), vj)| {
+                let term = wj / (x - xj);
+                (numerator + term * vj, denominator + term)
+            },
+        );
+
+        numerator / denominator
+    }
+}
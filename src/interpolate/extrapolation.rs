@@ -0,0 +1,77 @@
+/// How an interpolant is evaluated, differentiated and integrated outside the range of its data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extrapolation {
+    /// Continues the polynomial of the first or last interval, or the interpolating polynomial.
+    Extrapolate,
+    /// Continues along the tangent at the first or last data point.
+    Linear,
+    /// Keeps the value at the first or last data point.
+    Constant,
+    /// Returns the given value, for example `f64::NAN`, for the interpolant and all its derivatives.
+    Fill(f64),
+}
+
+impl Extrapolation {
+    // The derivative of the given order at x outside [first, last]. `end(x, order)` evaluates the
+    // interpolant at a data point and `extend(x, order)` continues its polynomial beyond the data.
+    pub(crate) fn derivative<E, X>(
+        self,
+        x: f64,
+        order: usize,
+        first: f64,
+        last: f64,
+        end: E,
+        extend: X,
+    ) -> f64
+    where
+        E: Fn(f64, usize) -> f64,
+        X: Fn(f64, usize) -> f64,
+    {
+        let nearest = if x < first { first } else { last };
+
+        match (self, order) {
+            (Extrapolation::Extrapolate, _) => extend(x, order),
+            (Extrapolation::Linear, 0) => end(nearest, 0) + end(nearest, 1) * (x - nearest),
+            (Extrapolation::Linear, 1) => end(nearest, 1),
+            (Extrapolation::Constant, 0) => end(nearest, 0),
+            (Extrapolation::Fill(value), _) => value,
+            _ => 0.0,
+        }
+    }
+
+    // The integral from `lower` to `upper`, which both lie on the same side outside the data and next to
+    // the data point `nearest`. `extend(a, b)` integrates the continued polynomial.
+    pub(crate) fn integral<E, X>(
+        self,
+        lower: f64,
+        upper: f64,
+        nearest: f64,
+        end: E,
+        extend: X,
+    ) -> f64
+    where
+        E: Fn(f64, usize) -> f64,
+        X: Fn(f64, f64) -> f64,
+    {
+        let length = upper - lower;
+
+        match self {
+            Extrapolation::Extrapolate => extend(lower, upper),
+            Extrapolation::Linear => {
+                length * (end(nearest, 0) + end(nearest, 1) * (0.5 * (lower + upper) - nearest))
+            }
+            Extrapolation::Constant => length * end(nearest, 0),
+            Extrapolation::Fill(value) => length * value,
+        }
+    }
+}
+
+// Checks that the data has at least two points with strictly increasing abscissas.
+pub(crate) fn validate(x: &[f64], y: &[f64]) {
+    assert_eq!(x.len(), y.len(), "x and y must have the same length!");
+    assert!(x.len() >= 2, "At least two data points are needed!");
+    assert!(
+        x.windows(2).all(|pair| pair[0] < pair[1]),
+        "x must be strictly increasing!"
+    );
+}
@@ -0,0 +1,8 @@
+mod barycentric;
+mod extrapolation;
+mod piecewise;
+mod slopes;
+
+pub use barycentric::Barycentric;
+pub use extrapolation::Extrapolation;
+pub use piecewise::{Interpolant, SplineBoundary};
@@ -0,0 +1,303 @@
+use crate::interpolate::extrapolation::{validate, Extrapolation};
+use crate::interpolate::slopes::Slopes;
+
+/// The end conditions of a cubic spline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary {
+    /// A vanishing second derivative at both ends.
+    Natural,
+    /// The given first derivatives at the first and the last data point.
+    Clamped(f64, f64),
+    /// A continuous third derivative at the second and the second to last data point, so the first two and
+    /// the last two intervals are each one cubic, as in MATLAB's `spline`.
+    NotAKnot,
+}
+
+/// A piecewise polynomial interpolant of data points `(x[i], y[i])` with strictly increasing `x`.
+///
+/// Every interval holds a polynomial of degree at most three, whose values, derivatives and integrals are
+/// evaluated exactly. The interpolant is created with one of the methods below, optionally configured with
+/// the [`Extrapolation`] outside the data, and passed as a function with [`Interpolant::as_fn`].
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::interpolate::{Interpolant, SplineBoundary};
+///
+/// let x = [0_f64, 1_f64, 2_f64, 3_f64, 4_f64];
+/// let y = x.map(|x: f64| x.sin());
+///
+/// let spline = Interpolant::cubic_spline(&x, &y, SplineBoundary::NotAKnot);
+///
+/// println!("sin(2.5) ≈ {}", spline.evaluate(2.5));
+/// println!("cos(2.5) ≈ {}", spline.derivative(2.5, 1));
+/// println!("1 - cos(4) ≈ {}", spline.integrate(0_f64, 4_f64));
+/// ```
+/// <hr/>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpolant {
+    x: Vec<f64>,
+    // The coefficients of y[i] + b t + c t² + d t³ with t = x - x[i] on every interval.
+    coefficients: Vec<[f64; 4]>,
+    // The integrals from x[0] to every data point.
+    cumulative: Vec<f64>,
+    extrapolation: Extrapolation,
+}
+
+impl Interpolant {
+    /// Creates the piecewise linear interpolant, which connects the data points by straight lines.
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length, there are less than two points, or `x` is not strictly
+    /// increasing.
+    pub fn linear(x: &[f64], y: &[f64]) -> Self {
+        validate(x, y);
+
+        let coefficients = x
+            .windows(2)
+            .zip(y.windows(2))
+            .map(|(x, y)| [y[0], (y[1] - y[0]) / (x[1] - x[0]), 0.0, 0.0])
+            .collect();
+
+        Self::from_coefficients(x, coefficients)
+    }
+
+    /// Creates the cubic spline with the given end conditions, the twice continuously differentiable
+    /// piecewise cubic through the data points. With two data points it is the line through them, or the
+    /// cubic with the clamped slopes, and with three a not-a-knot spline is the parabola through them.
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length, there are less than two points, or `x` is not strictly
+    /// increasing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::interpolate::{Interpolant, SplineBoundary};
+    ///
+    /// let x = [0_f64, 0.5, 1.0, 1.5, 2.0];
+    /// let y = x.map(|x: f64| x.exp());
+    ///
+    /// // The exact slopes at the ends make the error O(h⁴).
+    /// let spline = Interpolant::cubic_spline(&x, &y, SplineBoundary::Clamped(1_f64, 2_f64.exp()));
+    ///
+    /// println!("e^1.25 ≈ {}", spline.evaluate(1.25));
+    /// ```
+    /// <hr/>
+    pub fn cubic_spline(x: &[f64], y: &[f64], boundary: SplineBoundary) -> Self {
+        validate(x, y);
+        let (steps, secants) = Self::secants(x, y);
+        Self::hermite(x, y, &Slopes::spline(&steps, &secants, boundary))
+    }
+
+    /// Creates the monotone piecewise cubic Hermite interpolant (PCHIP) of Fritsch and Carlson, which is
+    /// continuously differentiable and preserves the monotonicity of the data, so it neither overshoots
+    /// nor oscillates.
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length, there are less than two points, or `x` is not strictly
+    /// increasing.
+    pub fn pchip(x: &[f64], y: &[f64]) -> Self {
+        validate(x, y);
+        let (steps, secants) = Self::secants(x, y);
+        Self::hermite(x, y, &Slopes::pchip(&steps, &secants))
+    }
+
+    /// Creates Akima's continuously differentiable piecewise cubic interpolant, whose slopes depend only on
+    /// the nearby data, so it wiggles less than a cubic spline around outliers and sudden changes.
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length, there are less than two points, or `x` is not strictly
+    /// increasing.
+    pub fn akima(x: &[f64], y: &[f64]) -> Self {
+        validate(x, y);
+        let (_, secants) = Self::secants(x, y);
+        Self::hermite(x, y, &Slopes::akima(&secants))
+    }
+
+    /// Sets the [`Extrapolation`] outside the data, which by default continues the end polynomials.
+    pub fn extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Evaluates the interpolant at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.derivative(x, 0)
+    }
+
+    /// Evaluates the derivative of the given order at `x`, where the order zero is the interpolant itself.
+    /// At an interior data point the derivative of the interval to its right is used, and derivatives above
+    /// the third order vanish.
+    pub fn derivative(&self, x: f64, order: usize) -> f64 {
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+
+        if (first..=last).contains(&x) {
+            self.piece(x, order)
+        } else {
+            self.extrapolation.derivative(
+                x,
+                order,
+                first,
+                last,
+                |x, order| self.end(x, order),
+                |x, order| self.piece(x, order),
+            )
+        }
+    }
+
+    /// Integrates the interpolant from `lower` to `upper`, with the [`Extrapolation`] outside the data.
+    pub fn integrate(&self, lower: f64, upper: f64) -> f64 {
+        if lower > upper {
+            return -self.integrate(upper, lower);
+        }
+
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+        let extend = |a: f64, b: f64| self.antiderivative(b) - self.antiderivative(a);
+        let end = |x: f64, order: usize| self.end(x, order);
+        let mut integral = 0.0;
+
+        if lower < first {
+            integral += self
+                .extrapolation
+                .integral(lower, upper.min(first), first, end, extend);
+        }
+        if upper > last {
+            integral += self
+                .extrapolation
+                .integral(lower.max(last), upper, last, end, extend);
+        }
+
+        let (a, b) = (lower.max(first), upper.min(last));
+        if a < b {
+            integral += self.antiderivative(b) - self.antiderivative(a);
+        }
+
+        integral
+    }
+
+    /// Returns the interpolant as a function of `x`, for example for [`Functions::simpson`](crate::Functions).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::interpolate::Interpolant;
+    /// use numerilib::Functions;
+    ///
+    /// let x = [0_f64, 1_f64, 2_f64, 3_f64];
+    /// let y = [0_f64, 1_f64, 4_f64, 9_f64];
+    ///
+    /// let interpolant = Interpolant::pchip(&x, &y);
+    /// let integral = Functions::simpson(interpolant.as_fn(), 0_f64, 3_f64, 300_f64);
+    ///
+    /// println!("The integral is {} or exactly {}", integral, interpolant.integrate(0_f64, 3_f64));
+    /// ```
+    /// <hr/>
+    pub fn as_fn(&self) -> impl Fn(f64) -> f64 + '_ {
+        move |x: f64| self.evaluate(x)
+    }
+
+    fn secants(x: &[f64], y: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let steps: Vec<f64> = x.windows(2).map(|x| x[1] - x[0]).collect();
+        let secants = y
+            .windows(2)
+            .zip(&steps)
+            .map(|(y, h)| (y[1] - y[0]) / h)
+            .collect();
+        (steps, secants)
+    }
+
+    // The cubic Hermite polynomial on every interval with the given slopes at the data points.
+    fn hermite(x: &[f64], y: &[f64], slopes: &[f64]) -> Self {
+        let coefficients = (0..x.len() - 1)
+            .map(|i| {
+                let h = x[i + 1] - x[i];
+                let secant = (y[i + 1] - y[i]) / h;
+                let (left, right) = (slopes[i], slopes[i + 1]);
+
+                [
+                    y[i],
+                    left,
+                    (3.0 * secant - 2.0 * left - right) / h,
+                    (left + right - 2.0 * secant) / (h * h),
+                ]
+            })
+            .collect();
+
+        Self::from_coefficients(x, coefficients)
+    }
+
+    fn from_coefficients(x: &[f64], coefficients: Vec<[f64; 4]>) -> Self {
+        let mut cumulative = Vec::with_capacity(x.len());
+        cumulative.push(0.0);
+
+        for (i, piece) in coefficients.iter().enumerate() {
+            let integral = Self::primitive(piece, x[i + 1] - x[i]);
+            cumulative.push(cumulative[i] + integral);
+        }
+
+        Self {
+            x: x.to_vec(),
+            coefficients,
+            cumulative,
+            extrapolation: Extrapolation::Extrapolate,
+        }
+    }
+
+    // The interval whose polynomial is used at x, where the end intervals extend beyond the data.
+    fn interval(&self, x: f64) -> usize {
+        self.x
+            .partition_point(|&knot| knot <= x)
+            .clamp(1, self.x.len() - 1)
+            - 1
+    }
+
+    // The derivative of the given order of the polynomial of the interval at x.
+    fn piece(&self, x: f64, order: usize) -> f64 {
+        let i = self.interval(x);
+        let [a, b, c, d] = self.coefficients[i];
+        let t = x - self.x[i];
+
+        match order {
+            0 => a + t * (b + t * (c + t * d)),
+            1 => b + t * (2.0 * c + 3.0 * t * d),
+            2 => 2.0 * c + 6.0 * t * d,
+            3 => 6.0 * d,
+            _ => 0.0,
+        }
+    }
+
+    // The one-sided derivative at the first or the last data point from inside the data.
+    fn end(&self, x: f64, order: usize) -> f64 {
+        let i = if x <= self.x[0] {
+            0
+        } else {
+            self.coefficients.len() - 1
+        };
+        let [a, b, c, d] = self.coefficients[i];
+        let t = x - self.x[i];
+
+        match order {
+            0 => a + t * (b + t * (c + t * d)),
+            _ => b + t * (2.0 * c + 3.0 * t * d),
+        }
+    }
+
+    // ∫ (a + b t + c t² + d t³) dt from 0 to t.
+    fn primitive(piece: &[f64; 4], t: f64) -> f64 {
+        let [a, b, c, d] = *piece;
+        t * (a + t * (b / 2.0 + t * (c / 3.0 + t * d / 4.0)))
+    }
+
+    // The integral from x[0] to x of the polynomial pieces, continued beyond the data.
+    fn antiderivative(&self, x: f64) -> f64 {
+        let i = self.interval(x);
+        self.cumulative[i] + Self::primitive(&self.coefficients[i], x - self.x[i])
+    }
+}
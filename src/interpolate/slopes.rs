@@ -0,0 +1,149 @@
+use crate::interpolate::piecewise::SplineBoundary;
+use crate::matrix::BandedLu;
+
+// The slopes of the interpolating cubics at the data points, from which every interval is the cubic Hermite
+// polynomial through its end points. `steps[i]` is x[i + 1] - x[i] and `secants[i]` the slope of the chord
+// over it.
+pub struct Slopes;
+
+impl Slopes {
+    // The cubic spline, which is twice continuously differentiable. The slopes solve the tridiagonal system
+    //     h[i] m[i - 1] + 2 (h[i - 1] + h[i]) m[i] + h[i - 1] m[i + 1] = 3 (h[i] δ[i - 1] + h[i - 1] δ[i])
+    // closed by the boundary conditions.
+    pub fn spline(steps: &[f64], secants: &[f64], boundary: SplineBoundary) -> Vec<f64> {
+        let n = steps.len() + 1;
+
+        if n == 2 {
+            return match boundary {
+                SplineBoundary::Clamped(first, last) => vec![first, last],
+                _ => vec![secants[0]; 2],
+            };
+        }
+
+        if n == 3 && boundary == SplineBoundary::NotAKnot {
+            // The single parabola through the three points.
+            let curvature = (secants[1] - secants[0]) / (steps[0] + steps[1]);
+            return vec![
+                secants[0] - curvature * steps[0],
+                secants[0] + curvature * steps[0],
+                secants[1] + curvature * steps[1],
+            ];
+        }
+
+        let mut bands = Vec::with_capacity(n);
+        let mut rhs = Vec::with_capacity(n);
+        let (h, d) = (steps, secants);
+
+        match boundary {
+            SplineBoundary::Natural => {
+                bands.push(vec![0.0, 2.0, 1.0]);
+                rhs.push(3.0 * d[0]);
+            }
+            SplineBoundary::Clamped(first, _) => {
+                bands.push(vec![0.0, 1.0, 0.0]);
+                rhs.push(first);
+            }
+            SplineBoundary::NotAKnot => {
+                let sum = h[0] + h[1];
+                bands.push(vec![0.0, h[1], sum]);
+                rhs.push(((h[0] + 2.0 * sum) * h[1] * d[0] + h[0] * h[0] * d[1]) / sum);
+            }
+        }
+
+        for i in 1..n - 1 {
+            bands.push(vec![h[i], 2.0 * (h[i - 1] + h[i]), h[i - 1]]);
+            rhs.push(3.0 * (h[i] * d[i - 1] + h[i - 1] * d[i]));
+        }
+
+        let (a, b) = (n - 3, n - 2);
+        match boundary {
+            SplineBoundary::Natural => {
+                bands.push(vec![1.0, 2.0, 0.0]);
+                rhs.push(3.0 * d[b]);
+            }
+            SplineBoundary::Clamped(_, last) => {
+                bands.push(vec![0.0, 1.0, 0.0]);
+                rhs.push(last);
+            }
+            SplineBoundary::NotAKnot => {
+                let sum = h[a] + h[b];
+                bands.push(vec![sum, h[a], 0.0]);
+                rhs.push((h[b] * h[b] * d[a] + (2.0 * sum + h[b]) * h[a] * d[b]) / sum);
+            }
+        }
+
+        // The system is diagonally dominant apart from the not-a-knot rows, which stay well conditioned.
+        BandedLu::new(1, 1, bands)
+            .expect("The spline system is not singular for increasing abscissas!")
+            .solve(&rhs)
+    }
+
+    // The monotone piecewise cubic Hermite slopes of Fritsch and Carlson: the weighted harmonic mean of the
+    // neighbouring secants where they have the same sign, zero at local extrema, and a shape preserving
+    // three point formula at the ends, as in MATLAB's `pchip` and SciPy's `PchipInterpolator`.
+    pub fn pchip(steps: &[f64], secants: &[f64]) -> Vec<f64> {
+        let n = steps.len() + 1;
+        if n == 2 {
+            return vec![secants[0]; 2];
+        }
+
+        let (h, d) = (steps, secants);
+        let mut slopes = vec![0.0; n];
+
+        for i in 1..n - 1 {
+            if d[i - 1] * d[i] > 0.0 {
+                let first = 2.0 * h[i] + h[i - 1];
+                let second = h[i] + 2.0 * h[i - 1];
+                slopes[i] = (first + second) / (first / d[i - 1] + second / d[i]);
+            }
+        }
+
+        let end = |h0: f64, h1: f64, d0: f64, d1: f64| {
+            let slope = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+
+            if slope.signum() != d0.signum() || d0 == 0.0 {
+                0.0
+            } else if d0.signum() != d1.signum() && slope.abs() > 3.0 * d0.abs() {
+                3.0 * d0
+            } else {
+                slope
+            }
+        };
+
+        slopes[0] = end(h[0], h[1], d[0], d[1]);
+        slopes[n - 1] = end(h[n - 2], h[n - 3], d[n - 2], d[n - 3]);
+        slopes
+    }
+
+    // Akima's slopes, which weigh the two neighbouring secants by the change of the secants on the far
+    // side, so that an outlier only affects the nearby intervals. Two secants are extrapolated linearly
+    // beyond each end, and the mean of the neighbouring secants is used where both weights vanish.
+    pub fn akima(secants: &[f64]) -> Vec<f64> {
+        let n = secants.len() + 1;
+        if n == 2 {
+            return vec![secants[0]; 2];
+        }
+
+        let m = secants.len();
+        let mut extended = Vec::with_capacity(m + 4);
+        let before = 2.0 * secants[0] - secants[1];
+        extended.extend([2.0 * before - secants[0], before]);
+        extended.extend_from_slice(secants);
+        let after = 2.0 * secants[m - 1] - secants[m - 2];
+        extended.extend([after, 2.0 * after - secants[m - 1]]);
+
+        extended
+            .windows(4)
+            .map(|window| {
+                let left = (window[3] - window[2]).abs();
+                let right = (window[1] - window[0]).abs();
+
+                if left + right > 0.0 {
+                    (left * window[1] + right * window[2]) / (left + right)
+                } else {
+                    0.5 * (window[1] + window[2])
+                }
+            })
+            .collect()
+    }
+}
@@ -1,8 +1,19 @@
 mod complex;
+mod dual;
 #[allow(clippy::needless_doctest_main)]
 pub(crate) mod extra;
 mod func;
 mod matrix;
+mod quaternion;
+
+/// modules for interpolating one-dimensional data (Splines, PCHIP, Akima, Barycentric, etc.)
+pub mod interpolate;
+
+/// modules for solving Ordinary Differential Equations (Runge-Kutta, etc.)
+pub mod ode;
+
+/// modules for minimizing multivariate functions and fitting curves (Nelder-Mead, BFGS, Levenberg-Marquardt, Simplex, etc.)
+pub mod optimize;
 
 /// modules for calculating Special Functions (Gamma, Beta, Error, etc.)
 pub mod special;
@@ -11,5 +22,7 @@ pub mod special;
 pub mod stats;
 
 pub use complex::*;
+pub use dual::*;
 pub use func::*;
 pub use matrix::*;
+pub use quaternion::*;
@@ -0,0 +1,109 @@
+// The LU decomposition with partial pivoting of a banded matrix with `lower` sub-diagonals and `upper`
+// super-diagonals, in O(n (lower + upper) lower) operations and O(n (2 lower + upper)) memory.
+//
+// Row i stores the columns from i - lower to i + lower + upper, the extra `lower` columns holding the fill
+// created by the row interchanges.
+pub(crate) struct BandedLu {
+    lower: usize,
+    upper: usize,
+    rows: Vec<Vec<f64>>,
+    multipliers: Vec<Vec<f64>>,
+    pivots: Vec<usize>,
+}
+
+impl BandedLu {
+    // `bands[i]` holds the entries of row i in the columns from i - lower to i + upper, where the entries
+    // outside of the matrix are ignored.
+    pub(crate) fn new(lower: usize, upper: usize, bands: Vec<Vec<f64>>) -> Option<Self> {
+        let n = bands.len();
+        let width = 2 * lower + upper + 1;
+
+        let rows = bands
+            .into_iter()
+            .map(|mut band| {
+                assert_eq!(
+                    band.len(),
+                    lower + upper + 1,
+                    "Every band must have {} values!",
+                    lower + upper + 1
+                );
+                band.resize(width, 0.0);
+                band
+            })
+            .collect();
+
+        let mut lu = Self {
+            lower,
+            upper,
+            rows,
+            multipliers: vec![vec![0.0; lower]; n],
+            pivots: (0..n).collect(),
+        };
+
+        for k in 0..n {
+            let last_row = (k + lower).min(n - 1);
+            let last_column = (k + lower + upper).min(n - 1);
+
+            let pivot = (k..=last_row)
+                .max_by(|&a, &b| lu.get(a, k).abs().total_cmp(&lu.get(b, k).abs()))
+                .unwrap_or(k);
+            let pivot_value = lu.get(pivot, k);
+
+            if pivot_value == 0.0 || !pivot_value.is_finite() {
+                return None;
+            }
+
+            if pivot != k {
+                for j in k..=last_column {
+                    let value = lu.get(k, j);
+                    lu.set(k, j, lu.get(pivot, j));
+                    lu.set(pivot, j, value);
+                }
+            }
+            lu.pivots[k] = pivot;
+
+            for i in k + 1..=last_row {
+                let multiplier = lu.get(i, k) / pivot_value;
+                lu.multipliers[k][i - k - 1] = multiplier;
+
+                for j in k + 1..=last_column {
+                    lu.set(i, j, lu.get(i, j) - multiplier * lu.get(k, j));
+                }
+            }
+        }
+
+        Some(lu)
+    }
+
+    // Column j of row i, which requires j + lower >= i.
+    fn get(&self, i: usize, j: usize) -> f64 {
+        self.rows[i][j + self.lower - i]
+    }
+
+    fn set(&mut self, i: usize, j: usize, value: f64) {
+        self.rows[i][j + self.lower - i] = value;
+    }
+
+    pub(crate) fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        let n = self.rows.len();
+        assert_eq!(rhs.len(), n, "The right hand side must have {} values!", n);
+
+        let mut x = rhs.to_vec();
+
+        for k in 0..n {
+            x.swap(k, self.pivots[k]);
+
+            for i in k + 1..=(k + self.lower).min(n - 1) {
+                x[i] -= self.multipliers[k][i - k - 1] * x[k];
+            }
+        }
+
+        for k in (0..n).rev() {
+            let last_column = (k + self.lower + self.upper).min(n - 1);
+            let sum: f64 = (k + 1..=last_column).map(|j| self.get(k, j) * x[j]).sum();
+            x[k] = (x[k] - sum) / self.get(k, k);
+        }
+
+        x
+    }
+}
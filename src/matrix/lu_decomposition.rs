@@ -0,0 +1,176 @@
+use crate::Matrix;
+
+// The LU decomposition with partial pivoting of a square matrix whose size is only known at run time,
+// which is shared with the solvers that work on slices.
+pub(crate) struct DenseLu {
+    factors: Vec<Vec<f64>>,
+    permutation: Vec<usize>,
+    sign: f64,
+}
+
+impl DenseLu {
+    pub(crate) fn new(mut factors: Vec<Vec<f64>>) -> Option<Self> {
+        let n = factors.len();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| factors[a][k].abs().total_cmp(&factors[b][k].abs()))
+                .unwrap_or(k);
+
+            if factors[pivot][k] == 0.0 || !factors[pivot][k].is_finite() {
+                return None;
+            }
+
+            if pivot != k {
+                factors.swap(pivot, k);
+                permutation.swap(pivot, k);
+                sign = -sign;
+            }
+
+            let (upper, lower) = factors.split_at_mut(k + 1);
+            let pivot_row = &upper[k];
+
+            for row in lower.iter_mut() {
+                let multiplier = row[k] / pivot_row[k];
+                row[k] = multiplier;
+
+                for (value, pivot_value) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *value -= multiplier * pivot_value;
+                }
+            }
+        }
+
+        Some(Self {
+            factors,
+            permutation,
+            sign,
+        })
+    }
+
+    pub(crate) fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        let n = self.factors.len();
+        assert_eq!(rhs.len(), n, "The right hand side must have {} values!", n);
+
+        let mut x: Vec<f64> = self.permutation.iter().map(|&row| rhs[row]).collect();
+
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| self.factors[i][j] * x[j]).sum();
+            x[i] -= sum;
+        }
+
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|j| self.factors[i][j] * x[j]).sum();
+            x[i] = (x[i] - sum) / self.factors[i][i];
+        }
+
+        x
+    }
+}
+
+/// The LU decomposition `PA = LU` of a square matrix with partial pivoting.
+///
+/// `L` is unit lower triangular and `U` is upper triangular. Both are stored in a single matrix, and `P`
+/// is stored as the row of `A` that ended up in each row.
+pub struct LuDecomposition<const N: usize>(DenseLu);
+
+impl<const N: usize> Matrix<N, N> {
+    /// Calculates the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// # Returns
+    ///
+    /// The decomposition, or `None` if the matrix is singular or contains values that are not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Matrix;
+    ///
+    /// let matrix = Matrix::new([
+    ///     [2_f64, 1_f64],
+    ///     [4_f64, 3_f64]
+    /// ]);
+    ///
+    /// let lu = matrix.lu().unwrap();
+    ///
+    /// println!("L:\n{:?}\nU:\n{:?}", lu.lower(), lu.upper());
+    /// ```
+    /// <hr/>
+    pub fn lu(&self) -> Option<LuDecomposition<N>> {
+        let rows = (0..N).map(|i| self[i].clone()).collect();
+        DenseLu::new(rows).map(LuDecomposition)
+    }
+
+    /// Solves the linear system `Ax = b` with the LU decomposition of the matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `rhs`: The right hand side `b` with `N` values.
+    ///
+    /// # Returns
+    ///
+    /// The solution `x`, or `None` if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` does not have `N` values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Matrix;
+    ///
+    /// let matrix = Matrix::new([
+    ///     [2_f64, 1_f64],
+    ///     [4_f64, 3_f64]
+    /// ]);
+    ///
+    /// let x = matrix.solve(&[3_f64, 7_f64]).unwrap();
+    ///
+    /// println!("The solution is: {:?}", x);
+    /// ```
+    /// <hr/>
+    pub fn solve(&self, rhs: &[f64]) -> Option<Vec<f64>> {
+        self.lu().map(|lu| lu.solve(rhs))
+    }
+}
+
+impl<const N: usize> LuDecomposition<N> {
+    /// Returns the unit lower triangular factor `L`.
+    pub fn lower(&self) -> Matrix<N, N> {
+        let mut lower = Matrix::identity();
+        for i in 0..N {
+            lower[i][..i].copy_from_slice(&self.0.factors[i][..i]);
+        }
+        lower
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn upper(&self) -> Matrix<N, N> {
+        let mut upper = Matrix::new([[0.0; N]; N]);
+        for i in 0..N {
+            upper[i][i..].copy_from_slice(&self.0.factors[i][i..]);
+        }
+        upper
+    }
+
+    /// Returns the row permutation, where row `i` of `PA` is row `permutation[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.0.permutation
+    }
+
+    /// Calculates the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> f64 {
+        (0..N).fold(self.0.sign, |product, i| product * self.0.factors[i][i])
+    }
+
+    /// Solves `Ax = b` by forward and back substitution.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` does not have `N` values.
+    pub fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        self.0.solve(rhs)
+    }
+}
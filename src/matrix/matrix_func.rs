@@ -2,6 +2,7 @@ use std::fmt;
 use std::ops::{Index, IndexMut};
 
 /// A module containing Matrix and Matrix Functions.
+#[derive(Clone, PartialEq)]
 pub struct Matrix<const ROWS: usize, const COLS: usize>(Vec<Vec<f64>>);
 
 impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS> {
@@ -420,7 +421,6 @@ impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS> {
     ///         Multiplication:\n{:?}\n
     ///         Division:\n{:?}\n
     ///         Power:\n{:?}", matrix1, matrix2, result_addition, result_subtraction, result_multiplication, result_division, result_exponentiation);
-    /// }
     ///
     /// /*
     ///     Outputs the Following:
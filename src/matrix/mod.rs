@@ -1,3 +1,9 @@
+mod banded;
+mod lu_decomposition;
 mod matrix_func;
+mod vector_func;
 
+pub(crate) use banded::BandedLu;
+pub use lu_decomposition::*;
 pub use matrix_func::*;
+pub use vector_func::*;
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A column vector with a fixed number of elements.
+#[derive(Clone, PartialEq)]
+pub struct Vector<const N: usize>(Vec<f64>);
+
+impl<const N: usize> Vector<N> {
+    /// Creates a new vector from an array of elements.
+    ///
+    /// # Parameters
+    ///
+    /// - `elements`: An array containing the elements of the vector.
+    ///
+    /// # Returns
+    ///
+    /// A new vector containing the elements provided in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let vector = Vector::new([1_f64, 2_f64, 3_f64]);
+    ///
+    /// println!("Vector: {:?}", vector);
+    ///
+    /// /*
+    ///     Outputs the following:
+    ///     ----------------------
+    ///     Vector: [1, 2, 3]
+    /// */
+    /// ```
+    /// <hr/>
+    pub fn new(elements: [f64; N]) -> Self {
+        Self(elements.to_vec())
+    }
+
+    /// Returns the value at a specific index (1-based) of the vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index (1-based) of the value.
+    ///
+    /// # Returns
+    ///
+    /// The value at the given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let vector = Vector::new([1_f64, 2_f64, 3_f64]);
+    ///
+    /// println!("The second element is: {}", vector.get(2));
+    /// ```
+    /// <hr/>
+    pub fn get(&self, index: usize) -> f64 {
+        self.0[index - 1]
+    }
+
+    /// Updates the value at a specific index (1-based) of the vector.
+    ///
+    /// If the index is out of bounds, this function does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index (1-based) where the value should be updated.
+    /// - `new_value`: The new value to set at the specified position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let mut vector = Vector::new([1_f64, 2_f64, 3_f64]);
+    /// vector.update(2, 10_f64);
+    ///
+    /// println!("Updated Vector: {:?}", vector);
+    /// ```
+    /// <hr/>
+    pub fn update(&mut self, index: usize, new_value: f64) {
+        if let Some(element) = self.0.get_mut(index - 1) {
+            *element = new_value;
+        }
+    }
+
+    /// Returns the number of elements of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let vector = Vector::new([1_f64, 2_f64, 3_f64]);
+    ///
+    /// println!("Vector Dimension: {}", vector.dimension());
+    /// ```
+    /// <hr/>
+    pub fn dimension(&self) -> usize {
+        N
+    }
+
+    /// Calculates the dot product of two vectors.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The other vector.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the products of the corresponding elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let a = Vector::new([1_f64, 2_f64, 3_f64]);
+    /// let b = Vector::new([4_f64, 5_f64, 6_f64]);
+    ///
+    /// println!("The dot product is: {}", a.dot(&b));
+    /// ```
+    /// <hr/>
+    pub fn dot(&self, other: &Vector<N>) -> f64 {
+        self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum()
+    }
+
+    /// Calculates the Euclidean norm of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let vector = Vector::new([3_f64, 4_f64]);
+    ///
+    /// println!("The norm is: {}", vector.norm());
+    /// ```
+    /// <hr/>
+    pub fn norm(&self) -> f64 {
+        self.0
+            .iter()
+            .fold(0.0, |norm: f64, value| norm.hypot(*value))
+    }
+
+    /// Returns the elements of the vector as a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numerilib::Vector;
+    ///
+    /// let vector = Vector::new([1_f64, 2_f64, 3_f64]);
+    /// let sum: f64 = vector.as_slice().iter().sum();
+    ///
+    /// println!("The sum of the elements is: {}", sum);
+    /// ```
+    /// <hr/>
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> fmt::Debug for Vector<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, val) in self.0.iter().enumerate() {
+            write!(f, "{}", val)?;
+            if index + 1 < N {
+                write!(f, ", ")?;
+            }
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,760 @@
+use crate::matrix::{BandedLu, DenseLu};
+use crate::ode::problem::{InitialValueProblem, OdeMethod};
+use crate::ode::runge_kutta::norm;
+use crate::ode::solution::{OdeOptions, OdeSolution, OdeStatus, Segment};
+use crate::{Functions, RootError, RootTolerance};
+use std::cell::Cell;
+use std::fmt;
+
+// The right hand side f(x, y, y') of a second order equation.
+pub type SecondOrder<'a> = dyn Fn(f64, f64, f64) -> f64 + 'a;
+
+/// A boundary condition at one end of a [`BoundaryValueProblem`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundaryCondition {
+    /// The value `y = value`.
+    Dirichlet(f64),
+    /// The slope `y' = value`.
+    Neumann(f64),
+    /// The mixed (Robin) condition `a y + b y' = c`, where `a` and `b` are not both zero.
+    Mixed { a: f64, b: f64, c: f64 },
+}
+
+impl BoundaryCondition {
+    // The coefficients (a, b, c) of a y + b y' = c.
+    fn coefficients(&self) -> (f64, f64, f64) {
+        match *self {
+            BoundaryCondition::Dirichlet(value) => (1.0, 0.0, value),
+            BoundaryCondition::Neumann(value) => (0.0, 1.0, value),
+            BoundaryCondition::Mixed { a, b, c } => (a, b, c),
+        }
+    }
+
+    fn residual(&self, y: f64, dy: f64) -> f64 {
+        let (a, b, c) = self.coefficients();
+        a * y + b * dy - c
+    }
+
+    // The value of y when the condition fixes it.
+    fn value(&self) -> Option<f64> {
+        match self.coefficients() {
+            (a, 0.0, c) => Some(c / a),
+            _ => None,
+        }
+    }
+}
+
+/// The methods of a [`BoundaryValueProblem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BvpMethod {
+    /// Integrates from the left end with Dormand–Prince and finds the initial condition missing at the left
+    /// end with the secant method. Accurate and cheap, but unstable when the solutions of the equation grow
+    /// quickly over the interval.
+    Shooting,
+    /// Shoots on the given number of equal intervals at once and solves for the initial values of every
+    /// interval, the continuity between them and the boundary conditions with Newton's method. Much more
+    /// robust than single shooting for long intervals or rapidly growing solutions.
+    MultipleShooting(usize),
+    /// Replaces the derivatives with second order central differences on the given number of equal
+    /// intervals and solves the tridiagonal nonlinear system with Newton's method and a banded LU
+    /// decomposition. The error decreases with the square of the step size.
+    FiniteDifference(usize),
+}
+
+/// The solution of a [`BoundaryValueProblem`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BvpSolution {
+    /// The points of the solution, from the left to the right end.
+    pub x: Vec<f64>,
+    /// The values of `y` at the points in `x`.
+    pub y: Vec<f64>,
+    /// The values of `y'` at the points in `x`.
+    pub dy: Vec<f64>,
+    /// The number of secant or Newton iterations.
+    pub iterations: usize,
+    /// The number of times `f` was evaluated, including the integrations and finite difference Jacobians.
+    pub evaluations: usize,
+    /// The largest absolute residual of the boundary conditions and of the continuity or difference
+    /// equations.
+    pub residual: f64,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl BvpSolution {
+    /// Evaluates the solution at any point of the interval by cubic Hermite interpolation of `y` and `y'`.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is outside of the interval.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+        assert!(
+            (x - first) * (last - x) >= 0.0,
+            "The point must be between {} and {}!",
+            first,
+            last
+        );
+
+        let direction = (last - first).signum();
+        let index = self
+            .x
+            .partition_point(|point| (x - point) * direction >= 0.0)
+            .clamp(1, self.x.len() - 1);
+
+        Segment::hermite(
+            self.x[index - 1],
+            self.x[index] - self.x[index - 1],
+            &[self.y[index - 1]],
+            &[self.dy[index - 1]],
+            &[self.y[index]],
+            &[self.dy[index]],
+        )
+        .evaluate(x)[0]
+    }
+}
+
+/// The reasons a boundary value solver can fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BvpError {
+    /// `f` returned NaN or an infinite value.
+    NonFiniteValue,
+    /// The Jacobian of the shooting or difference equations is singular.
+    SingularJacobian,
+    /// An initial value problem of a shooting method stopped with the given status.
+    IntegrationFailed { status: OdeStatus },
+}
+
+impl fmt::Display for BvpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BvpError::NonFiniteValue => write!(f, "the function is not finite"),
+            BvpError::SingularJacobian => write!(f, "the Jacobian is singular"),
+            BvpError::IntegrationFailed { status } => {
+                write!(f, "the integration stopped with the status {:?}", status)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BvpError {}
+
+fn largest(values: &[f64]) -> f64 {
+    values
+        .iter()
+        .fold(0.0, |largest: f64, value| largest.max(value.abs()))
+}
+
+// The last iterate of `BoundaryValueProblem::newton` with the data of its evaluation.
+struct Iterate<S> {
+    u: Vec<f64>,
+    data: S,
+    residuals: Vec<f64>,
+    iterations: usize,
+    converged: bool,
+}
+
+/// A two-point boundary value problem `y'' = f(x, y, y')` on the interval from `a` to `b` with a
+/// [`BoundaryCondition`] at each end.
+///
+/// The problem is created from the right hand side, the interval and the boundary conditions, then
+/// configured with an initial guess of the solution, the tolerances of the integrator and of the nonlinear
+/// solver, and finally solved with one of the [`BvpMethod`]s. Without a guess the solvers start from the
+/// straight line through the Dirichlet values, or zero.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::ode::{BoundaryCondition, BoundaryValueProblem, BvpMethod};
+///
+/// // The deflection of a beam y'' = (1 + y'^2)^(3/2) / 10 clamped at both ends.
+/// let beam = |_x: f64, _y: f64, dy: f64| (1_f64 + dy * dy).powf(1.5) / 10_f64;
+///
+/// let problem = BoundaryValueProblem::new(
+///     beam,
+///     0_f64,
+///     1_f64,
+///     BoundaryCondition::Dirichlet(0_f64),
+///     BoundaryCondition::Dirichlet(0_f64),
+/// );
+/// let solution = problem.solve(BvpMethod::Shooting).unwrap();
+///
+/// println!("y(0.5) = {}", solution.evaluate(0.5));
+/// ```
+/// <hr/>
+pub struct BoundaryValueProblem<'a> {
+    function: Box<SecondOrder<'a>>,
+    a: f64,
+    b: f64,
+    left: BoundaryCondition,
+    right: BoundaryCondition,
+    guess: Option<Box<dyn Fn(f64) -> f64 + 'a>>,
+    options: OdeOptions,
+    tolerance: RootTolerance,
+}
+
+impl<'a> BoundaryValueProblem<'a> {
+    const MAX_HALVINGS: usize = 30;
+
+    /// Creates the problem `y'' = function(x, y, y')` on the interval from `a` to `b` with the conditions
+    /// `left` at `a` and `right` at `b`.
+    ///
+    /// The integrations of the shooting methods default to the tolerances `1e-12` and `1e-10`, and the
+    /// nonlinear solvers stop when the update is below `1e-10` (absolute and relative) or after 50
+    /// iterations.
+    ///
+    /// # Panics
+    ///
+    /// If `a` equals `b`, or if a mixed condition has `a = b = 0`.
+    pub fn new<F: Fn(f64, f64, f64) -> f64 + 'a>(
+        function: F,
+        a: f64,
+        b: f64,
+        left: BoundaryCondition,
+        right: BoundaryCondition,
+    ) -> Self {
+        assert!(a != b, "The interval must not be empty!");
+        for condition in [left, right] {
+            let (a, b, _) = condition.coefficients();
+            assert!(
+                a != 0.0 || b != 0.0,
+                "A boundary condition must involve y or y'!"
+            );
+        }
+
+        Self {
+            function: Box::new(function),
+            a,
+            b,
+            left,
+            right,
+            guess: None,
+            options: OdeOptions {
+                absolute: 1e-12,
+                relative: 1e-10,
+                ..OdeOptions::default()
+            },
+            tolerance: RootTolerance {
+                absolute: 1e-10,
+                relative: 1e-10,
+                residual: 0.0,
+                max_iterations: 50,
+            },
+        }
+    }
+
+    /// Sets an initial guess of the solution, which gives the first shooting parameters and the starting
+    /// point of the Newton iterations.
+    pub fn guess<G: Fn(f64) -> f64 + 'a>(mut self, guess: G) -> Self {
+        self.guess = Some(Box::new(guess));
+        self
+    }
+
+    /// Sets the tolerances and step limits of the integrations of the shooting methods.
+    pub fn options(mut self, options: OdeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the stopping criteria of the secant and Newton iterations.
+    pub fn tolerance(mut self, tolerance: RootTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Solves the problem with the given method.
+    ///
+    /// # Returns
+    ///
+    /// A `BvpSolution` with the solution and its derivative at the points of the integration or the grid,
+    /// the numbers of iterations and evaluations of `f` and whether the iteration converged, or a `BvpError`
+    /// if `f` is not finite, the Jacobian is singular or an integration fails.
+    ///
+    /// # Panics
+    ///
+    /// If multiple shooting has no interval or the finite differences have less than two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::ode::{BoundaryCondition, BoundaryValueProblem, BvpMethod};
+    ///
+    /// // y'' = -y with y(0) = 0 and y'(π/2) + y(π/2) = 1, solved by y = sin(x).
+    /// let problem = BoundaryValueProblem::new(
+    ///     |_x: f64, y: f64, _dy: f64| -y,
+    ///     0_f64,
+    ///     std::f64::consts::FRAC_PI_2,
+    ///     BoundaryCondition::Dirichlet(0_f64),
+    ///     BoundaryCondition::Mixed { a: 1_f64, b: 1_f64, c: 1_f64 },
+    /// );
+    ///
+    /// let collocation = problem.solve(BvpMethod::FiniteDifference(100)).unwrap();
+    /// let shooting = problem.solve(BvpMethod::MultipleShooting(4)).unwrap();
+    ///
+    /// println!("y(1) = {} and {}", collocation.evaluate(1.0), shooting.evaluate(1.0));
+    /// ```
+    /// <hr/>
+    pub fn solve(&self, method: BvpMethod) -> Result<BvpSolution, BvpError> {
+        let evaluations = Cell::new(0);
+        let function = |x: f64, y: f64, dy: f64| {
+            evaluations.set(evaluations.get() + 1);
+            (self.function)(x, y, dy)
+        };
+
+        let mut solution = match method {
+            BvpMethod::Shooting => self.shooting(&function)?,
+            BvpMethod::MultipleShooting(intervals) => {
+                assert!(
+                    intervals >= 1,
+                    "Multiple shooting needs at least one interval!"
+                );
+                self.multiple_shooting(&function, intervals)?
+            }
+            BvpMethod::FiniteDifference(intervals) => {
+                assert!(
+                    intervals >= 2,
+                    "The finite differences need at least two intervals!"
+                );
+                self.finite_difference(&function, intervals)?
+            }
+        };
+
+        solution.evaluations = evaluations.get();
+        Ok(solution)
+    }
+
+    fn initial_guess(&self, x: f64) -> f64 {
+        if let Some(guess) = &self.guess {
+            return guess(x);
+        }
+
+        match (self.left.value(), self.right.value()) {
+            (Some(left), Some(right)) => left + (right - left) * (x - self.a) / (self.b - self.a),
+            (Some(value), None) | (None, Some(value)) => value,
+            (None, None) => 0.0,
+        }
+    }
+
+    // The initial states (y, y') that satisfy the left condition form the line p + s d.
+    fn initial_state(&self, s: f64) -> [f64; 2] {
+        let (a, b, c) = self.left.coefficients();
+        let squared = a * a + b * b;
+        let length = squared.sqrt();
+
+        [
+            a * c / squared - s * b / length,
+            b * c / squared + s * a / length,
+        ]
+    }
+
+    // The parameter s of the initial state closest to the guess.
+    fn initial_parameter(&self) -> f64 {
+        let (a, b, _) = self.left.coefficients();
+        let length = a.hypot(b);
+        let y = self.initial_guess(self.a);
+        let dy = Functions::derivative(|x: f64| self.initial_guess(x), self.a);
+
+        (a * dy - b * y) / length
+    }
+
+    fn integrate<F: Fn(f64, f64, f64) -> f64>(
+        &self,
+        function: &F,
+        x0: f64,
+        x1: f64,
+        state: [f64; 2],
+    ) -> Result<OdeSolution, BvpError> {
+        let system = |x: f64, y: &[f64]| vec![y[1], function(x, y[0], y[1])];
+        let solution = InitialValueProblem::new(system, x0, &state, x1)
+            .options(self.options)
+            .solve(OdeMethod::DormandPrince);
+
+        match solution.status {
+            OdeStatus::Success => Ok(solution),
+            OdeStatus::NonFiniteValue => Err(BvpError::NonFiniteValue),
+            status => Err(BvpError::IntegrationFailed { status }),
+        }
+    }
+
+    fn end(solution: &OdeSolution) -> [f64; 2] {
+        let state = &solution.y[solution.y.len() - 1];
+        [state[0], state[1]]
+    }
+
+    // The trajectories of consecutive integrations joined into a solution.
+    fn join(
+        solutions: &[OdeSolution],
+        iterations: usize,
+        residual: f64,
+        converged: bool,
+    ) -> BvpSolution {
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+        let mut dy = Vec::new();
+
+        for (index, solution) in solutions.iter().enumerate() {
+            let skip = if index == 0 { 0 } else { 1 };
+
+            for (t, state) in solution.t.iter().zip(&solution.y).skip(skip) {
+                x.push(*t);
+                y.push(state[0]);
+                dy.push(state[1]);
+            }
+        }
+
+        BvpSolution {
+            x,
+            y,
+            dy,
+            iterations,
+            evaluations: 0,
+            residual,
+            converged,
+        }
+    }
+
+    fn shooting<F: Fn(f64, f64, f64) -> f64>(&self, function: &F) -> Result<BvpSolution, BvpError> {
+        let failure = Cell::new(None);
+        let mismatch =
+            |s: f64| match self.integrate(function, self.a, self.b, self.initial_state(s)) {
+                Ok(solution) => {
+                    let [y, dy] = Self::end(&solution);
+                    self.right.residual(y, dy)
+                }
+                Err(error) => {
+                    failure.set(Some(error));
+                    f64::NAN
+                }
+            };
+
+        let s0 = self.initial_parameter();
+        let s1 = s0 + 1e-3 * s0.abs().max(1.0);
+        let result = Functions::secant(mismatch, s0, s1, self.tolerance);
+
+        if let Some(error) = failure.get() {
+            return Err(error);
+        }
+
+        let result = result.map_err(|error| match error {
+            RootError::ZeroSlope { .. } => BvpError::SingularJacobian,
+            _ => BvpError::NonFiniteValue,
+        })?;
+
+        let solution = self.integrate(function, self.a, self.b, self.initial_state(result.root))?;
+
+        Ok(Self::join(
+            &[solution],
+            result.iterations,
+            result.function_value.abs(),
+            result.converged,
+        ))
+    }
+
+    // Newton's method for residuals(u) = 0 with a backtracking line search on the residual norm. `evaluate`
+    // returns the residuals and the data `step` needs to solve the linearized equations J du = residuals.
+    fn newton<S, E, D>(&self, mut u: Vec<f64>, evaluate: E, step: D) -> Result<Iterate<S>, BvpError>
+    where
+        E: Fn(&[f64]) -> Result<(Vec<f64>, S), BvpError>,
+        D: Fn(&[f64], &[f64], &S) -> Result<Vec<f64>, BvpError>,
+    {
+        let (mut residuals, mut data) = evaluate(&u)?;
+        if !residuals.iter().all(|value| value.is_finite()) {
+            return Err(BvpError::NonFiniteValue);
+        }
+
+        for iteration in 1..=self.tolerance.max_iterations {
+            let update = step(&u, &residuals, &data)?;
+            let update_size = largest(&update);
+            let current = norm(&residuals);
+
+            if update_size <= self.tolerance.width(largest(&u)) {
+                let next: Vec<f64> = u.iter().zip(&update).map(|(u, du)| u - du).collect();
+                if let Ok((next_residuals, next_data)) = evaluate(&next) {
+                    return Ok(Iterate {
+                        u: next,
+                        data: next_data,
+                        residuals: next_residuals,
+                        iterations: iteration,
+                        converged: true,
+                    });
+                }
+            }
+
+            let mut scale = 1.0;
+            let mut accepted = None;
+
+            for _ in 0..=Self::MAX_HALVINGS {
+                let trial: Vec<f64> = u
+                    .iter()
+                    .zip(&update)
+                    .map(|(u, du)| u - scale * du)
+                    .collect();
+
+                if let Ok((trial_residuals, trial_data)) = evaluate(&trial) {
+                    if norm(&trial_residuals) < current {
+                        accepted = Some((trial, trial_residuals, trial_data));
+                        break;
+                    }
+                }
+
+                scale *= 0.5;
+            }
+
+            // Close to the solution rounding can keep the residuals from decreasing.
+            let Some((trial, trial_residuals, trial_data)) = accepted else {
+                let converged = update_size <= self.tolerance.width(largest(&u));
+                return Ok(Iterate {
+                    u,
+                    data,
+                    residuals,
+                    iterations: iteration,
+                    converged,
+                });
+            };
+
+            u = trial;
+            residuals = trial_residuals;
+            data = trial_data;
+
+            if largest(&residuals) <= self.tolerance.residual
+                || update_size <= self.tolerance.width(largest(&u))
+            {
+                return Ok(Iterate {
+                    u,
+                    data,
+                    residuals,
+                    iterations: iteration,
+                    converged: true,
+                });
+            }
+        }
+
+        Ok(Iterate {
+            u,
+            data,
+            residuals,
+            iterations: self.tolerance.max_iterations,
+            converged: false,
+        })
+    }
+
+    // The unknowns are the parameter s of the left end and (y, y') at the interior nodes, and the equations
+    // are the continuity of (y, y') at the interior nodes and the right condition.
+    fn multiple_shooting<F: Fn(f64, f64, f64) -> f64>(
+        &self,
+        function: &F,
+        intervals: usize,
+    ) -> Result<BvpSolution, BvpError> {
+        let nodes: Vec<f64> = (0..=intervals)
+            .map(|k| self.a + (self.b - self.a) * k as f64 / intervals as f64)
+            .collect();
+
+        let start = |u: &[f64], k: usize| {
+            if k == 0 {
+                self.initial_state(u[0])
+            } else {
+                [u[2 * k - 1], u[2 * k]]
+            }
+        };
+        let interval =
+            |u: &[f64], k: usize| self.integrate(function, nodes[k], nodes[k + 1], start(u, k));
+        let assemble = |u: &[f64], ends: &[[f64; 2]]| {
+            let mut residuals = Vec::with_capacity(2 * intervals - 1);
+
+            for (k, end) in ends.iter().enumerate().take(intervals - 1) {
+                let next = start(u, k + 1);
+                residuals.push(end[0] - next[0]);
+                residuals.push(end[1] - next[1]);
+            }
+
+            let [y, dy] = ends[intervals - 1];
+            residuals.push(self.right.residual(y, dy));
+            residuals
+        };
+
+        let evaluate = |u: &[f64]| {
+            let solutions = (0..intervals)
+                .map(|k| interval(u, k))
+                .collect::<Result<Vec<OdeSolution>, BvpError>>()?;
+            let ends: Vec<[f64; 2]> = solutions.iter().map(Self::end).collect();
+
+            Ok((assemble(u, &ends), solutions))
+        };
+
+        // Every unknown only changes the integration of its own interval.
+        let step = |u: &[f64], residuals: &[f64], solutions: &Vec<OdeSolution>| {
+            let n = u.len();
+            let ends: Vec<[f64; 2]> = solutions.iter().map(Self::end).collect();
+            let mut jacobian = vec![vec![0.0; n]; n];
+
+            for j in 0..n {
+                let k = j.div_ceil(2);
+                let delta = f64::EPSILON.cbrt() * u[j].abs().max(1.0);
+                let mut shifted = u.to_vec();
+                shifted[j] += delta;
+
+                let mut shifted_ends = ends.clone();
+                shifted_ends[k] = Self::end(&interval(&shifted, k)?);
+                let shifted_residuals = assemble(&shifted, &shifted_ends);
+
+                for i in 0..n {
+                    jacobian[i][j] = (shifted_residuals[i] - residuals[i]) / delta;
+                }
+            }
+
+            let lu = DenseLu::new(jacobian).ok_or(BvpError::SingularJacobian)?;
+            Ok(lu.solve(residuals))
+        };
+
+        let mut guess = vec![self.initial_parameter()];
+        for &node in &nodes[1..intervals] {
+            guess.push(self.initial_guess(node));
+            guess.push(Functions::derivative(|x: f64| self.initial_guess(x), node));
+        }
+
+        let iterate = self.newton(guess, evaluate, step)?;
+
+        Ok(Self::join(
+            &iterate.data,
+            iterate.iterations,
+            largest(&iterate.residuals),
+            iterate.converged,
+        ))
+    }
+
+    // The unknowns are y at the nodes. The interior equations are
+    // (y[i+1] - 2y[i] + y[i-1]) / h² = f(x[i], y[i], (y[i+1] - y[i-1]) / 2h), a Dirichlet condition is an
+    // equation by itself, and otherwise the condition gives y' at the end and eliminates the value at the
+    // fictitious node outside of the interval from the equation there.
+    fn finite_difference<F: Fn(f64, f64, f64) -> f64>(
+        &self,
+        function: &F,
+        intervals: usize,
+    ) -> Result<BvpSolution, BvpError> {
+        let h = (self.b - self.a) / intervals as f64;
+        let x: Vec<f64> = (0..=intervals)
+            .map(|i| {
+                if i == intervals {
+                    self.b
+                } else {
+                    self.a + h * i as f64
+                }
+            })
+            .collect();
+
+        // The slope at every node: central differences inside and the boundary conditions at the ends.
+        let slopes = |y: &[f64]| -> Vec<f64> {
+            (0..=intervals)
+                .map(|i| {
+                    let (condition, one_sided) = if i == 0 {
+                        (
+                            Some(self.left),
+                            (-3.0 * y[0] + 4.0 * y[1] - y[2]) / (2.0 * h),
+                        )
+                    } else if i == intervals {
+                        let n = intervals;
+                        (
+                            Some(self.right),
+                            (3.0 * y[n] - 4.0 * y[n - 1] + y[n - 2]) / (2.0 * h),
+                        )
+                    } else {
+                        (None, (y[i + 1] - y[i - 1]) / (2.0 * h))
+                    };
+
+                    match condition.map(|condition| condition.coefficients()) {
+                        Some((a, b, c)) if b != 0.0 => (c - a * y[i]) / b,
+                        _ => one_sided,
+                    }
+                })
+                .collect()
+        };
+
+        let evaluate = |y: &[f64]| {
+            let dy = slopes(y);
+            let mut residuals = Vec::with_capacity(intervals + 1);
+            let mut bands = Vec::with_capacity(intervals + 1);
+
+            for i in 0..=intervals {
+                let condition = if i == 0 {
+                    Some(self.left)
+                } else if i == intervals {
+                    Some(self.right)
+                } else {
+                    None
+                };
+
+                if let Some(condition) = condition.filter(|condition| condition.value().is_some()) {
+                    let (a, _, _) = condition.coefficients();
+                    residuals.push(condition.residual(y[i], dy[i]));
+                    bands.push(vec![0.0, a, 0.0]);
+                    continue;
+                }
+
+                let value = function(x[i], y[i], dy[i]);
+                let y_step = f64::EPSILON.sqrt() * y[i].abs().max(1.0);
+                let dy_step = f64::EPSILON.sqrt() * dy[i].abs().max(1.0);
+                let f_y = (function(x[i], y[i] + y_step, dy[i]) - value) / y_step;
+                let f_dy = (function(x[i], y[i], dy[i] + dy_step) - value) / dy_step;
+
+                match condition.map(|condition| condition.coefficients()) {
+                    Some((a, b, _)) if i == 0 => {
+                        let ratio = a / b;
+                        residuals
+                            .push((2.0 * y[1] - 2.0 * y[0] - 2.0 * h * dy[0]) / (h * h) - value);
+                        bands.push(vec![
+                            0.0,
+                            (-2.0 + 2.0 * h * ratio) / (h * h) - f_y + f_dy * ratio,
+                            2.0 / (h * h),
+                        ]);
+                    }
+                    Some((a, b, _)) => {
+                        let ratio = a / b;
+                        let n = intervals;
+                        residuals.push(
+                            (2.0 * y[n - 1] - 2.0 * y[n] + 2.0 * h * dy[n]) / (h * h) - value,
+                        );
+                        bands.push(vec![
+                            2.0 / (h * h),
+                            (-2.0 - 2.0 * h * ratio) / (h * h) - f_y + f_dy * ratio,
+                            0.0,
+                        ]);
+                    }
+                    None => {
+                        residuals.push((y[i + 1] - 2.0 * y[i] + y[i - 1]) / (h * h) - value);
+                        bands.push(vec![
+                            1.0 / (h * h) + f_dy / (2.0 * h),
+                            -2.0 / (h * h) - f_y,
+                            1.0 / (h * h) - f_dy / (2.0 * h),
+                        ]);
+                    }
+                }
+            }
+
+            if residuals.iter().all(|value| value.is_finite()) {
+                Ok((residuals, bands))
+            } else {
+                Err(BvpError::NonFiniteValue)
+            }
+        };
+
+        let step = |_: &[f64], residuals: &[f64], bands: &Vec<Vec<f64>>| {
+            let lu = BandedLu::new(1, 1, bands.clone()).ok_or(BvpError::SingularJacobian)?;
+            Ok(lu.solve(residuals))
+        };
+
+        let guess: Vec<f64> = x.iter().map(|&x| self.initial_guess(x)).collect();
+        let iterate = self.newton(guess, evaluate, step)?;
+        let dy = slopes(&iterate.u);
+
+        Ok(BvpSolution {
+            x,
+            y: iterate.u,
+            dy,
+            iterations: iterate.iterations,
+            evaluations: 0,
+            residual: largest(&iterate.residuals),
+            converged: iterate.converged,
+        })
+    }
+}
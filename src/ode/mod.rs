@@ -0,0 +1,9 @@
+mod boundary;
+mod problem;
+mod runge_kutta;
+mod solution;
+mod stiff;
+
+pub use boundary::{BoundaryCondition, BoundaryValueProblem, BvpError, BvpMethod, BvpSolution};
+pub use problem::{InitialValueProblem, OdeMethod};
+pub use solution::{Event, EventDirection, EventRecord, OdeOptions, OdeSolution, OdeStatus};
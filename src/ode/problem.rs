@@ -0,0 +1,253 @@
+use crate::ode::runge_kutta::{RungeKutta, DORMAND_PRINCE, TSITOURAS};
+use crate::ode::solution::{
+    Callback, Derivative, Event, JacobianFunction, OdeOptions, OdeSolution, Outcome, Recorder,
+};
+use crate::ode::stiff::Stiff;
+use crate::Matrix;
+
+/// The integration methods of an [`InitialValueProblem`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OdeMethod {
+    /// The classical fourth order Runge–Kutta method with the given fixed step size.
+    Rk4(f64),
+    /// The adaptive Dormand–Prince 5(4) pair, as in MATLAB's `ode45` and SciPy's `RK45`.
+    DormandPrince,
+    /// The adaptive Tsitouras 5(4) pair, which usually needs fewer steps than Dormand–Prince for the same
+    /// tolerance.
+    Tsit5,
+    /// The implicit variable order (one to five) backward differentiation formulas for stiff problems, as in
+    /// MATLAB's `ode15s` and SciPy's `BDF`.
+    Bdf,
+    /// The linearly implicit Rosenbrock-W pair of orders 2 and 3 for stiff problems, as in MATLAB's `ode23s`.
+    /// It is usually cheaper than [`OdeMethod::Bdf`] for small systems and crude tolerances.
+    RosenbrockW,
+    /// Starts with Dormand–Prince and switches to BDF when the problem becomes stiff, and back when it stops
+    /// being stiff. The switches are recorded in [`OdeSolution::switches`](crate::ode::OdeSolution).
+    Auto,
+}
+
+/// A system of ordinary differential equations `dy/dt = f(t, y)` with the initial value `y(t0) = y0`,
+/// integrated from `t0` to `t_end`.
+///
+/// The problem is created from the right hand side and the interval, then configured with the tolerances,
+/// events, a per-step callback and the Jacobian used by the stiff methods, and finally solved with one of the
+/// [`OdeMethod`]s. `t_end` may be smaller than `t0` to integrate backwards.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::ode::{InitialValueProblem, OdeMethod, OdeOptions};
+///
+/// // The harmonic oscillator y'' = -y written as a first order system.
+/// let oscillator = |_t: f64, y: &[f64]| vec![y[1], -y[0]];
+///
+/// let mut problem = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64).options(OdeOptions {
+///     relative: 1e-9,
+///     ..OdeOptions::default()
+/// });
+/// let solution = problem.solve(OdeMethod::Tsit5);
+///
+/// println!("y(10) = {:?} after {} steps", solution.y.last().unwrap(), solution.steps);
+/// ```
+/// <hr/>
+pub struct InitialValueProblem<'a> {
+    function: Box<Derivative<'a>>,
+    t0: f64,
+    y0: Vec<f64>,
+    t_end: f64,
+    options: OdeOptions,
+    events: Vec<Event<'a>>,
+    callback: Option<Box<Callback<'a>>>,
+    jacobian: Option<Box<JacobianFunction<'a>>>,
+}
+
+impl<'a> InitialValueProblem<'a> {
+    /// Creates the problem `dy/dt = function(t, y)` with `y(t0) = y0` on the interval from `t0` to `t_end`
+    /// with the default [`OdeOptions`].
+    pub fn new<F: Fn(f64, &[f64]) -> Vec<f64> + 'a>(
+        function: F,
+        t0: f64,
+        y0: &[f64],
+        t_end: f64,
+    ) -> Self {
+        Self {
+            function: Box::new(function),
+            t0,
+            y0: y0.to_vec(),
+            t_end,
+            options: OdeOptions::default(),
+            events: Vec::new(),
+            callback: None,
+            jacobian: None,
+        }
+    }
+
+    /// Sets the tolerances and step limits of the adaptive methods.
+    pub fn options(mut self, options: OdeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Adds an event whose zero crossings are located on the dense output of every step.
+    pub fn event(mut self, event: Event<'a>) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Sets a function that is called with `t` and `y` after every accepted step.
+    pub fn callback<C: FnMut(f64, &[f64]) + 'a>(mut self, callback: C) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the Jacobian `∂f/∂y` used by the stiff methods, which otherwise approximate it by forward
+    /// differences.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is not the number of components of `y0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::ode::{InitialValueProblem, OdeMethod};
+    /// use numerilib::Matrix;
+    ///
+    /// // The Van der Pol oscillator with μ = 1000.
+    /// let van_der_pol = |_t: f64, y: &[f64]| vec![y[1], 1000_f64 * (1_f64 - y[0] * y[0]) * y[1] - y[0]];
+    ///
+    /// let mut problem = InitialValueProblem::new(van_der_pol, 0_f64, &[2_f64, 0_f64], 3000_f64).jacobian(
+    ///     |_t: f64, y: &[f64]| {
+    ///         Matrix::new([
+    ///             [0_f64, 1_f64],
+    ///             [-2000_f64 * y[0] * y[1] - 1_f64, 1000_f64 * (1_f64 - y[0] * y[0])],
+    ///         ])
+    ///     },
+    /// );
+    /// let solution = problem.solve(OdeMethod::Bdf);
+    ///
+    /// println!("y(3000) = {:?} after {} steps", solution.y.last().unwrap(), solution.steps);
+    /// ```
+    /// <hr/>
+    pub fn jacobian<const N: usize, J: Fn(f64, &[f64]) -> Matrix<N, N> + 'a>(
+        mut self,
+        jacobian: J,
+    ) -> Self {
+        assert_eq!(
+            N,
+            self.y0.len(),
+            "The Jacobian must be a {}x{} matrix!",
+            self.y0.len(),
+            self.y0.len()
+        );
+        self.jacobian = Some(Box::new(move |t: f64, y: &[f64]| {
+            let matrix = jacobian(t, y);
+            (0..N).map(|i| matrix[i].to_vec()).collect()
+        }));
+        self
+    }
+
+    /// Integrates the problem with the given method.
+    ///
+    /// # Returns
+    ///
+    /// An `OdeSolution` with the trajectory at every accepted step, its dense output, the located events,
+    /// the numbers of steps and evaluations of the right hand side and why the integration stopped.
+    ///
+    /// # Panics
+    ///
+    /// If the fixed step of [`OdeMethod::Rk4`] is not positive.
+    pub fn solve(&mut self, method: OdeMethod) -> OdeSolution {
+        let function = |t: f64, y: &[f64]| (self.function)(t, y);
+        let jacobian = self.jacobian.as_deref();
+        let options = &self.options;
+        let h = options.initial_step;
+        let t_end = self.t_end;
+        let mut recorder = Recorder::new(
+            &self.events,
+            self.callback.as_deref_mut(),
+            self.t0,
+            &self.y0,
+        );
+
+        let finished = |outcome: Outcome| match outcome {
+            Outcome::Finished(status) => status,
+            Outcome::Switch(_) => unreachable!("Only the automatic method switches!"),
+        };
+
+        let status = match method {
+            OdeMethod::Rk4(step) => RungeKutta::rk4(&function, t_end, step, &mut recorder),
+            OdeMethod::DormandPrince => finished(RungeKutta::adaptive(
+                &function,
+                &DORMAND_PRINCE,
+                t_end,
+                h,
+                options,
+                &mut recorder,
+                false,
+            )),
+            OdeMethod::Tsit5 => finished(RungeKutta::adaptive(
+                &function,
+                &TSITOURAS,
+                t_end,
+                h,
+                options,
+                &mut recorder,
+                false,
+            )),
+            OdeMethod::Bdf => finished(Stiff::bdf(
+                &function,
+                jacobian,
+                t_end,
+                h,
+                options,
+                &mut recorder,
+                false,
+            )),
+            OdeMethod::RosenbrockW => finished(Stiff::rosenbrock(
+                &function,
+                jacobian,
+                t_end,
+                h,
+                options,
+                &mut recorder,
+                false,
+            )),
+            OdeMethod::Auto => {
+                let mut h = h;
+                let mut stiff = false;
+
+                loop {
+                    let outcome = if stiff {
+                        Stiff::bdf(&function, jacobian, t_end, h, options, &mut recorder, true)
+                    } else {
+                        RungeKutta::adaptive(
+                            &function,
+                            &DORMAND_PRINCE,
+                            t_end,
+                            h,
+                            options,
+                            &mut recorder,
+                            true,
+                        )
+                    };
+
+                    match outcome {
+                        Outcome::Finished(status) => break status,
+                        Outcome::Switch(step) => {
+                            stiff = !stiff;
+                            h = step;
+                            recorder.switch(if stiff {
+                                OdeMethod::Bdf
+                            } else {
+                                OdeMethod::DormandPrince
+                            });
+                        }
+                    }
+                }
+            }
+        };
+
+        recorder.finish(status)
+    }
+}
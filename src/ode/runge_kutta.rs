@@ -0,0 +1,447 @@
+use crate::ode::solution::{OdeOptions, OdeStatus, Outcome, Recorder, Segment};
+
+// An explicit embedded Runge-Kutta pair with seven stages whose last stage is evaluated at the new state
+// (first same as last), an error estimate of order four and a continuous extension of order four.
+pub struct Tableau {
+    c: [f64; 7],
+    a: [[f64; 6]; 7],
+    b: [f64; 7],
+    // The difference between the weights of the fifth and the fourth order solutions.
+    error: [f64; 7],
+    // The coefficients of θ, θ², θ³ and θ⁴ in the weights of the continuous extension.
+    dense: [[f64; 4]; 7],
+}
+
+pub const DORMAND_PRINCE: Tableau = Tableau {
+    c: [0.0, 0.2, 0.3, 0.8, 8.0 / 9.0, 1.0, 1.0],
+    a: [
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [0.2, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+        [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+        [
+            19372.0 / 6561.0,
+            -25360.0 / 2187.0,
+            64448.0 / 6561.0,
+            -212.0 / 729.0,
+            0.0,
+            0.0,
+        ],
+        [
+            9017.0 / 3168.0,
+            -355.0 / 33.0,
+            46732.0 / 5247.0,
+            49.0 / 176.0,
+            -5103.0 / 18656.0,
+            0.0,
+        ],
+        [
+            35.0 / 384.0,
+            0.0,
+            500.0 / 1113.0,
+            125.0 / 192.0,
+            -2187.0 / 6784.0,
+            11.0 / 84.0,
+        ],
+    ],
+    b: [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+        0.0,
+    ],
+    error: [
+        71.0 / 57600.0,
+        0.0,
+        -71.0 / 16695.0,
+        71.0 / 1920.0,
+        -17253.0 / 339200.0,
+        22.0 / 525.0,
+        -1.0 / 40.0,
+    ],
+    dense: [
+        [
+            1.0,
+            -2.8535800653862835,
+            3.0717434641059005,
+            -1.1270175653862835,
+        ],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 4.023133379230305, -6.249321565289, 2.675424484351598],
+        [
+            0.0,
+            -3.7324019615885042,
+            10.068970589843675,
+            -5.685526961588504,
+        ],
+        [
+            0.0,
+            2.5548038301849423,
+            -6.399112377351017,
+            3.5219323679207912,
+        ],
+        [
+            0.0,
+            -1.3744241142186024,
+            3.272657752246729,
+            -1.7672812570757455,
+        ],
+        [
+            0.0,
+            1.3824689317781436,
+            -3.764937863556287,
+            2.382468931778144,
+        ],
+    ],
+};
+
+pub const TSITOURAS: Tableau = Tableau {
+    c: [0.0, 0.161, 0.327, 0.9, 0.9800255409045097, 1.0, 1.0],
+    a: [
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [0.161, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [-0.008480655492356989, 0.335480655492357, 0.0, 0.0, 0.0, 0.0],
+        [
+            2.897153057105493,
+            -6.359448489975075,
+            4.3622954328695815,
+            0.0,
+            0.0,
+            0.0,
+        ],
+        [
+            5.325864828439257,
+            -11.748883564062828,
+            7.4955393428898365,
+            -0.09249506636175525,
+            0.0,
+            0.0,
+        ],
+        [
+            5.86145544294642,
+            -12.92096931784711,
+            8.159367898576159,
+            -0.071584973281401,
+            -0.028269050394068383,
+            0.0,
+        ],
+        [
+            0.09646076681806523,
+            0.01,
+            0.4798896504144996,
+            1.379008574103742,
+            -3.290069515436081,
+            2.324710524099774,
+        ],
+    ],
+    b: [
+        0.09646076681806523,
+        0.01,
+        0.4798896504144996,
+        1.379008574103742,
+        -3.290069515436081,
+        2.324710524099774,
+        0.0,
+    ],
+    error: [
+        -0.001780011052225777,
+        -0.0008164344596567469,
+        0.007880878010261995,
+        -0.1447110071732629,
+        0.5823571654525552,
+        -0.45808210592918697,
+        0.015151515151515152,
+    ],
+    dense: [
+        [
+            1.0,
+            -2.763706197274826,
+            2.9132554618219126,
+            -1.0530884977290216,
+        ],
+        [0.0, 0.1317, -0.2234, 0.1017],
+        [
+            0.0,
+            3.930296236894751,
+            -5.941033872131505,
+            2.490627285651253,
+        ],
+        [
+            0.0,
+            -12.411077166933676,
+            30.338188630282318,
+            -16.548102889244902,
+        ],
+        [0.0, 37.50931341651104, -88.1789048947664, 47.37952196281928],
+        [
+            0.0,
+            -27.896526289197286,
+            65.09189467479368,
+            -34.87065786149661,
+        ],
+        [0.0, 1.5, -4.0, 2.5],
+    ],
+};
+
+// Adds `scale * Σ_j weights[j] * vectors[j]` to `base`.
+pub fn combine(base: &[f64], scale: f64, weights: &[f64], vectors: &[Vec<f64>]) -> Vec<f64> {
+    let mut result = base.to_vec();
+
+    for (weight, vector) in weights.iter().zip(vectors) {
+        if *weight != 0.0 {
+            for (r, v) in result.iter_mut().zip(vector) {
+                *r += scale * weight * v;
+            }
+        }
+    }
+
+    result
+}
+
+pub fn is_finite(values: &[f64]) -> bool {
+    values.iter().all(|value| value.is_finite())
+}
+
+pub fn norm(values: &[f64]) -> f64 {
+    values
+        .iter()
+        .fold(0.0, |norm: f64, value| norm.hypot(*value))
+}
+
+pub struct RungeKutta;
+
+impl RungeKutta {
+    pub const SAFETY: f64 = 0.9;
+    pub const MIN_FACTOR: f64 = 0.2;
+    pub const MAX_FACTOR: f64 = 10.0;
+
+    // The stiffness test of Hairer and Wanner: h|λ| is estimated from the two stages at t + h, and the
+    // problem is considered stiff once it exceeds the stability boundary of about 3.25 in 15 steps before
+    // 6 steps in a row stay inside of it.
+    const STABILITY_BOUNDARY: f64 = 3.25;
+    const STIFF_STEPS: usize = 15;
+    const NON_STIFF_STEPS: usize = 6;
+
+    // The classical fourth order method with a fixed step, shortened at the end of the interval.
+    pub fn rk4<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        t_end: f64,
+        step: f64,
+        recorder: &mut Recorder,
+    ) -> OdeStatus {
+        assert!(step > 0.0, "The step size must be positive!");
+
+        let (t0, y0) = recorder.last();
+        let direction = (t_end - t0).signum();
+        let mut t = t0;
+        let mut y = y0;
+        let mut f = function(t, &y);
+        recorder.evaluated(1);
+
+        while (t_end - t) * direction > 0.0 {
+            if !is_finite(&f) {
+                return OdeStatus::NonFiniteValue;
+            }
+
+            // The times are computed from the step count so that rounding errors do not accumulate.
+            let t_next = t0 + direction * step * (recorder.steps() + 1) as f64;
+            let last = (t_end - t_next) * direction <= 1e-9 * step;
+            let h = if last { t_end - t } else { t_next - t };
+
+            let k2 = function(
+                t + 0.5 * h,
+                &combine(&y, 0.5 * h, &[1.0], std::slice::from_ref(&f)),
+            );
+            let k3 = function(
+                t + 0.5 * h,
+                &combine(&y, 0.5 * h, &[1.0], std::slice::from_ref(&k2)),
+            );
+            let k4 = function(t + h, &combine(&y, h, &[1.0], std::slice::from_ref(&k3)));
+
+            let stages = [f.clone(), k2, k3, k4];
+            let y_new = combine(&y, h / 6.0, &[1.0, 2.0, 2.0, 1.0], &stages);
+            let t_new = if last { t_end } else { t_next };
+            let f_new = function(t_new, &y_new);
+            recorder.evaluated(4);
+
+            if !is_finite(&y_new) {
+                return OdeStatus::NonFiniteValue;
+            }
+
+            let segment = Segment::hermite(t, h, &y, &f, &y_new, &f_new);
+            if recorder.accept(segment, t_new, y_new.clone()) {
+                return OdeStatus::Terminated;
+            }
+
+            t = t_new;
+            y = y_new;
+            f = f_new;
+        }
+
+        OdeStatus::Success
+    }
+
+    // Selects the first step so that an explicit Euler step of it would have an error of about the
+    // tolerance (Hairer, Nørsett and Wanner, "Solving Ordinary Differential Equations I", II.4).
+    pub fn initial_step<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        t0: f64,
+        y0: &[f64],
+        f0: &[f64],
+        direction: f64,
+        order: i32,
+        options: &OdeOptions,
+    ) -> f64 {
+        let d0 = options.error_norm(y0, y0, y0);
+        let d1 = options.error_norm(f0, y0, y0);
+        let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+            1e-6
+        } else {
+            0.01 * d0 / d1
+        };
+
+        let y1 = combine(y0, direction * h0, &[1.0], &[f0.to_vec()]);
+        let f1 = function(t0 + direction * h0, &y1);
+        let difference: Vec<f64> = f1.iter().zip(f0).map(|(a, b)| a - b).collect();
+        let d2 = options.error_norm(&difference, y0, y0) / h0;
+
+        let h1 = if d1.max(d2) <= 1e-15 {
+            (h0 * 1e-3).max(1e-6)
+        } else {
+            (0.01 / d1.max(d2)).powf(1.0 / (order + 1) as f64)
+        };
+
+        let h = (100.0 * h0).min(h1);
+        if h.is_finite() {
+            h
+        } else {
+            h0
+        }
+    }
+
+    // An embedded pair with the step size controlled by the estimate of the local error, starting from the
+    // last accepted step of `recorder` with the step `h` (or an automatic one when it is zero). With
+    // `detect_stiffness` it returns `Outcome::Switch` as soon as the problem looks stiff.
+    pub fn adaptive<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        tableau: &Tableau,
+        t_end: f64,
+        mut h: f64,
+        options: &OdeOptions,
+        recorder: &mut Recorder,
+        detect_stiffness: bool,
+    ) -> Outcome {
+        let (mut t, mut y) = recorder.last();
+        let direction = (t_end - t).signum();
+        let mut f = function(t, &y);
+        recorder.evaluated(1);
+
+        if h <= 0.0 {
+            h = Self::initial_step(function, t, &y, &f, direction, 4, options);
+            recorder.evaluated(1);
+        }
+
+        let mut stiff_steps = 0;
+        let mut non_stiff_steps = 0;
+
+        while (t_end - t) * direction > 0.0 {
+            if recorder.steps() >= options.max_steps {
+                return Outcome::Finished(OdeStatus::MaxSteps);
+            }
+
+            let mut rejected = false;
+
+            let (t_new, y_new, f_new, segment, norm, stiffness) = loop {
+                let min_step = 10.0 * f64::EPSILON * t.abs().max(f64::MIN_POSITIVE);
+                h = h.min(options.max_step);
+                if h < min_step {
+                    return Outcome::Finished(OdeStatus::StepSizeTooSmall);
+                }
+
+                let last = h >= (t_end - t).abs();
+                let signed = if last { t_end - t } else { direction * h };
+
+                let mut stages = vec![f.clone()];
+                let mut state = y.clone();
+                for i in 1..6 {
+                    state = combine(&y, signed, &tableau.a[i][..i], &stages);
+                    stages.push(function(t + tableau.c[i] * signed, &state));
+                }
+
+                let y_new = combine(&y, signed, &tableau.b, &stages);
+                let t_new = if last { t_end } else { t + signed };
+                let f_new = function(t_new, &y_new);
+                recorder.evaluated(6);
+
+                // The sixth stage is also evaluated at t + h, so the two stages estimate |λ|.
+                let difference: Vec<f64> = y_new.iter().zip(&state).map(|(a, b)| a - b).collect();
+                let slope: Vec<f64> = f_new.iter().zip(&stages[5]).map(|(a, b)| a - b).collect();
+                let stiffness = signed.abs() * norm(&slope) / norm(&difference);
+
+                stages.push(f_new.clone());
+                let error = combine(&vec![0.0; y.len()], signed, &tableau.error, &stages);
+                let error_norm = options.error_norm(&error, &y, &y_new);
+
+                if error_norm.is_finite() && error_norm <= 1.0 && is_finite(&f_new) {
+                    let coefficients = (0..4)
+                        .map(|j| {
+                            let weights: Vec<f64> =
+                                tableau.dense.iter().map(|row| row[j]).collect();
+                            combine(&vec![0.0; y.len()], 1.0, &weights, &stages)
+                        })
+                        .collect();
+                    let segment = Segment {
+                        t,
+                        h: signed,
+                        y: y.clone(),
+                        coefficients,
+                    };
+                    break (t_new, y_new, f_new, segment, error_norm, stiffness);
+                }
+
+                rejected = true;
+                recorder.reject();
+                h *= if error_norm.is_finite() {
+                    (Self::SAFETY * error_norm.powf(-0.2)).max(Self::MIN_FACTOR)
+                } else {
+                    Self::MIN_FACTOR
+                };
+            };
+
+            if recorder.accept(segment, t_new, y_new.clone()) {
+                return Outcome::Finished(OdeStatus::Terminated);
+            }
+
+            let factor = if norm == 0.0 {
+                Self::MAX_FACTOR
+            } else {
+                (Self::SAFETY * norm.powf(-0.2)).min(Self::MAX_FACTOR)
+            };
+            h *= if rejected { factor.min(1.0) } else { factor };
+
+            t = t_new;
+            y = y_new;
+            f = f_new;
+
+            if detect_stiffness {
+                if stiffness > Self::STABILITY_BOUNDARY {
+                    non_stiff_steps = 0;
+                    stiff_steps += 1;
+                    if stiff_steps == Self::STIFF_STEPS {
+                        return Outcome::Switch(h);
+                    }
+                } else {
+                    non_stiff_steps += 1;
+                    if non_stiff_steps == Self::NON_STIFF_STEPS {
+                        stiff_steps = 0;
+                    }
+                }
+            }
+        }
+
+        Outcome::Finished(OdeStatus::Success)
+    }
+}
@@ -0,0 +1,400 @@
+use crate::ode::problem::OdeMethod;
+use crate::{Functions, RootTolerance};
+
+// The right hand side f(t, y), an event function g(t, y), a per-step callback and the Jacobian ∂f/∂y.
+pub type Derivative<'a> = dyn Fn(f64, &[f64]) -> Vec<f64> + 'a;
+pub type EventFunction<'a> = dyn Fn(f64, &[f64]) -> f64 + 'a;
+pub type Callback<'a> = dyn FnMut(f64, &[f64]) + 'a;
+pub type JacobianFunction<'a> = dyn Fn(f64, &[f64]) -> Vec<Vec<f64>> + 'a;
+
+/// The error tolerances and step limits of the adaptive ODE solvers.
+///
+/// A step is accepted when the root mean square of the estimated local errors, each divided by
+/// `absolute + relative * |y_i|`, is at most one. The `Default` tolerances are `1e-9` and `1e-6`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OdeOptions {
+    /// The absolute tolerance on every component.
+    pub absolute: f64,
+    /// The tolerance relative to the magnitude of every component.
+    pub relative: f64,
+    /// The size of the first step, or `0` to select it automatically.
+    pub initial_step: f64,
+    /// The largest allowed step size.
+    pub max_step: f64,
+    /// The maximum number of accepted steps.
+    pub max_steps: usize,
+}
+
+impl Default for OdeOptions {
+    fn default() -> Self {
+        Self {
+            absolute: 1e-9,
+            relative: 1e-6,
+            initial_step: 0.0,
+            max_step: f64::INFINITY,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl OdeOptions {
+    // The root mean square of `error` scaled by the tolerances for the states `y` and `y_new`.
+    pub fn error_norm(&self, error: &[f64], y: &[f64], y_new: &[f64]) -> f64 {
+        if error.is_empty() {
+            return 0.0;
+        }
+
+        let sum: f64 = error
+            .iter()
+            .zip(y.iter().zip(y_new))
+            .map(|(e, (a, b))| (e / (self.absolute + self.relative * a.abs().max(b.abs()))).powi(2))
+            .sum();
+
+        (sum / error.len() as f64).sqrt()
+    }
+}
+
+/// Why an ODE solver stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdeStatus {
+    /// The end of the interval was reached.
+    Success,
+    /// A terminal event occurred.
+    Terminated,
+    /// The maximum number of steps was reached.
+    MaxSteps,
+    /// The step size became too small relative to `t`, usually because of a singularity or stiffness.
+    StepSizeTooSmall,
+    /// The right hand side returned NaN or an infinite value during a fixed step.
+    NonFiniteValue,
+}
+
+/// An event located by an ODE solver.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecord {
+    /// The index of the event in the order the events were added.
+    pub index: usize,
+    /// The time of the zero crossing.
+    pub t: f64,
+    /// The state at `t`.
+    pub y: Vec<f64>,
+}
+
+// A piece of the dense output, y(t + θh) = y + h Σ_j θ^(j + 1) coefficients[j] for θ in [0, 1].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub t: f64,
+    pub h: f64,
+    pub y: Vec<f64>,
+    pub coefficients: Vec<Vec<f64>>,
+}
+
+impl Segment {
+    // The cubic Hermite interpolant through the states and derivatives at both ends of a step.
+    pub fn hermite(t: f64, h: f64, y: &[f64], f: &[f64], y_new: &[f64], f_new: &[f64]) -> Self {
+        let n = y.len();
+        let mut coefficients = vec![vec![0.0; n]; 3];
+
+        for i in 0..n {
+            let slope = (y_new[i] - y[i]) / h;
+            coefficients[0][i] = f[i];
+            coefficients[1][i] = 3.0 * slope - 2.0 * f[i] - f_new[i];
+            coefficients[2][i] = f[i] + f_new[i] - 2.0 * slope;
+        }
+
+        Self {
+            t,
+            h,
+            y: y.to_vec(),
+            coefficients,
+        }
+    }
+
+    pub fn evaluate(&self, t: f64) -> Vec<f64> {
+        let theta = (t - self.t) / self.h;
+
+        self.y
+            .iter()
+            .enumerate()
+            .map(|(i, y)| {
+                let polynomial = self
+                    .coefficients
+                    .iter()
+                    .rev()
+                    .fold(0.0, |sum, coefficient| (sum + coefficient[i]) * theta);
+                y + self.h * polynomial
+            })
+            .collect()
+    }
+}
+
+/// The trajectory computed by an ODE solver.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution {
+    /// The times of the initial point and of every accepted step.
+    pub t: Vec<f64>,
+    /// The states at the times in `t`.
+    pub y: Vec<Vec<f64>>,
+    /// The located events in the order they occurred.
+    pub events: Vec<EventRecord>,
+    /// The times at which [`OdeMethod::Auto`] switched methods, together with the method used from there on.
+    pub switches: Vec<(f64, OdeMethod)>,
+    /// The number of accepted steps.
+    pub steps: usize,
+    /// The number of rejected steps.
+    pub rejected_steps: usize,
+    /// The number of times the right hand side was evaluated.
+    pub evaluations: usize,
+    /// Why the solver stopped.
+    pub status: OdeStatus,
+    segments: Vec<Segment>,
+}
+
+impl OdeSolution {
+    /// Evaluates the dense output of the solution at any time between the first and the last point.
+    ///
+    /// The explicit Runge–Kutta methods use their continuous extensions of order four, and the other
+    /// methods use cubic Hermite interpolation between the steps.
+    ///
+    /// # Parameters
+    ///
+    /// - `t`: The time at which the solution is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated state at `t`.
+    ///
+    /// # Panics
+    ///
+    /// If `t` is outside of the computed trajectory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::ode::{InitialValueProblem, OdeMethod};
+    ///
+    /// let mut problem = InitialValueProblem::new(|_t: f64, y: &[f64]| vec![-y[0]], 0_f64, &[1_f64], 2_f64);
+    /// let solution = problem.solve(OdeMethod::DormandPrince);
+    ///
+    /// println!("y(0.5) = {:?}", solution.evaluate(0.5));
+    /// ```
+    /// <hr/>
+    pub fn evaluate(&self, t: f64) -> Vec<f64> {
+        let first = self.t[0];
+        let last = self.t[self.t.len() - 1];
+        assert!(
+            (t - first) * (last - t) >= 0.0,
+            "The time must be between {} and {}!",
+            first,
+            last
+        );
+
+        if self.segments.is_empty() {
+            return self.y[0].clone();
+        }
+
+        let direction = (last - first).signum();
+        let index = self
+            .segments
+            .partition_point(|segment| (t - segment.t) * direction >= 0.0);
+
+        self.segments[index.max(1) - 1].evaluate(t)
+    }
+}
+
+/// A zero crossing of `g(t, y)` in the given direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventDirection {
+    /// Crossings from negative to positive values.
+    Rising,
+    /// Crossings from positive to negative values.
+    Falling,
+    /// Crossings in both directions.
+    Both,
+}
+
+/// A function `g(t, y)` whose zero crossings are located during the integration.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::ode::{Event, EventDirection};
+///
+/// // Stops when the height of a falling object reaches the ground.
+/// let ground = Event::new(|_t: f64, y: &[f64]| y[0])
+///     .direction(EventDirection::Falling)
+///     .terminal(true);
+/// ```
+/// <hr/>
+pub struct Event<'a> {
+    function: Box<EventFunction<'a>>,
+    direction: EventDirection,
+    terminal: bool,
+}
+
+impl<'a> Event<'a> {
+    /// Creates an event for the zero crossings of `function` in both directions that does not stop the
+    /// integration.
+    pub fn new<G: Fn(f64, &[f64]) -> f64 + 'a>(function: G) -> Self {
+        Self {
+            function: Box::new(function),
+            direction: EventDirection::Both,
+            terminal: false,
+        }
+    }
+
+    /// Only locates the crossings in the given direction.
+    pub fn direction(mut self, direction: EventDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Stops the integration at the first crossing when `terminal` is `true`.
+    pub fn terminal(mut self, terminal: bool) -> Self {
+        self.terminal = terminal;
+        self
+    }
+
+    fn crosses(&self, before: f64, after: f64) -> bool {
+        let rising = before < 0.0 && after >= 0.0;
+        let falling = before > 0.0 && after <= 0.0;
+
+        match self.direction {
+            EventDirection::Rising => rising,
+            EventDirection::Falling => falling,
+            EventDirection::Both => rising || falling,
+        }
+    }
+}
+
+// How a solver returned: finished with a status, or asking to continue with the other kind of method from
+// the last accepted step with the given step size.
+pub enum Outcome {
+    Finished(OdeStatus),
+    Switch(f64),
+}
+
+// Records the accepted steps of a solver, locates the events on the dense output of every step, runs the
+// per-step callback and counts the steps and evaluations.
+pub struct Recorder<'p, 'a> {
+    events: &'p [Event<'a>],
+    callback: Option<&'p mut Callback<'a>>,
+    event_values: Vec<f64>,
+    solution: OdeSolution,
+}
+
+impl<'p, 'a> Recorder<'p, 'a> {
+    pub fn new(
+        events: &'p [Event<'a>],
+        callback: Option<&'p mut Callback<'a>>,
+        t: f64,
+        y: &[f64],
+    ) -> Self {
+        Self {
+            event_values: events.iter().map(|event| (event.function)(t, y)).collect(),
+            events,
+            callback,
+            solution: OdeSolution {
+                t: vec![t],
+                y: vec![y.to_vec()],
+                events: Vec::new(),
+                switches: Vec::new(),
+                steps: 0,
+                rejected_steps: 0,
+                evaluations: 0,
+                status: OdeStatus::Success,
+                segments: Vec::new(),
+            },
+        }
+    }
+
+    // The time and state of the last accepted step.
+    pub fn last(&self) -> (f64, Vec<f64>) {
+        let index = self.solution.t.len() - 1;
+        (self.solution.t[index], self.solution.y[index].clone())
+    }
+
+    pub fn steps(&self) -> usize {
+        self.solution.steps
+    }
+
+    pub fn evaluated(&mut self, evaluations: usize) {
+        self.solution.evaluations += evaluations;
+    }
+
+    pub fn reject(&mut self) {
+        self.solution.rejected_steps += 1;
+    }
+
+    pub fn switch(&mut self, method: OdeMethod) {
+        let (t, _) = self.last();
+        self.solution.switches.push((t, method));
+    }
+
+    // Adds an accepted step that ends at (t, y) and returns `true` when a terminal event stopped the
+    // integration inside of it.
+    pub fn accept(&mut self, segment: Segment, t: f64, y: Vec<f64>) -> bool {
+        let t_previous = segment.t;
+        let mut crossings: Vec<(f64, usize)> = Vec::new();
+
+        for (index, event) in self.events.iter().enumerate() {
+            let value = (event.function)(t, &y);
+
+            if event.crosses(self.event_values[index], value) {
+                let tolerance = RootTolerance {
+                    absolute: 4.0 * f64::EPSILON * (t - t_previous).abs(),
+                    ..RootTolerance::default()
+                };
+                let g = |time: f64| (event.function)(time, &segment.evaluate(time));
+
+                // The dense output can round the event function at the ends of the step to the other sign
+                // than the accepted states, which leaves Brent without a bracket. The crossing is then taken
+                // from the linear interpolation of the two event values.
+                let before = self.event_values[index];
+                let time = match Functions::brent(g, t_previous, t, tolerance) {
+                    Ok(result) => result.root,
+                    Err(_) => t_previous + (t - t_previous) * before / (before - value),
+                };
+                crossings.push((time, index));
+            }
+
+            self.event_values[index] = value;
+        }
+
+        let direction = (t - t_previous).signum();
+        crossings.sort_by(|a, b| ((a.0 - b.0) * direction).total_cmp(&0.0));
+        self.solution.steps += 1;
+
+        for (time, index) in crossings {
+            let state = segment.evaluate(time);
+            self.solution.events.push(EventRecord {
+                index,
+                t: time,
+                y: state.clone(),
+            });
+
+            if self.events[index].terminal {
+                self.push(segment, time, state);
+                return true;
+            }
+        }
+
+        self.push(segment, t, y);
+        false
+    }
+
+    fn push(&mut self, segment: Segment, t: f64, y: Vec<f64>) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(t, &y);
+        }
+
+        self.solution.segments.push(segment);
+        self.solution.t.push(t);
+        self.solution.y.push(y);
+    }
+
+    pub fn finish(mut self, status: OdeStatus) -> OdeSolution {
+        self.solution.status = status;
+        self.solution
+    }
+}
@@ -0,0 +1,552 @@
+use crate::matrix::DenseLu;
+use crate::ode::runge_kutta::{combine, is_finite, RungeKutta};
+use crate::ode::solution::{JacobianFunction, OdeOptions, OdeStatus, Outcome, Recorder, Segment};
+
+// The largest row sum of |J|, an upper bound of its spectral radius.
+fn infinity_norm(matrix: &[Vec<f64>]) -> f64 {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|value| value.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+// The matrix I - scale * J and its LU decomposition.
+fn iteration_matrix(jacobian: &[Vec<f64>], scale: f64) -> Option<DenseLu> {
+    let rows = jacobian
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, value)| if i == j { 1.0 } else { 0.0 } - scale * value)
+                .collect()
+        })
+        .collect();
+
+    DenseLu::new(rows)
+}
+
+pub struct Stiff;
+
+impl Stiff {
+    const MAX_ORDER: usize = 5;
+    const NEWTON_ITERATIONS: usize = 4;
+    // The coefficients of the numerical differentiation formulas of Klopfenstein and Shampine, which are more
+    // accurate than the plain BDFs of the same order with almost the same stability.
+    const KAPPA: [f64; 6] = [0.0, -0.185, -1.0 / 9.0, -0.0823, -0.0415, 0.0];
+
+    // Switching back to an explicit method once h‖J‖ stays inside its stability region for 15 steps.
+    const STABILITY_BOUNDARY: f64 = 3.25;
+    const NON_STIFF_STEPS: usize = 15;
+
+    // The Jacobian from the user or by forward differences.
+    fn jacobian<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        analytic: Option<&JacobianFunction>,
+        t: f64,
+        y: &[f64],
+        f: &[f64],
+        recorder: &mut Recorder,
+    ) -> Vec<Vec<f64>> {
+        if let Some(jacobian) = analytic {
+            return jacobian(t, y);
+        }
+
+        let n = y.len();
+        let mut jacobian = vec![vec![0.0; n]; n];
+        let mut point = y.to_vec();
+
+        for j in 0..n {
+            let step = f64::EPSILON.sqrt() * y[j].abs().max(1.0);
+            point[j] = y[j] + step;
+            let shifted = function(t, &point);
+            point[j] = y[j];
+
+            for i in 0..n {
+                jacobian[i][j] = (shifted[i] - f[i]) / step;
+            }
+        }
+
+        recorder.evaluated(n);
+        jacobian
+    }
+
+    fn gamma(order: usize) -> f64 {
+        (1..=order).map(|k| 1.0 / k as f64).sum()
+    }
+
+    fn alpha(order: usize) -> f64 {
+        (1.0 - Self::KAPPA[order]) * Self::gamma(order)
+    }
+
+    fn error_constant(order: usize) -> f64 {
+        Self::KAPPA[order] * Self::gamma(order) + 1.0 / (order + 1) as f64
+    }
+
+    // The matrix that maps the backward differences of the interpolating polynomial to the differences for a
+    // step size multiplied by `factor`.
+    fn rescaling(order: usize, factor: f64) -> Vec<Vec<f64>> {
+        let mut matrix = vec![vec![1.0; order + 1]; order + 1];
+
+        for i in 1..=order {
+            let previous = matrix[i - 1].clone();
+
+            for (j, value) in matrix[i].iter_mut().enumerate() {
+                *value = if j == 0 {
+                    0.0
+                } else {
+                    previous[j] * (i as f64 - 1.0 - factor * j as f64) / i as f64
+                };
+            }
+        }
+
+        matrix
+    }
+
+    fn change_step(differences: &mut [Vec<f64>], order: usize, factor: f64) {
+        let r = Self::rescaling(order, factor);
+        let u = Self::rescaling(order, 1.0);
+        let n = differences[0].len();
+
+        let product: Vec<Vec<f64>> = (0..=order)
+            .map(|i| {
+                (0..=order)
+                    .map(|j| (0..=order).map(|k| r[i][k] * u[k][j]).sum())
+                    .collect()
+            })
+            .collect();
+
+        let updated: Vec<Vec<f64>> = (0..=order)
+            .map(|i| {
+                (0..n)
+                    .map(|m| (0..=order).map(|k| product[k][i] * differences[k][m]).sum())
+                    .collect()
+            })
+            .collect();
+
+        for (difference, row) in differences.iter_mut().zip(updated) {
+            *difference = row;
+        }
+    }
+
+    // Solves c f(t, y_predict + d) = ψ + d for the correction d with a simplified Newton iteration, returning
+    // whether it converged, the number of iterations, the new state and the correction.
+    #[allow(clippy::too_many_arguments)]
+    fn correct<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        t: f64,
+        y_predict: &[f64],
+        c: f64,
+        psi: &[f64],
+        lu: &DenseLu,
+        tolerance: f64,
+        options: &OdeOptions,
+        recorder: &mut Recorder,
+    ) -> (bool, usize, Vec<f64>, Vec<f64>) {
+        let n = y_predict.len();
+        let mut y = y_predict.to_vec();
+        let mut correction = vec![0.0; n];
+        let mut previous_norm: Option<f64> = None;
+
+        for k in 0..Self::NEWTON_ITERATIONS {
+            let f = function(t, &y);
+            recorder.evaluated(1);
+            if !is_finite(&f) {
+                return (false, k + 1, y, correction);
+            }
+
+            let rhs: Vec<f64> = (0..n).map(|i| c * f[i] - psi[i] - correction[i]).collect();
+            let dy = lu.solve(&rhs);
+            let dy_norm = options.error_norm(&dy, y_predict, y_predict);
+            let rate = previous_norm.map(|previous| dy_norm / previous);
+
+            if let Some(rate) = rate {
+                let remaining = (Self::NEWTON_ITERATIONS - k) as i32;
+                if rate >= 1.0 || rate.powi(remaining) / (1.0 - rate) * dy_norm > tolerance {
+                    return (false, k + 1, y, correction);
+                }
+            }
+
+            for i in 0..n {
+                y[i] += dy[i];
+                correction[i] += dy[i];
+            }
+
+            if dy_norm == 0.0 || rate.is_some_and(|rate| rate / (1.0 - rate) * dy_norm < tolerance)
+            {
+                return (true, k + 1, y, correction);
+            }
+
+            previous_norm = Some(dy_norm);
+        }
+
+        (false, Self::NEWTON_ITERATIONS, y, correction)
+    }
+
+    // The variable order, quasi-constant step size numerical differentiation formulas of orders one to five,
+    // as in SciPy's `BDF` (Shampine and Reichelt, "The MATLAB ODE Suite"). The solution is kept as backward
+    // differences, which are rescaled whenever the step size changes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bdf<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        analytic: Option<&JacobianFunction>,
+        t_end: f64,
+        h: f64,
+        options: &OdeOptions,
+        recorder: &mut Recorder,
+        detect_non_stiffness: bool,
+    ) -> Outcome {
+        let (mut t, mut y) = recorder.last();
+        let n = y.len();
+        let direction = (t_end - t).signum();
+        let mut f = function(t, &y);
+        recorder.evaluated(1);
+
+        let mut h_abs = if h > 0.0 {
+            h
+        } else {
+            recorder.evaluated(1);
+            RungeKutta::initial_step(function, t, &y, &f, direction, 1, options)
+        };
+        h_abs = h_abs.min(options.max_step);
+
+        let tolerance =
+            (10.0 * f64::EPSILON / options.relative).max(options.relative.sqrt().min(0.03));
+        let mut jacobian = Self::jacobian(function, analytic, t, &y, &f, recorder);
+        let mut current = true;
+        let mut lu: Option<DenseLu> = None;
+
+        let mut differences = vec![vec![0.0; n]; Self::MAX_ORDER + 3];
+        differences[0] = y.clone();
+        differences[1] = f.iter().map(|value| value * h_abs * direction).collect();
+
+        let mut order = 1;
+        let mut equal_steps = 0;
+        let mut non_stiff_steps = 0;
+
+        while (t_end - t) * direction > 0.0 {
+            if recorder.steps() >= options.max_steps {
+                return Outcome::Finished(OdeStatus::MaxSteps);
+            }
+
+            let (t_new, y_new, correction, psi, c, safety, error_norm) = loop {
+                if h_abs > options.max_step {
+                    Self::change_step(&mut differences, order, options.max_step / h_abs);
+                    h_abs = options.max_step;
+                    equal_steps = 0;
+                    lu = None;
+                }
+
+                if h_abs < 10.0 * f64::EPSILON * t.abs().max(f64::MIN_POSITIVE) {
+                    return Outcome::Finished(OdeStatus::StepSizeTooSmall);
+                }
+
+                let mut t_new = t + direction * h_abs;
+                if (t_new - t_end) * direction >= 0.0 {
+                    t_new = t_end;
+                    Self::change_step(&mut differences, order, (t_new - t).abs() / h_abs);
+                    equal_steps = 0;
+                    lu = None;
+                }
+                let h = t_new - t;
+                h_abs = h.abs();
+
+                let y_predict: Vec<f64> = (0..n)
+                    .map(|i| differences[..=order].iter().map(|row| row[i]).sum())
+                    .collect();
+                let psi: Vec<f64> = (0..n)
+                    .map(|i| {
+                        (1..=order)
+                            .map(|k| Self::gamma(k) * differences[k][i])
+                            .sum::<f64>()
+                            / Self::alpha(order)
+                    })
+                    .collect();
+                let c = h / Self::alpha(order);
+
+                let mut result = None;
+                loop {
+                    if lu.is_none() {
+                        lu = iteration_matrix(&jacobian, c);
+                    }
+
+                    if let Some(matrix) = &lu {
+                        let (converged, iterations, y_new, correction) = Self::correct(
+                            function, t_new, &y_predict, c, &psi, matrix, tolerance, options,
+                            recorder,
+                        );
+                        if converged {
+                            result = Some((iterations, y_new, correction));
+                            break;
+                        }
+                    }
+
+                    if current {
+                        break;
+                    }
+
+                    let f_predict = function(t_new, &y_predict);
+                    recorder.evaluated(1);
+                    jacobian =
+                        Self::jacobian(function, analytic, t_new, &y_predict, &f_predict, recorder);
+                    current = true;
+                    lu = None;
+                }
+
+                let Some((iterations, y_new, correction)) = result else {
+                    recorder.reject();
+                    h_abs *= 0.5;
+                    Self::change_step(&mut differences, order, 0.5);
+                    equal_steps = 0;
+                    lu = None;
+                    continue;
+                };
+
+                let safety = 0.9 * (2 * Self::NEWTON_ITERATIONS + 1) as f64
+                    / (2 * Self::NEWTON_ITERATIONS + iterations) as f64;
+                let error: Vec<f64> = correction
+                    .iter()
+                    .map(|value| Self::error_constant(order) * value)
+                    .collect();
+                let error_norm = options.error_norm(&error, &y_new, &y_new);
+
+                if error_norm > 1.0 {
+                    recorder.reject();
+                    let factor = (safety * error_norm.powf(-1.0 / (order + 1) as f64))
+                        .max(RungeKutta::MIN_FACTOR);
+                    h_abs *= factor;
+                    Self::change_step(&mut differences, order, factor);
+                    equal_steps = 0;
+                    lu = None;
+                    continue;
+                }
+
+                break (t_new, y_new, correction, psi, c, safety, error_norm);
+            };
+
+            // The corrector equation gives the derivative at the new point without another evaluation.
+            let f_new: Vec<f64> = (0..n).map(|i| (psi[i] + correction[i]) / c).collect();
+            let segment = Segment::hermite(t, t_new - t, &y, &f, &y_new, &f_new);
+            if recorder.accept(segment, t_new, y_new.clone()) {
+                return Outcome::Finished(OdeStatus::Terminated);
+            }
+
+            equal_steps += 1;
+            t = t_new;
+            y = y_new;
+            f = f_new;
+            current = false;
+
+            differences[order + 2] = (0..n)
+                .map(|i| correction[i] - differences[order + 1][i])
+                .collect();
+            differences[order + 1] = correction;
+            for i in (0..=order).rev() {
+                let next = differences[i + 1].clone();
+                for (value, increment) in differences[i].iter_mut().zip(next) {
+                    *value += increment;
+                }
+            }
+
+            if detect_non_stiffness {
+                if h_abs * infinity_norm(&jacobian) < Self::STABILITY_BOUNDARY {
+                    non_stiff_steps += 1;
+                    if non_stiff_steps == Self::NON_STIFF_STEPS {
+                        return Outcome::Switch(h_abs);
+                    }
+                } else {
+                    non_stiff_steps = 0;
+                }
+            }
+
+            if equal_steps < order + 1 {
+                continue;
+            }
+
+            // Chooses the order whose error estimate allows the largest next step.
+            let scaled_norm = |order: usize, row: &[f64]| {
+                let error: Vec<f64> = row
+                    .iter()
+                    .map(|value| Self::error_constant(order) * value)
+                    .collect();
+                options.error_norm(&error, &y, &y)
+            };
+            let lower = if order > 1 {
+                scaled_norm(order - 1, &differences[order])
+            } else {
+                f64::INFINITY
+            };
+            let higher = if order < Self::MAX_ORDER {
+                scaled_norm(order + 1, &differences[order + 2])
+            } else {
+                f64::INFINITY
+            };
+
+            let factors = [
+                lower.powf(-1.0 / order as f64),
+                error_norm.powf(-1.0 / (order + 1) as f64),
+                higher.powf(-1.0 / (order + 2) as f64),
+            ];
+            let best = (0..3)
+                .max_by(|&a, &b| factors[a].total_cmp(&factors[b]))
+                .unwrap_or(1);
+            order = order + best - 1;
+
+            let factor = (safety * factors[best]).min(RungeKutta::MAX_FACTOR);
+            h_abs *= factor;
+            Self::change_step(&mut differences, order, factor);
+            equal_steps = 0;
+            lu = None;
+        }
+
+        Outcome::Finished(OdeStatus::Success)
+    }
+
+    // The L-stable Rosenbrock-W pair of orders 2 and 3 of Shampine and Reichelt (MATLAB's `ode23s`). As a
+    // W-method its order does not depend on the Jacobian being exact, so finite difference Jacobians cost
+    // no accuracy. The time derivative ∂f/∂t is approximated by a forward difference.
+    #[allow(clippy::too_many_arguments)]
+    pub fn rosenbrock<F: Fn(f64, &[f64]) -> Vec<f64>>(
+        function: &F,
+        analytic: Option<&JacobianFunction>,
+        t_end: f64,
+        mut h: f64,
+        options: &OdeOptions,
+        recorder: &mut Recorder,
+        detect_non_stiffness: bool,
+    ) -> Outcome {
+        let d = 1.0 / (2.0 + 2_f64.sqrt());
+        let e32 = 6.0 + 2_f64.sqrt();
+
+        let (mut t, mut y) = recorder.last();
+        let n = y.len();
+        let direction = (t_end - t).signum();
+        let mut f = function(t, &y);
+        recorder.evaluated(1);
+
+        if h <= 0.0 {
+            recorder.evaluated(1);
+            h = RungeKutta::initial_step(function, t, &y, &f, direction, 2, options);
+        }
+
+        let mut non_stiff_steps = 0;
+
+        while (t_end - t) * direction > 0.0 {
+            if recorder.steps() >= options.max_steps {
+                return Outcome::Finished(OdeStatus::MaxSteps);
+            }
+
+            let jacobian = Self::jacobian(function, analytic, t, &y, &f, recorder);
+            let time_step = f64::EPSILON.sqrt() * t.abs().max(1.0);
+            let shifted = function(t + time_step, &y);
+            recorder.evaluated(1);
+            let time_derivative: Vec<f64> =
+                (0..n).map(|i| (shifted[i] - f[i]) / time_step).collect();
+
+            let mut rejected = false;
+
+            let (t_new, y_new, f_new, segment, error_norm, signed) = loop {
+                h = h.min(options.max_step);
+                if h < 10.0 * f64::EPSILON * t.abs().max(f64::MIN_POSITIVE) {
+                    return Outcome::Finished(OdeStatus::StepSizeTooSmall);
+                }
+
+                let last = h >= (t_end - t).abs();
+                let signed = if last { t_end - t } else { direction * h };
+                let t_new = if last { t_end } else { t + signed };
+
+                let Some(lu) = iteration_matrix(&jacobian, signed * d) else {
+                    rejected = true;
+                    recorder.reject();
+                    h *= 0.5;
+                    continue;
+                };
+
+                let k1 = lu.solve(&combine(
+                    &f,
+                    signed * d,
+                    &[1.0],
+                    std::slice::from_ref(&time_derivative),
+                ));
+                let f1 = function(
+                    t + 0.5 * signed,
+                    &combine(&y, 0.5 * signed, &[1.0], std::slice::from_ref(&k1)),
+                );
+                let k2: Vec<f64> = lu
+                    .solve(&(0..n).map(|i| f1[i] - k1[i]).collect::<Vec<f64>>())
+                    .iter()
+                    .zip(&k1)
+                    .map(|(a, b)| a + b)
+                    .collect();
+                let y_new = combine(&y, signed, &[1.0], std::slice::from_ref(&k2));
+                let f_new = function(t_new, &y_new);
+                let k3 = lu.solve(
+                    &(0..n)
+                        .map(|i| {
+                            f_new[i] - e32 * (k2[i] - f1[i]) - 2.0 * (k1[i] - f[i])
+                                + signed * d * time_derivative[i]
+                        })
+                        .collect::<Vec<f64>>(),
+                );
+                recorder.evaluated(2);
+
+                let error: Vec<f64> = (0..n)
+                    .map(|i| signed / 6.0 * (k1[i] - 2.0 * k2[i] + k3[i]))
+                    .collect();
+                let error_norm = options.error_norm(&error, &y, &y_new);
+
+                if error_norm.is_finite() && error_norm <= 1.0 && is_finite(&f_new) {
+                    let coefficients = vec![
+                        (0..n)
+                            .map(|i| (k1[i] - 2.0 * d * k2[i]) / (1.0 - 2.0 * d))
+                            .collect(),
+                        (0..n).map(|i| (k2[i] - k1[i]) / (1.0 - 2.0 * d)).collect(),
+                    ];
+                    let segment = Segment {
+                        t,
+                        h: signed,
+                        y: y.clone(),
+                        coefficients,
+                    };
+                    break (t_new, y_new, f_new, segment, error_norm, signed);
+                }
+
+                rejected = true;
+                recorder.reject();
+                h *= if error_norm.is_finite() {
+                    (RungeKutta::SAFETY * error_norm.powf(-1.0 / 3.0)).max(RungeKutta::MIN_FACTOR)
+                } else {
+                    RungeKutta::MIN_FACTOR
+                };
+            };
+
+            if recorder.accept(segment, t_new, y_new.clone()) {
+                return Outcome::Finished(OdeStatus::Terminated);
+            }
+
+            let factor = if error_norm == 0.0 {
+                RungeKutta::MAX_FACTOR
+            } else {
+                (RungeKutta::SAFETY * error_norm.powf(-1.0 / 3.0)).min(RungeKutta::MAX_FACTOR)
+            };
+            h *= if rejected { factor.min(1.0) } else { factor };
+
+            t = t_new;
+            y = y_new;
+            f = f_new;
+
+            if detect_non_stiffness {
+                if signed.abs() * infinity_norm(&jacobian) < Self::STABILITY_BOUNDARY {
+                    non_stiff_steps += 1;
+                    if non_stiff_steps == Self::NON_STIFF_STEPS {
+                        return Outcome::Switch(h);
+                    }
+                } else {
+                    non_stiff_steps = 0;
+                }
+            }
+        }
+
+        Outcome::Finished(OdeStatus::Success)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::optimize::line_search::LineSearch;
+use crate::optimize::result::{
+    dot, largest, result, start, Gradient, Objective, OptimizeError, OptimizeResult,
+    OptimizeTolerance,
+};
+
+pub struct ConjugateGradient;
+
+impl ConjugateGradient {
+    // Conjugate gradient directions need a more exact line search than quasi-Newton ones.
+    const CURVATURE: f64 = 0.1;
+
+    // The Polak–Ribière+ method: d ← -∇f_new + β d with β = max(0, ∇f_new·(∇f_new - ∇f) / ∇f·∇f), which
+    // restarts with the steepest descent direction whenever β vanishes, every n iterations and whenever
+    // d is not a descent direction. The first trial step keeps the decrease of the last step,
+    // α₀ = α ∇f·d / ∇f_new·d_new.
+    pub fn polak_ribiere(
+        function: &Objective,
+        gradient: &Gradient,
+        guess: &[f64],
+        tolerance: OptimizeTolerance,
+    ) -> Result<OptimizeResult, OptimizeError> {
+        let n = guess.len();
+        let (mut value, mut slope) = start(function, gradient, guess)?;
+        let mut x = guess.to_vec();
+        let mut direction: Vec<f64> = slope.iter().map(|g| -g).collect();
+        let mut initial = 1.0_f64.min(1.0 / largest(&slope));
+        let mut since_restart = 0;
+
+        for iteration in 1..=tolerance.max_iterations {
+            if tolerance.gradient_converged(&slope, value) {
+                return Ok(result(x, value, slope, iteration - 1, true));
+            }
+
+            let mut point = LineSearch::wolfe(
+                function,
+                gradient,
+                &x,
+                value,
+                &slope,
+                &direction,
+                initial,
+                Self::CURVATURE,
+            );
+
+            if point.is_none() && since_restart > 0 {
+                direction = slope.iter().map(|g| -g).collect();
+                point = LineSearch::wolfe(
+                    function,
+                    gradient,
+                    &x,
+                    value,
+                    &slope,
+                    &direction,
+                    1.0_f64.min(1.0 / largest(&slope)),
+                    Self::CURVATURE,
+                );
+            }
+
+            let Some(point) = point else {
+                return Ok(result(x, value, slope, iteration, false));
+            };
+
+            let step: Vec<f64> = direction.iter().map(|d| point.alpha * d).collect();
+            let step_converged = tolerance.step_converged(&step, &point.x);
+            let decrease = point.alpha * dot(&slope, &direction);
+
+            let difference: Vec<f64> = point
+                .gradient
+                .iter()
+                .zip(&slope)
+                .map(|(a, b)| a - b)
+                .collect();
+            let beta = (dot(&point.gradient, &difference) / dot(&slope, &slope)).max(0.0);
+            since_restart += 1;
+
+            direction = if beta == 0.0 || since_restart >= n {
+                since_restart = 0;
+                point.gradient.iter().map(|g| -g).collect()
+            } else {
+                point
+                    .gradient
+                    .iter()
+                    .zip(&direction)
+                    .map(|(g, d)| -g + beta * d)
+                    .collect()
+            };
+
+            if dot(&direction, &point.gradient) >= 0.0 {
+                since_restart = 0;
+                direction = point.gradient.iter().map(|g| -g).collect();
+            }
+
+            initial = decrease / dot(&point.gradient, &direction);
+            if !initial.is_finite() || initial <= 0.0 {
+                initial = 1.0;
+            }
+
+            x = point.x;
+            value = point.value;
+            slope = point.gradient;
+
+            if step_converged {
+                let converged = tolerance.gradient_converged(&slope, value);
+                return Ok(result(x, value, slope, iteration, converged));
+            }
+        }
+
+        let converged = tolerance.gradient_converged(&slope, value);
+        Ok(result(x, value, slope, tolerance.max_iterations, converged))
+    }
+}
@@ -0,0 +1,378 @@
+use crate::optimize::result::{dot, OptimizeError, OptimizeTolerance};
+use crate::stats::distr::Chi2;
+use crate::{Functions, Matrix, Stencil};
+use std::cell::Cell;
+
+// A model y = f(x, p) and its gradient with respect to the parameters p.
+type Model<'a> = dyn Fn(f64, &[f64]) -> f64 + 'a;
+type ModelGradient<'a> = dyn Fn(f64, &[f64]) -> Vec<f64> + 'a;
+
+/// The result of a least squares fit with `N` parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitResult<const N: usize> {
+    /// The fitted parameters.
+    pub parameters: Vec<f64>,
+    /// The covariance matrix of the parameters.
+    pub covariance: Matrix<N, N>,
+    /// The standard errors of the parameters, the square roots of the diagonal of the covariance.
+    pub standard_errors: Vec<f64>,
+    /// The residuals `y - f(x, p)` at the data points.
+    pub residuals: Vec<f64>,
+    /// The sum of the squared residuals divided by the squared uncertainties of the data.
+    pub chi_squared: f64,
+    /// The number of data points minus the number of parameters.
+    pub degrees_of_freedom: usize,
+    /// `chi_squared / degrees_of_freedom`, which is close to one for a good fit with correct uncertainties.
+    pub reduced_chi_squared: f64,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The number of times the model was evaluated at a data point, including the evaluations of finite
+    /// difference Jacobians.
+    pub evaluations: usize,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl<const N: usize> FitResult<N> {
+    /// Calculates the probability that χ² with the degrees of freedom of the fit exceeds `chi_squared`
+    /// with [`Chi2::cdf`]. It is only meaningful when the uncertainties of the data were given with
+    /// [`CurveFit::sigma`].
+    pub fn p_value(&self) -> f64 {
+        1_f64 - Chi2::cdf(self.chi_squared, self.degrees_of_freedom as f64)
+    }
+}
+
+/// The fit of a model `y = f(x, p)` with `N` parameters to data points `(x, y)` that minimizes the sum of
+/// the squared residuals with the Levenberg–Marquardt method.
+///
+/// Every iteration solves the damped normal equations `(JᵀJ + λ diag(JᵀJ)) δ = Jᵀr` with the LU
+/// decomposition of a [`Matrix`], where `J` is the Jacobian of the model with respect to the parameters and
+/// `r` the residuals, both divided by the uncertainties of the data. The damping λ is adjusted with the
+/// ratio of the actual and the predicted reduction of χ². Without a [`CurveFit::jacobian`] the gradients of
+/// the model are approximated with the finite differences of [`Functions::gradient`].
+///
+/// The covariance of the parameters is `(JᵀJ)⁻¹` at the solution. Without uncertainties all data points
+/// have the weight one and the covariance is scaled by the reduced χ², which then estimates the variance of
+/// the data.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::optimize::CurveFit;
+///
+/// let x = [0_f64, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0];
+/// let y = [5.02_f64, 3.85, 3.07, 2.49, 2.13, 1.85, 1.68, 1.58, 1.49];
+///
+/// let model = |x: f64, p: &[f64]| p[0] * (-p[1] * x).exp() + p[2];
+/// let fit = CurveFit::new(model, &x, &y).fit(&[1_f64, 1_f64, 0_f64]).unwrap();
+///
+/// println!("a = {} ± {}", fit.parameters[0], fit.standard_errors[0]);
+/// println!("b = {} ± {}", fit.parameters[1], fit.standard_errors[1]);
+/// println!("c = {} ± {}", fit.parameters[2], fit.standard_errors[2]);
+/// ```
+/// <hr/>
+pub struct CurveFit<'a> {
+    model: Box<Model<'a>>,
+    jacobian: Option<Box<ModelGradient<'a>>>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    sigma: Option<Vec<f64>>,
+    stencil: Stencil,
+    tolerance: OptimizeTolerance,
+}
+
+impl<'a> CurveFit<'a> {
+    // The initial damping relative to the largest diagonal element of JᵀJ.
+    const DAMPING: f64 = 1e-3;
+    // The number of times the damping may grow in one iteration before the fit gives up.
+    const MAX_RETRIES: usize = 30;
+
+    /// Creates the fit of `model(x, p)` to the data points `(x[i], y[i])` with central difference
+    /// gradients and the default [`OptimizeTolerance`].
+    ///
+    /// # Panics
+    ///
+    /// If `x` and `y` do not have the same length.
+    pub fn new<F: Fn(f64, &[f64]) -> f64 + 'a>(model: F, x: &[f64], y: &[f64]) -> Self {
+        assert_eq!(x.len(), y.len(), "x and y must have the same length!");
+
+        Self {
+            model: Box::new(model),
+            jacobian: None,
+            x: x.to_vec(),
+            y: y.to_vec(),
+            sigma: None,
+            stencil: Stencil::Central,
+            tolerance: OptimizeTolerance::default(),
+        }
+    }
+
+    /// Sets the standard deviations of the `y` values, which weigh every residual by `1 / sigma[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If `sigma` does not have a value for every data point.
+    pub fn sigma(mut self, sigma: &[f64]) -> Self {
+        assert_eq!(
+            sigma.len(),
+            self.y.len(),
+            "sigma must have a value for every data point!"
+        );
+        self.sigma = Some(sigma.to_vec());
+        self
+    }
+
+    /// Sets the gradient `∂f/∂p` of the model at a data point, which otherwise is approximated by finite
+    /// differences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::optimize::CurveFit;
+    ///
+    /// let x = [0_f64, 1_f64, 2_f64, 3_f64, 4_f64];
+    /// let y = [2.1_f64, 1.2, 0.75, 0.45, 0.3];
+    ///
+    /// let fit = CurveFit::new(|x: f64, p: &[f64]| p[0] * (-p[1] * x).exp(), &x, &y)
+    ///     .sigma(&[0.1_f64; 5])
+    ///     .jacobian(|x: f64, p: &[f64]| {
+    ///         let decay = (-p[1] * x).exp();
+    ///         vec![decay, -p[0] * x * decay]
+    ///     })
+    ///     .fit(&[1_f64, 1_f64])
+    ///     .unwrap();
+    ///
+    /// println!("χ²/ν = {}, p = {}", fit.reduced_chi_squared, fit.p_value());
+    /// ```
+    /// <hr/>
+    pub fn jacobian<J: Fn(f64, &[f64]) -> Vec<f64> + 'a>(mut self, jacobian: J) -> Self {
+        self.jacobian = Some(Box::new(jacobian));
+        self
+    }
+
+    /// Sets the finite differences used when no Jacobian is given.
+    pub fn stencil(mut self, stencil: Stencil) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
+    /// Sets the stopping criteria. The gradient tolerance applies to `Jᵀr`, and the function tolerance to
+    /// the relative reduction of χ² in one iteration.
+    pub fn tolerance(mut self, tolerance: OptimizeTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Fits the model, starting from the parameters `guess`.
+    ///
+    /// # Returns
+    ///
+    /// A `FitResult` with the parameters, their covariance and standard errors, the residuals, χ² and the
+    /// numbers of iterations and evaluations. A fit that stops because the damping no longer finds a step
+    /// that decreases χ², for example where the model is not finite, returns its last point as not
+    /// converged.
+    ///
+    /// # Errors
+    ///
+    /// - `OptimizeError::NonFiniteValue` if the model or its gradient is not finite at `guess`.
+    /// - `OptimizeError::InsufficientData` if there are not more data points than parameters.
+    /// - `OptimizeError::SingularCovariance` if `JᵀJ` is singular at the solution, usually because the model
+    ///   does not depend on some parameter or on a combination of them.
+    ///
+    /// # Panics
+    ///
+    /// If the Jacobian does not return `N` components.
+    pub fn fit<const N: usize>(&self, guess: &[f64; N]) -> Result<FitResult<N>, OptimizeError> {
+        let m = self.x.len();
+        if m <= N {
+            return Err(OptimizeError::InsufficientData {
+                points: m,
+                parameters: N,
+            });
+        }
+
+        let evaluations = Cell::new(0);
+        let model = |x: f64, p: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            (self.model)(x, p)
+        };
+        let weight = |i: usize| self.sigma.as_ref().map_or(1_f64, |sigma| 1_f64 / sigma[i]);
+
+        // The weighted residuals and their sum of squares, or None where the model is not finite.
+        let residuals = |p: &[f64]| -> Option<(Vec<f64>, f64)> {
+            let r: Vec<f64> = (0..m)
+                .map(|i| (self.y[i] - model(self.x[i], p)) * weight(i))
+                .collect();
+            let chi_squared = dot(&r, &r);
+            chi_squared.is_finite().then_some((r, chi_squared))
+        };
+
+        // JᵀJ and Jᵀr for the weighted Jacobian.
+        let normal = |p: &[f64; N], r: &[f64]| -> Option<(Matrix<N, N>, Vec<f64>)> {
+            let mut product = Matrix::new([[0_f64; N]; N]);
+            let mut rhs = vec![0_f64; N];
+
+            for (i, (&x, residual)) in self.x.iter().zip(r).enumerate() {
+                let row = match &self.jacobian {
+                    Some(jacobian) => {
+                        let row = jacobian(x, p);
+                        assert_eq!(row.len(), N, "The Jacobian must have {} components!", N);
+                        row
+                    }
+                    None => Functions::gradient(|p: &[f64]| model(x, p), p, self.stencil)
+                        .as_slice()
+                        .to_vec(),
+                };
+                let w = weight(i);
+
+                for j in 0..N {
+                    rhs[j] += row[j] * w * residual;
+                    for k in 0..N {
+                        product[j][k] += row[j] * row[k] * w * w;
+                    }
+                }
+            }
+
+            (0..N)
+                .all(|j| rhs[j].is_finite() && product[j].iter().all(|a| a.is_finite()))
+                .then_some((product, rhs))
+        };
+
+        let non_finite = || OptimizeError::NonFiniteValue { x: guess.to_vec() };
+        let mut p = *guess;
+        let (mut r, mut chi_squared) = residuals(&p).ok_or_else(non_finite)?;
+        let (mut product, mut rhs) = normal(&p, &r).ok_or_else(non_finite)?;
+
+        let largest_diagonal = (0..N).map(|j| product[j][j]).fold(0_f64, f64::max);
+        let mut damping = Self::DAMPING * largest_diagonal.max(f64::MIN_POSITIVE);
+        let mut growth = 2_f64;
+        let mut iterations = 0;
+        let mut converged = false;
+
+        'outer: while iterations < self.tolerance.max_iterations {
+            if self.tolerance.gradient_converged(&rhs, chi_squared) {
+                converged = true;
+                break;
+            }
+            iterations += 1;
+
+            // Marquardt's scaling keeps the damping invariant under a rescaling of the parameters.
+            let scaling: Vec<f64> = (0..N)
+                .map(|j| {
+                    if product[j][j] > 0_f64 {
+                        product[j][j]
+                    } else {
+                        1_f64
+                    }
+                })
+                .collect();
+
+            let mut retries = 0;
+            loop {
+                if retries == Self::MAX_RETRIES {
+                    break 'outer;
+                }
+
+                let mut damped = product.clone();
+                for j in 0..N {
+                    damped[j][j] += damping * scaling[j];
+                }
+
+                let Some(step) = damped.solve(&rhs) else {
+                    damping *= growth;
+                    growth *= 2_f64;
+                    retries += 1;
+                    continue;
+                };
+                let step_converged = self.tolerance.step_converged(&step, &p);
+
+                let mut trial = p;
+                trial.iter_mut().zip(&step).for_each(|(p, d)| *p += d);
+
+                let predicted: f64 = (0..N)
+                    .map(|j| step[j] * (damping * scaling[j] * step[j] + rhs[j]))
+                    .sum();
+                let evaluated = residuals(&trial)
+                    .and_then(|(r, chi_squared)| Some((normal(&trial, &r)?, r, chi_squared)));
+
+                let Some(((new_product, new_rhs), new_r, new_chi_squared)) =
+                    evaluated.filter(|(_, _, new_chi_squared)| *new_chi_squared < chi_squared)
+                else {
+                    // A step below the tolerance that still does not decrease χ² means that the growing
+                    // damping cannot find a better point: the fit has stalled.
+                    if step_converged {
+                        break 'outer;
+                    }
+                    damping *= growth;
+                    growth *= 2_f64;
+                    retries += 1;
+                    continue;
+                };
+
+                let ratio = (chi_squared - new_chi_squared) / predicted;
+                damping *= (1_f64 / 3_f64).max(1_f64 - (2_f64 * ratio - 1_f64).powi(3));
+                growth = 2_f64;
+
+                let reduction = chi_squared - new_chi_squared;
+                p = trial;
+                r = new_r;
+                chi_squared = new_chi_squared;
+                product = new_product;
+                rhs = new_rhs;
+
+                if step_converged || reduction <= self.tolerance.function * chi_squared {
+                    converged = true;
+                    break 'outer;
+                }
+                break;
+            }
+        }
+
+        let lu = product
+            .lu()
+            .ok_or_else(|| OptimizeError::SingularCovariance {
+                parameters: p.to_vec(),
+            })?;
+
+        let degrees_of_freedom = m - N;
+        let reduced_chi_squared = chi_squared / degrees_of_freedom as f64;
+        let scale = if self.sigma.is_some() {
+            1_f64
+        } else {
+            reduced_chi_squared
+        };
+
+        let mut covariance = Matrix::new([[0_f64; N]; N]);
+        for j in 0..N {
+            let mut unit = [0_f64; N];
+            unit[j] = 1_f64;
+            let column = lu.solve(&unit);
+
+            for (k, value) in column.iter().enumerate() {
+                covariance[k][j] = scale * value;
+            }
+        }
+
+        if (0..N).any(|j| covariance[j][j] < 0_f64 || !covariance[j][j].is_finite()) {
+            return Err(OptimizeError::SingularCovariance {
+                parameters: p.to_vec(),
+            });
+        }
+
+        let standard_errors = (0..N).map(|j| covariance[j][j].sqrt()).collect();
+        let residuals = r.iter().enumerate().map(|(i, r)| r / weight(i)).collect();
+
+        Ok(FitResult {
+            parameters: p.to_vec(),
+            covariance,
+            standard_errors,
+            residuals,
+            chi_squared,
+            degrees_of_freedom,
+            reduced_chi_squared,
+            iterations,
+            evaluations: evaluations.get(),
+            converged,
+        })
+    }
+}
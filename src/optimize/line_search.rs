@@ -0,0 +1,187 @@
+use crate::optimize::result::{axpy, dot, is_finite, Gradient, Objective};
+
+// A point x + alpha d on the search line with its function value, gradient and directional derivative.
+pub struct Point {
+    pub alpha: f64,
+    pub x: Vec<f64>,
+    pub value: f64,
+    pub gradient: Vec<f64>,
+    pub slope: f64,
+}
+
+pub struct LineSearch;
+
+impl LineSearch {
+    // The sufficient decrease parameter of the Armijo condition.
+    const ARMIJO: f64 = 1e-4;
+    const MAX_EXPANSIONS: usize = 50;
+    const MAX_ZOOMS: usize = 50;
+
+    // A step that satisfies the strong Wolfe conditions
+    //     f(x + αd) <= f(x) + c1 α ∇f(x)·d    and    |∇f(x + αd)·d| <= c2 |∇f(x)·d|
+    // with Algorithms 3.5 and 3.6 of Nocedal and Wright, "Numerical Optimization". The step grows from
+    // `initial` until it brackets an acceptable step, which is then found by safeguarded quadratic
+    // interpolation. Steps where the function is not finite are treated as too long. When the curvature
+    // condition cannot be met the best step with sufficient decrease is returned, and `None` when there is
+    // none or `d` is not a descent direction.
+    #[allow(clippy::too_many_arguments)]
+    pub fn wolfe(
+        function: &Objective,
+        gradient: &Gradient,
+        x: &[f64],
+        value: f64,
+        slope: &[f64],
+        direction: &[f64],
+        initial: f64,
+        curvature: f64,
+    ) -> Option<Point> {
+        let slope_0 = dot(slope, direction);
+        if slope_0 >= 0.0 || !slope_0.is_finite() {
+            return None;
+        }
+
+        let start = Point {
+            alpha: 0.0,
+            x: x.to_vec(),
+            value,
+            gradient: slope.to_vec(),
+            slope: slope_0,
+        };
+        let sufficient = |alpha: f64, f: f64| f <= value + Self::ARMIJO * alpha * slope_0;
+        let evaluate = |alpha: f64| {
+            let point = axpy(x, alpha, direction);
+            let f = function(&point);
+            (point, if f.is_finite() { f } else { f64::INFINITY })
+        };
+        let complete = |alpha: f64, point: Vec<f64>, f: f64| {
+            let g = gradient(&point);
+            is_finite(&g).then(|| Point {
+                alpha,
+                slope: dot(&g, direction),
+                x: point,
+                value: f,
+                gradient: g,
+            })
+        };
+
+        let mut previous = start;
+        let mut alpha = initial;
+
+        for expansion in 0..Self::MAX_EXPANSIONS {
+            let (point, f) = evaluate(alpha);
+
+            if !sufficient(alpha, f) || (expansion > 0 && f >= previous.value) {
+                return Self::zoom(
+                    previous,
+                    alpha,
+                    f,
+                    &evaluate,
+                    &complete,
+                    &sufficient,
+                    slope_0,
+                    curvature,
+                );
+            }
+
+            let Some(point) = complete(alpha, point, f) else {
+                return Self::zoom(
+                    previous,
+                    alpha,
+                    f64::INFINITY,
+                    &evaluate,
+                    &complete,
+                    &sufficient,
+                    slope_0,
+                    curvature,
+                );
+            };
+
+            if point.slope.abs() <= -curvature * slope_0 {
+                return Some(point);
+            }
+
+            if point.slope >= 0.0 {
+                return Self::zoom(
+                    point,
+                    previous.alpha,
+                    previous.value,
+                    &evaluate,
+                    &complete,
+                    &sufficient,
+                    slope_0,
+                    curvature,
+                );
+            }
+
+            previous = point;
+            alpha *= 2.0;
+        }
+
+        (previous.alpha > 0.0).then_some(previous)
+    }
+
+    // Shrinks the interval between `low`, the best step so far with sufficient decrease, and `high` until
+    // it contains a step that satisfies the strong Wolfe conditions.
+    #[allow(clippy::too_many_arguments)]
+    fn zoom<E, C, S>(
+        mut low: Point,
+        mut high: f64,
+        mut f_high: f64,
+        evaluate: &E,
+        complete: &C,
+        sufficient: &S,
+        slope_0: f64,
+        curvature: f64,
+    ) -> Option<Point>
+    where
+        E: Fn(f64) -> (Vec<f64>, f64),
+        C: Fn(f64, Vec<f64>, f64) -> Option<Point>,
+        S: Fn(f64, f64) -> bool,
+    {
+        for _ in 0..Self::MAX_ZOOMS {
+            let width = high - low.alpha;
+            if width.abs() <= f64::EPSILON * low.alpha.abs().max(high.abs()) {
+                break;
+            }
+
+            // The minimum of the parabola through f(low), its slope and f(high), kept away from the ends.
+            let curvature_term = f_high - low.value - low.slope * width;
+            let mut alpha = if f_high.is_finite() && curvature_term > 0.0 {
+                low.alpha - low.slope * width * width / (2.0 * curvature_term)
+            } else {
+                low.alpha + 0.5 * width
+            };
+            let fraction = (alpha - low.alpha) / width;
+            if !(0.1..=0.9).contains(&fraction) {
+                alpha = low.alpha + 0.5 * width;
+            }
+
+            let (point, f) = evaluate(alpha);
+
+            if !sufficient(alpha, f) || f >= low.value {
+                high = alpha;
+                f_high = f;
+                continue;
+            }
+
+            let Some(point) = complete(alpha, point, f) else {
+                high = alpha;
+                f_high = f64::INFINITY;
+                continue;
+            };
+
+            if point.slope.abs() <= -curvature * slope_0 {
+                return Some(point);
+            }
+
+            if point.slope * (high - low.alpha) >= 0.0 {
+                high = low.alpha;
+                f_high = low.value;
+            }
+
+            low = point;
+        }
+
+        (low.alpha > 0.0).then_some(low)
+    }
+}
@@ -0,0 +1,343 @@
+use crate::optimize::result::OptimizeError;
+use crate::Matrix;
+
+/// The relation between the two sides of a linear constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// `a·x <= b`
+    LessEqual,
+    /// `a·x >= b`
+    GreaterEqual,
+    /// `a·x = b`
+    Equal,
+}
+
+/// The optimal solution of a [`LinearProgram`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LpSolution {
+    /// The values of the variables.
+    pub x: Vec<f64>,
+    /// The minimum of the objective `c·x`.
+    pub objective: f64,
+    /// The number of simplex pivots of both phases.
+    pub iterations: usize,
+}
+
+/// The linear program of minimizing `c·x` subject to linear constraints and `x >= 0`.
+///
+/// The program is solved with the two-phase simplex method on a dense tableau. The first phase minimizes
+/// the sum of artificial variables to find a feasible basis, and the second phase minimizes the objective
+/// from it. Entering variables are chosen by the most negative reduced cost until the first degenerate
+/// pivot and by Bland's rule afterwards, which rules out cycling, so the result only depends on the input.
+/// Maximizing `c·x` is minimizing `-c·x`.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::optimize::{LinearProgram, Relation};
+///
+/// // Maximizes 3x + 5y with x <= 4, 2y <= 12 and 3x + 2y <= 18.
+/// let program = LinearProgram::new(&[-3_f64, -5_f64])
+///     .constraint(&[1_f64, 0_f64], Relation::LessEqual, 4_f64)
+///     .constraint(&[0_f64, 2_f64], Relation::LessEqual, 12_f64)
+///     .constraint(&[3_f64, 2_f64], Relation::LessEqual, 18_f64);
+///
+/// let solution = program.solve().unwrap();
+///
+/// println!("The maximum {} is at {:?}", -solution.objective, solution.x);
+/// ```
+/// <hr/>
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearProgram {
+    objective: Vec<f64>,
+    constraints: Vec<(Vec<f64>, Relation, f64)>,
+}
+
+impl LinearProgram {
+    // The tolerance on reduced costs, pivots and the infeasibility of the first phase.
+    const TOLERANCE: f64 = 1e-9;
+
+    /// Creates the program of minimizing `objective·x` subject to `x >= 0` and no other constraints.
+    pub fn new(objective: &[f64]) -> Self {
+        Self {
+            objective: objective.to_vec(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Creates the program in standard form: minimizing `objective·x` subject to `Ax = b` and `x >= 0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::optimize::LinearProgram;
+    /// use numerilib::Matrix;
+    ///
+    /// let a = Matrix::new([
+    ///     [1_f64, 1_f64, 1_f64, 0_f64],
+    ///     [1_f64, -1_f64, 0_f64, 1_f64],
+    /// ]);
+    ///
+    /// let solution = LinearProgram::standard(&[-1_f64, -2_f64, 0_f64, 0_f64], &a, &[4_f64, 2_f64])
+    ///     .solve()
+    ///     .unwrap();
+    ///
+    /// println!("x = {:?}", solution.x);
+    /// ```
+    /// <hr/>
+    pub fn standard<const M: usize, const N: usize>(
+        objective: &[f64; N],
+        a: &Matrix<M, N>,
+        b: &[f64; M],
+    ) -> Self {
+        (0..M).fold(Self::new(objective), |program, i| {
+            program.constraint(&a[i], Relation::Equal, b[i])
+        })
+    }
+
+    /// Adds the constraint `coefficients·x relation rhs`.
+    ///
+    /// # Panics
+    ///
+    /// If `coefficients` does not have a value for every variable.
+    pub fn constraint(mut self, coefficients: &[f64], relation: Relation, rhs: f64) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            self.objective.len(),
+            "The constraint must have a coefficient for every variable!"
+        );
+        self.constraints
+            .push((coefficients.to_vec(), relation, rhs));
+        self
+    }
+
+    /// Solves the program.
+    ///
+    /// # Returns
+    ///
+    /// An `LpSolution` with an optimal vertex, the minimum of the objective and the number of pivots.
+    ///
+    /// # Errors
+    ///
+    /// - `OptimizeError::Infeasible` if no `x` satisfies the constraints.
+    /// - `OptimizeError::Unbounded` if the objective has no minimum on the feasible set.
+    pub fn solve(&self) -> Result<LpSolution, OptimizeError> {
+        let n = self.objective.len();
+
+        // Every row gets a non-negative right hand side, and a slack variable if it is an inequality.
+        let rows: Vec<(Vec<f64>, Relation, f64)> = self
+            .constraints
+            .iter()
+            .map(|(a, relation, b)| {
+                if *b < 0.0 {
+                    let flipped = match relation {
+                        Relation::LessEqual => Relation::GreaterEqual,
+                        Relation::GreaterEqual => Relation::LessEqual,
+                        Relation::Equal => Relation::Equal,
+                    };
+                    (a.iter().map(|a| -a).collect(), flipped, -b)
+                } else {
+                    (a.clone(), *relation, *b)
+                }
+            })
+            .collect();
+
+        let slacks = rows
+            .iter()
+            .filter(|(_, relation, _)| *relation != Relation::Equal)
+            .count();
+        let artificials = rows
+            .iter()
+            .filter(|(_, relation, _)| *relation != Relation::LessEqual)
+            .count();
+        let structural = n + slacks;
+        let columns = structural + artificials;
+
+        let mut tableau = Tableau {
+            rows: Vec::with_capacity(rows.len()),
+            costs: vec![0.0; columns + 1],
+            basis: Vec::with_capacity(rows.len()),
+            bland: false,
+            iterations: 0,
+        };
+
+        let (mut slack, mut artificial) = (n, structural);
+        for (a, relation, b) in &rows {
+            let mut row = vec![0.0; columns + 1];
+            row[..n].copy_from_slice(a);
+            row[columns] = *b;
+
+            match relation {
+                Relation::LessEqual => {
+                    row[slack] = 1.0;
+                    tableau.basis.push(slack);
+                    slack += 1;
+                }
+                Relation::GreaterEqual => {
+                    row[slack] = -1.0;
+                    row[artificial] = 1.0;
+                    tableau.basis.push(artificial);
+                    slack += 1;
+                    artificial += 1;
+                }
+                Relation::Equal => {
+                    row[artificial] = 1.0;
+                    tableau.basis.push(artificial);
+                    artificial += 1;
+                }
+            }
+            tableau.rows.push(row);
+        }
+
+        // Phase one minimizes the sum of the artificial variables, whose reduced costs start at zero.
+        let scale = rows.iter().fold(1.0, |scale: f64, (_, _, b)| scale.max(*b));
+        if artificials > 0 {
+            for (row, &basic) in tableau.rows.iter().zip(&tableau.basis) {
+                if basic >= structural {
+                    for (cost, value) in tableau.costs[..structural].iter_mut().zip(row) {
+                        *cost -= value;
+                    }
+                    tableau.costs[columns] -= row[columns];
+                }
+            }
+
+            tableau.simplex(columns)?;
+
+            if -tableau.costs[columns] > Self::TOLERANCE * scale {
+                return Err(OptimizeError::Infeasible);
+            }
+
+            // Pivots the artificial variables that are still basic at zero out of the basis, and drops
+            // the rows where that is impossible because they are linear combinations of the others.
+            let mut i = 0;
+            while i < tableau.rows.len() {
+                if tableau.basis[i] >= structural {
+                    let entering = (0..structural)
+                        .max_by(|&a, &b| {
+                            tableau.rows[i][a]
+                                .abs()
+                                .total_cmp(&tableau.rows[i][b].abs())
+                        })
+                        .filter(|&j| tableau.rows[i][j].abs() > Self::TOLERANCE);
+
+                    match entering {
+                        Some(j) => tableau.pivot(i, j),
+                        None => {
+                            tableau.rows.remove(i);
+                            tableau.basis.remove(i);
+                            continue;
+                        }
+                    }
+                }
+                i += 1;
+            }
+        }
+
+        // Phase two starts from the reduced costs of the objective in the feasible basis.
+        tableau.costs = vec![0.0; columns + 1];
+        tableau.costs[..n].copy_from_slice(&self.objective);
+        for (row, &basic) in tableau.rows.iter().zip(&tableau.basis) {
+            let cost = if basic < n {
+                self.objective[basic]
+            } else {
+                0.0
+            };
+            if cost != 0.0 {
+                for (reduced, value) in tableau.costs.iter_mut().zip(row) {
+                    *reduced -= cost * value;
+                }
+            }
+        }
+
+        tableau.simplex(structural)?;
+
+        let mut x = vec![0.0; n];
+        for (row, &basic) in tableau.rows.iter().zip(&tableau.basis) {
+            if basic < n {
+                x[basic] = row[columns];
+            }
+        }
+        let objective = x.iter().zip(&self.objective).map(|(x, c)| x * c).sum();
+
+        Ok(LpSolution {
+            x,
+            objective,
+            iterations: tableau.iterations,
+        })
+    }
+}
+
+// A simplex tableau: the constraint rows with the right hand side in the last column, the reduced costs
+// with minus the objective in the last column, and the basic variable of every row.
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    costs: Vec<f64>,
+    basis: Vec<usize>,
+    bland: bool,
+    iterations: usize,
+}
+
+impl Tableau {
+    // Makes `column` basic in `row` by Gauss–Jordan elimination.
+    fn pivot(&mut self, row: usize, column: usize) {
+        let value = self.rows[row][column];
+        self.rows[row].iter_mut().for_each(|entry| *entry /= value);
+        let pivot_row = self.rows[row].clone();
+
+        for (i, other) in self.rows.iter_mut().enumerate() {
+            let factor = other[column];
+            if i != row && factor != 0.0 {
+                for (entry, pivot) in other.iter_mut().zip(&pivot_row) {
+                    *entry -= factor * pivot;
+                }
+                other[column] = 0.0;
+            }
+        }
+
+        let factor = self.costs[column];
+        for (entry, pivot) in self.costs.iter_mut().zip(&pivot_row) {
+            *entry -= factor * pivot;
+        }
+        self.costs[column] = 0.0;
+
+        self.basis[row] = column;
+        self.iterations += 1;
+    }
+
+    // Pivots until no reduced cost among the first `allowed` columns is negative.
+    fn simplex(&mut self, allowed: usize) -> Result<(), OptimizeError> {
+        let rhs = self.costs.len() - 1;
+
+        loop {
+            let candidates = (0..allowed).filter(|&j| self.costs[j] < -LinearProgram::TOLERANCE);
+            let entering = if self.bland {
+                candidates.min()
+            } else {
+                candidates.min_by(|&a, &b| self.costs[a].total_cmp(&self.costs[b]))
+            };
+
+            let Some(column) = entering else {
+                return Ok(());
+            };
+
+            // The ratio test, with ties broken by the smallest basic variable as in Bland's rule.
+            let leaving = (0..self.rows.len())
+                .filter(|&i| self.rows[i][column] > LinearProgram::TOLERANCE)
+                .map(|i| (i, self.rows[i][rhs] / self.rows[i][column]))
+                .min_by(|a, b| {
+                    a.1.total_cmp(&b.1)
+                        .then(self.basis[a.0].cmp(&self.basis[b.0]))
+                });
+
+            let Some((row, ratio)) = leaving else {
+                return Err(OptimizeError::Unbounded);
+            };
+
+            if ratio <= LinearProgram::TOLERANCE {
+                self.bland = true;
+            }
+
+            self.pivot(row, column);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::optimize::conjugate_gradient::ConjugateGradient;
+use crate::optimize::nelder_mead::NelderMead;
+use crate::optimize::projected_gradient::ProjectedGradient;
+use crate::optimize::quasi_newton::QuasiNewton;
+use crate::optimize::result::{
+    Gradient, Objective, OptimizeError, OptimizeResult, OptimizeTolerance,
+};
+use crate::{Functions, Stencil};
+use std::cell::Cell;
+
+/// The minimization methods of a [`Minimizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptimizeMethod {
+    /// The derivative-free Nelder–Mead simplex method with the adaptive coefficients of Gao and Han. It is
+    /// robust for noisy or non-smooth functions, but slow in more than a few dimensions.
+    NelderMead,
+    /// The BFGS quasi-Newton method, which builds a dense approximation of the inverse Hessian from the
+    /// gradients.
+    Bfgs,
+    /// The limited memory BFGS method, which keeps only the given number of the latest corrections (usually
+    /// between 5 and 20) and suits problems with many variables.
+    Lbfgs(usize),
+    /// The Polak–Ribière+ nonlinear conjugate gradient method, which needs only a few vectors of memory.
+    ConjugateGradient,
+    /// The spectral projected gradient method, the only method that respects the
+    /// [`Minimizer::bounds`] on the variables. Without bounds it is a steepest descent method with
+    /// Barzilai–Borwein steps.
+    ProjectedGradient,
+}
+
+/// The minimization of a function `f: ℝⁿ → ℝ` without constraints or with bounds on the variables.
+///
+/// The minimizer is created from the function and optionally configured with its gradient, the bounds and
+/// the tolerances. Without a gradient the gradient-based methods use the finite differences of
+/// [`Functions::gradient`] with the given [`Stencil`]. The gradient-based methods search along each
+/// direction with a line search that satisfies the strong Wolfe conditions.
+///
+/// # Example
+///
+/// ```rust
+/// use numerilib::optimize::{Minimizer, OptimizeMethod};
+/// use numerilib::stats::distr::Gaussian;
+///
+/// // Fits a Gaussian distribution to the data by maximum likelihood.
+/// let data = [4.9_f64, 5.3, 6.1, 4.4, 5.8, 5.0, 6.4, 4.7];
+/// let likelihood = |p: &[f64]| -data.iter().map(|&x| Gaussian::pdf(x, p[0], p[1]).ln()).sum::<f64>();
+///
+/// let minimizer = Minimizer::new(likelihood);
+/// let result = minimizer.minimize(OptimizeMethod::Bfgs, &[4_f64, 2_f64]).unwrap();
+///
+/// println!("mean = {}, sd = {}", result.argmin[0], result.argmin[1]);
+/// ```
+/// <hr/>
+pub struct Minimizer<'a> {
+    function: Box<Objective<'a>>,
+    gradient: Option<Box<Gradient<'a>>>,
+    bounds: Option<(Vec<f64>, Vec<f64>)>,
+    stencil: Stencil,
+    tolerance: OptimizeTolerance,
+}
+
+impl<'a> Minimizer<'a> {
+    /// Creates the minimizer of `function` with central difference gradients and the default
+    /// [`OptimizeTolerance`].
+    pub fn new<F: Fn(&[f64]) -> f64 + 'a>(function: F) -> Self {
+        Self {
+            function: Box::new(function),
+            gradient: None,
+            bounds: None,
+            stencil: Stencil::Central,
+            tolerance: OptimizeTolerance::default(),
+        }
+    }
+
+    /// Sets the gradient of the function, which otherwise is approximated by finite differences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::optimize::{Minimizer, OptimizeMethod};
+    ///
+    /// // The Rosenbrock function, whose minimum is at (1, 1).
+    /// let rosenbrock = |x: &[f64]| (1_f64 - x[0]).powi(2) + 100_f64 * (x[1] - x[0] * x[0]).powi(2);
+    ///
+    /// let minimizer = Minimizer::new(rosenbrock).gradient(|x: &[f64]| {
+    ///     vec![
+    ///         -2_f64 * (1_f64 - x[0]) - 400_f64 * x[0] * (x[1] - x[0] * x[0]),
+    ///         200_f64 * (x[1] - x[0] * x[0]),
+    ///     ]
+    /// });
+    /// let result = minimizer.minimize(OptimizeMethod::Lbfgs(10), &[-1.2_f64, 1_f64]).unwrap();
+    ///
+    /// println!("The minimum is at {:?} after {} iterations", result.argmin, result.iterations);
+    /// ```
+    /// <hr/>
+    pub fn gradient<G: Fn(&[f64]) -> Vec<f64> + 'a>(mut self, gradient: G) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    /// Sets the bounds `lower[i] <= x[i] <= upper[i]` of [`OptimizeMethod::ProjectedGradient`]. Infinite
+    /// bounds leave a variable free on that side. The function is only evaluated inside the bounds, except
+    /// for the finite difference gradients at a bound.
+    ///
+    /// # Panics
+    ///
+    /// If `lower` and `upper` do not have the same length, or some lower bound exceeds its upper bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::optimize::{Minimizer, OptimizeMethod};
+    ///
+    /// // The unconstrained minimum at (3, -1) lies outside the box [0, 2] x [0, 2].
+    /// let function = |x: &[f64]| (x[0] - 3_f64).powi(2) + (x[1] + 1_f64).powi(2) + x[0] * x[1];
+    ///
+    /// let result = Minimizer::new(function)
+    ///     .bounds(&[0_f64, 0_f64], &[2_f64, 2_f64])
+    ///     .minimize(OptimizeMethod::ProjectedGradient, &[1_f64, 1_f64])
+    ///     .unwrap();
+    ///
+    /// println!("The minimum on the box is at {:?}", result.argmin);
+    /// ```
+    /// <hr/>
+    pub fn bounds(mut self, lower: &[f64], upper: &[f64]) -> Self {
+        assert_eq!(
+            lower.len(),
+            upper.len(),
+            "The bounds must have the same length!"
+        );
+        assert!(
+            lower.iter().zip(upper).all(|(lower, upper)| lower <= upper),
+            "The lower bounds must not exceed the upper bounds!"
+        );
+        self.bounds = Some((lower.to_vec(), upper.to_vec()));
+        self
+    }
+
+    /// Sets the finite differences used when no gradient is given.
+    pub fn stencil(mut self, stencil: Stencil) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
+    /// Sets the stopping criteria.
+    pub fn tolerance(mut self, tolerance: OptimizeTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Minimizes the function with the given method, starting from `guess`.
+    ///
+    /// # Returns
+    ///
+    /// An `OptimizeResult` with the approximation of the minimizer, the function value and gradient there,
+    /// the numbers of iterations and evaluations, and whether the tolerance was reached. A method that stops
+    /// early because no step decreases the function anymore, usually at the limit of the floating point
+    /// precision or of the finite difference gradient, returns its last point as not converged.
+    ///
+    /// # Errors
+    ///
+    /// `OptimizeError::NonFiniteValue` if the function or its gradient is not finite at `guess`.
+    ///
+    /// # Panics
+    ///
+    /// If the gradient does not return `N` components, if the bounds do not have `N` values, or if bounds are
+    /// set and the method is not [`OptimizeMethod::ProjectedGradient`].
+    pub fn minimize<const N: usize>(
+        &self,
+        method: OptimizeMethod,
+        guess: &[f64; N],
+    ) -> Result<OptimizeResult, OptimizeError> {
+        if let Some((lower, _)) = &self.bounds {
+            assert_eq!(lower.len(), N, "The bounds must have {} values!", N);
+            assert_eq!(
+                method,
+                OptimizeMethod::ProjectedGradient,
+                "Only the projected gradient method supports bounds!"
+            );
+        }
+
+        let evaluations = Cell::new(0);
+        let gradient_evaluations = Cell::new(0);
+
+        let function = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            (self.function)(x)
+        };
+        let gradient = |x: &[f64]| {
+            gradient_evaluations.set(gradient_evaluations.get() + 1);
+
+            match &self.gradient {
+                Some(gradient) => {
+                    let slope = gradient(x);
+                    assert_eq!(slope.len(), N, "The gradient must have {} components!", N);
+                    slope
+                }
+                None => {
+                    let point: [f64; N] = x.try_into().expect("The point must have N components!");
+                    Functions::gradient(function, &point, self.stencil)
+                        .as_slice()
+                        .to_vec()
+                }
+            }
+        };
+
+        let tolerance = self.tolerance;
+        let mut result = match method {
+            OptimizeMethod::NelderMead => NelderMead::minimize(&function, guess, tolerance),
+            OptimizeMethod::Bfgs => QuasiNewton::bfgs(&function, &gradient, guess, tolerance),
+            OptimizeMethod::Lbfgs(memory) => {
+                QuasiNewton::lbfgs(&function, &gradient, guess, memory, tolerance)
+            }
+            OptimizeMethod::ConjugateGradient => {
+                ConjugateGradient::polak_ribiere(&function, &gradient, guess, tolerance)
+            }
+            OptimizeMethod::ProjectedGradient => {
+                let (lower, upper) = match &self.bounds {
+                    Some((lower, upper)) => (lower.clone(), upper.clone()),
+                    None => (vec![f64::NEG_INFINITY; N], vec![f64::INFINITY; N]),
+                };
+                ProjectedGradient::minimize(&function, &gradient, guess, &lower, &upper, tolerance)
+            }
+        }?;
+
+        result.evaluations = evaluations.get();
+        result.gradient_evaluations = gradient_evaluations.get();
+        Ok(result)
+    }
+}
@@ -0,0 +1,14 @@
+mod conjugate_gradient;
+mod least_squares;
+mod line_search;
+mod linear_program;
+mod minimizer;
+mod nelder_mead;
+mod projected_gradient;
+mod quasi_newton;
+mod result;
+
+pub use least_squares::{CurveFit, FitResult};
+pub use linear_program::{LinearProgram, LpSolution, Relation};
+pub use minimizer::{Minimizer, OptimizeMethod};
+pub use result::{OptimizeError, OptimizeResult, OptimizeTolerance};
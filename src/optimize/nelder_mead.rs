@@ -0,0 +1,145 @@
+use crate::optimize::result::{
+    largest, result, subtract, Objective, OptimizeError, OptimizeResult, OptimizeTolerance,
+};
+
+pub struct NelderMead;
+
+impl NelderMead {
+    // Evaluates the function with NaN and infinite values mapped to +∞, so the simplex moves away from them.
+    fn evaluate(function: &Objective, x: &[f64]) -> f64 {
+        let value = function(x);
+        if value.is_finite() {
+            value
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    // The Nelder–Mead simplex method with the dimension dependent coefficients of Gao and Han, which keep
+    // it effective in higher dimensions: reflection 1, expansion 1 + 2/n, contraction 3/4 - 1/(2n) and
+    // shrinking 1 - 1/n. The initial simplex moves every nonzero component by 5% and every zero component
+    // by 0.00025.
+    pub fn minimize(
+        function: &Objective,
+        guess: &[f64],
+        tolerance: OptimizeTolerance,
+    ) -> Result<OptimizeResult, OptimizeError> {
+        let n = guess.len();
+        // The coefficients of Gao and Han reduce to the standard 1, 2, 1/2 and 1/2 in two dimensions, which
+        // are also used for a single variable where shrinking would collapse the simplex.
+        let dimension = n.max(2) as f64;
+        let reflection = 1.0;
+        let expansion = 1.0 + 2.0 / dimension;
+        let contraction = 0.75 - 0.5 / dimension;
+        let shrinking = 1.0 - 1.0 / dimension;
+
+        let first = function(guess);
+        if !first.is_finite() {
+            return Err(OptimizeError::NonFiniteValue { x: guess.to_vec() });
+        }
+
+        let mut simplex: Vec<(Vec<f64>, f64)> = vec![(guess.to_vec(), first)];
+        for i in 0..n {
+            let mut vertex = guess.to_vec();
+            vertex[i] = if vertex[i] == 0.0 {
+                0.00025
+            } else {
+                1.05 * vertex[i]
+            };
+            let value = Self::evaluate(function, &vertex);
+            simplex.push((vertex, value));
+        }
+
+        // The point c + coefficient (c - worst) on the line through the centroid and the worst vertex.
+        let along = |centroid: &[f64], worst: &[f64], coefficient: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(worst)
+                .map(|(c, w)| c + coefficient * (c - w))
+                .collect()
+        };
+
+        for iteration in 1..=tolerance.max_iterations {
+            simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            let (best, f_best) = (&simplex[0].0, simplex[0].1);
+            let spread = simplex[n].1 - f_best;
+            let size = simplex
+                .iter()
+                .map(|(vertex, _)| largest(&subtract(vertex, best)))
+                .fold(0.0, f64::max);
+
+            if spread <= tolerance.function * f_best.abs().max(1.0)
+                || size <= tolerance.step * largest(best).max(1.0)
+            {
+                let (argmin, minimum) = simplex.swap_remove(0);
+                return Ok(result(argmin, minimum, Vec::new(), iteration - 1, true));
+            }
+
+            let centroid: Vec<f64> = (0..n)
+                .map(|j| {
+                    simplex[..n]
+                        .iter()
+                        .map(|(vertex, _)| vertex[j])
+                        .sum::<f64>()
+                        / n as f64
+                })
+                .collect();
+            let worst = simplex[n].0.clone();
+            let f_worst = simplex[n].1;
+            let f_second_worst = simplex[n - 1].1;
+
+            let reflected = along(&centroid, &worst, reflection);
+            let f_reflected = Self::evaluate(function, &reflected);
+
+            let replacement = if f_reflected < f_best {
+                let expanded = along(&centroid, &worst, reflection * expansion);
+                let f_expanded = Self::evaluate(function, &expanded);
+
+                if f_expanded < f_reflected {
+                    Some((expanded, f_expanded))
+                } else {
+                    Some((reflected, f_reflected))
+                }
+            } else if f_reflected < f_second_worst {
+                Some((reflected, f_reflected))
+            } else if f_reflected < f_worst {
+                let outside = along(&centroid, &worst, reflection * contraction);
+                let f_outside = Self::evaluate(function, &outside);
+
+                (f_outside <= f_reflected).then_some((outside, f_outside))
+            } else {
+                let inside = along(&centroid, &worst, -contraction);
+                let f_inside = Self::evaluate(function, &inside);
+
+                (f_inside < f_worst).then_some((inside, f_inside))
+            };
+
+            match replacement {
+                Some(vertex) => simplex[n] = vertex,
+                None => {
+                    let best = simplex[0].0.clone();
+
+                    for (vertex, value) in simplex.iter_mut().skip(1) {
+                        *vertex = best
+                            .iter()
+                            .zip(vertex.iter())
+                            .map(|(b, v)| b + shrinking * (v - b))
+                            .collect();
+                        *value = Self::evaluate(function, vertex);
+                    }
+                }
+            }
+        }
+
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (argmin, minimum) = simplex.swap_remove(0);
+        Ok(result(
+            argmin,
+            minimum,
+            Vec::new(),
+            tolerance.max_iterations,
+            false,
+        ))
+    }
+}
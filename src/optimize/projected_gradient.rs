@@ -0,0 +1,127 @@
+use crate::optimize::result::{
+    dot, largest, result, start, subtract, Gradient, Objective, OptimizeError, OptimizeResult,
+    OptimizeTolerance,
+};
+use std::collections::VecDeque;
+
+pub struct ProjectedGradient;
+
+impl ProjectedGradient {
+    // The sufficient decrease parameter, the number of remembered function values of the nonmonotone line
+    // search and the safeguards of the spectral step.
+    const ARMIJO: f64 = 1e-4;
+    const MEMORY: usize = 10;
+    const MIN_STEP: f64 = 1e-30;
+    const MAX_STEP: f64 = 1e30;
+
+    fn project(x: &[f64], lower: &[f64], upper: &[f64]) -> Vec<f64> {
+        x.iter()
+            .zip(lower.iter().zip(upper))
+            .map(|(x, (lower, upper))| x.clamp(*lower, *upper))
+            .collect()
+    }
+
+    // P(x - scale ∇f) - x
+    fn projected_step(
+        x: &[f64],
+        gradient: &[f64],
+        scale: f64,
+        lower: &[f64],
+        upper: &[f64],
+    ) -> Vec<f64> {
+        let trial: Vec<f64> = x.iter().zip(gradient).map(|(x, g)| x - scale * g).collect();
+        subtract(&Self::project(&trial, lower, upper), x)
+    }
+
+    // The spectral projected gradient method of Birgin, Martínez and Raydan for lower <= x <= upper. Every
+    // iteration projects a steepest descent step scaled by the Barzilai–Borwein step s·s / s·y onto the box
+    // and backtracks along it until f is sufficiently below the largest of the last ten values. It stops
+    // when the projected gradient P(x - ∇f) - x vanishes, which is the first order condition on the box.
+    pub fn minimize(
+        function: &Objective,
+        gradient: &Gradient,
+        guess: &[f64],
+        lower: &[f64],
+        upper: &[f64],
+        tolerance: OptimizeTolerance,
+    ) -> Result<OptimizeResult, OptimizeError> {
+        let mut x = Self::project(guess, lower, upper);
+        let (mut value, mut slope) = start(function, gradient, &x)?;
+        let mut history = VecDeque::from([value]);
+
+        let first = largest(&Self::projected_step(&x, &slope, 1.0, lower, upper));
+        let mut spectral = (1.0 / first).clamp(Self::MIN_STEP, Self::MAX_STEP);
+
+        for iteration in 1..=tolerance.max_iterations {
+            let projected = Self::projected_step(&x, &slope, 1.0, lower, upper);
+            if tolerance.gradient_converged(&projected, value) {
+                return Ok(result(x, value, slope, iteration - 1, true));
+            }
+
+            let direction = Self::projected_step(&x, &slope, spectral, lower, upper);
+            let decrease = dot(&slope, &direction);
+            let reference = history
+                .iter()
+                .fold(f64::NEG_INFINITY, |a: f64, &b| a.max(b));
+
+            // Backtracks with safeguarded quadratic interpolation until the nonmonotone Armijo condition holds.
+            let mut alpha = 1.0;
+            let accepted = loop {
+                let trial: Vec<f64> = x
+                    .iter()
+                    .zip(&direction)
+                    .map(|(x, d)| x + alpha * d)
+                    .collect();
+                let f = function(&trial);
+
+                if f.is_finite() && f <= reference + Self::ARMIJO * alpha * decrease {
+                    break Some((trial, f));
+                }
+
+                let step: Vec<f64> = direction.iter().map(|d| alpha * d).collect();
+                if tolerance.step_converged(&step, &x) {
+                    break None;
+                }
+
+                let curvature = f - value - alpha * decrease;
+                let interpolated = -0.5 * alpha * alpha * decrease / curvature;
+                alpha = if f.is_finite() && (0.1 * alpha..=0.9 * alpha).contains(&interpolated) {
+                    interpolated
+                } else {
+                    0.5 * alpha
+                };
+            };
+
+            let Some((next, f)) = accepted else {
+                return Ok(result(x, value, slope, iteration, false));
+            };
+
+            let next_slope = gradient(&next);
+            let s = subtract(&next, &x);
+            let y = subtract(&next_slope, &slope);
+            let curvature = dot(&s, &y);
+            spectral = if curvature > 0.0 {
+                (dot(&s, &s) / curvature).clamp(Self::MIN_STEP, Self::MAX_STEP)
+            } else {
+                Self::MAX_STEP
+            };
+
+            x = next;
+            value = f;
+            slope = next_slope;
+
+            if history.len() == Self::MEMORY {
+                history.pop_front();
+            }
+            history.push_back(value);
+
+            if !slope.iter().all(|g| g.is_finite()) {
+                return Ok(result(x, value, slope, iteration, false));
+            }
+        }
+
+        let projected = Self::projected_step(&x, &slope, 1.0, lower, upper);
+        let converged = tolerance.gradient_converged(&projected, value);
+        Ok(result(x, value, slope, tolerance.max_iterations, converged))
+    }
+}
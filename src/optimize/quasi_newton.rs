@@ -0,0 +1,228 @@
+use crate::optimize::line_search::LineSearch;
+use crate::optimize::result::{
+    axpy, dot, largest, result, start, subtract, Gradient, Objective, OptimizeError,
+    OptimizeResult, OptimizeTolerance,
+};
+use std::collections::VecDeque;
+
+pub struct QuasiNewton;
+
+impl QuasiNewton {
+    // The curvature parameter of the Wolfe conditions for quasi-Newton directions.
+    const CURVATURE: f64 = 0.9;
+
+    // The first step of a steepest descent direction, which moves no component by more than one.
+    fn first_step(gradient: &[f64]) -> f64 {
+        1.0_f64.min(1.0 / largest(gradient))
+    }
+
+    // BFGS with the inverse Hessian approximation H, scaled by s·y / y·y before the first update and
+    // updated with H ← (I - ρsyᵀ) H (I - ρysᵀ) + ρssᵀ, ρ = 1 / y·s, whenever the curvature y·s is
+    // positive. H is reset to the identity when its direction fails.
+    pub fn bfgs(
+        function: &Objective,
+        gradient: &Gradient,
+        guess: &[f64],
+        tolerance: OptimizeTolerance,
+    ) -> Result<OptimizeResult, OptimizeError> {
+        let n = guess.len();
+        let (mut value, mut slope) = start(function, gradient, guess)?;
+        let mut x = guess.to_vec();
+
+        let identity = || -> Vec<Vec<f64>> {
+            (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect()
+        };
+        let mut inverse = identity();
+        let mut updated = false;
+
+        for iteration in 1..=tolerance.max_iterations {
+            if tolerance.gradient_converged(&slope, value) {
+                return Ok(result(x, value, slope, iteration - 1, true));
+            }
+
+            let mut direction: Vec<f64> = inverse.iter().map(|row| -dot(row, &slope)).collect();
+            let mut initial = 1.0;
+            if !updated || dot(&direction, &slope) >= 0.0 {
+                inverse = identity();
+                updated = false;
+                direction = slope.iter().map(|g| -g).collect();
+                initial = Self::first_step(&slope);
+            }
+
+            let mut point = LineSearch::wolfe(
+                function,
+                gradient,
+                &x,
+                value,
+                &slope,
+                &direction,
+                initial,
+                Self::CURVATURE,
+            );
+
+            if point.is_none() && updated {
+                inverse = identity();
+                updated = false;
+                direction = slope.iter().map(|g| -g).collect();
+                point = LineSearch::wolfe(
+                    function,
+                    gradient,
+                    &x,
+                    value,
+                    &slope,
+                    &direction,
+                    Self::first_step(&slope),
+                    Self::CURVATURE,
+                );
+            }
+
+            let Some(point) = point else {
+                return Ok(result(x, value, slope, iteration, false));
+            };
+
+            let s = subtract(&point.x, &x);
+            let y = subtract(&point.gradient, &slope);
+            let curvature = dot(&s, &y);
+
+            if curvature > 0.0 {
+                if !updated {
+                    let scale = curvature / dot(&y, &y);
+                    for (i, row) in inverse.iter_mut().enumerate() {
+                        for (j, entry) in row.iter_mut().enumerate() {
+                            *entry = if i == j { scale } else { 0.0 };
+                        }
+                    }
+                }
+
+                let rho = 1.0 / curvature;
+                let hy: Vec<f64> = inverse.iter().map(|row| dot(row, &y)).collect();
+                let yhy = dot(&y, &hy);
+
+                for i in 0..n {
+                    for j in 0..n {
+                        inverse[i][j] += -rho * (hy[i] * s[j] + s[i] * hy[j])
+                            + (rho * rho * yhy + rho) * s[i] * s[j];
+                    }
+                }
+                updated = true;
+            }
+
+            x = point.x;
+            value = point.value;
+            slope = point.gradient;
+
+            if tolerance.step_converged(&s, &x) {
+                let converged = tolerance.gradient_converged(&slope, value);
+                return Ok(result(x, value, slope, iteration, converged));
+            }
+        }
+
+        let converged = tolerance.gradient_converged(&slope, value);
+        Ok(result(x, value, slope, tolerance.max_iterations, converged))
+    }
+
+    // L-BFGS keeps the last `memory` pairs s = x_new - x and y = ∇f_new - ∇f and applies the inverse
+    // Hessian approximation with the two-loop recursion, starting from (s·y / y·y) I, so every iteration
+    // costs O(memory n) instead of O(n²).
+    pub fn lbfgs(
+        function: &Objective,
+        gradient: &Gradient,
+        guess: &[f64],
+        memory: usize,
+        tolerance: OptimizeTolerance,
+    ) -> Result<OptimizeResult, OptimizeError> {
+        let (mut value, mut slope) = start(function, gradient, guess)?;
+        let mut x = guess.to_vec();
+        let mut pairs: VecDeque<(Vec<f64>, Vec<f64>, f64)> = VecDeque::with_capacity(memory);
+
+        for iteration in 1..=tolerance.max_iterations {
+            if tolerance.gradient_converged(&slope, value) {
+                return Ok(result(x, value, slope, iteration - 1, true));
+            }
+
+            let mut direction = slope.clone();
+            let mut coefficients = Vec::with_capacity(pairs.len());
+
+            for (s, y, rho) in pairs.iter().rev() {
+                let coefficient = rho * dot(s, &direction);
+                direction = axpy(&direction, -coefficient, y);
+                coefficients.push(coefficient);
+            }
+
+            if let Some((s, y, _)) = pairs.back() {
+                let scale = dot(s, y) / dot(y, y);
+                direction.iter_mut().for_each(|d| *d *= scale);
+            }
+
+            for ((s, y, rho), coefficient) in pairs.iter().zip(coefficients.iter().rev()) {
+                let beta = rho * dot(y, &direction);
+                direction = axpy(&direction, coefficient - beta, s);
+            }
+
+            direction.iter_mut().for_each(|d| *d = -*d);
+
+            let mut initial = 1.0;
+            if pairs.is_empty() || dot(&direction, &slope) >= 0.0 {
+                pairs.clear();
+                direction = slope.iter().map(|g| -g).collect();
+                initial = Self::first_step(&slope);
+            }
+
+            let mut point = LineSearch::wolfe(
+                function,
+                gradient,
+                &x,
+                value,
+                &slope,
+                &direction,
+                initial,
+                Self::CURVATURE,
+            );
+
+            if point.is_none() && !pairs.is_empty() {
+                pairs.clear();
+                direction = slope.iter().map(|g| -g).collect();
+                point = LineSearch::wolfe(
+                    function,
+                    gradient,
+                    &x,
+                    value,
+                    &slope,
+                    &direction,
+                    Self::first_step(&slope),
+                    Self::CURVATURE,
+                );
+            }
+
+            let Some(point) = point else {
+                return Ok(result(x, value, slope, iteration, false));
+            };
+
+            let s = subtract(&point.x, &x);
+            let y = subtract(&point.gradient, &slope);
+            let curvature = dot(&s, &y);
+            let step_converged = tolerance.step_converged(&s, &point.x);
+
+            if curvature > 0.0 && memory > 0 {
+                if pairs.len() == memory {
+                    pairs.pop_front();
+                }
+                pairs.push_back((s, y, 1.0 / curvature));
+            }
+
+            x = point.x;
+            value = point.value;
+            slope = point.gradient;
+
+            if step_converged {
+                let converged = tolerance.gradient_converged(&slope, value);
+                return Ok(result(x, value, slope, iteration, converged));
+            }
+        }
+
+        let converged = tolerance.gradient_converged(&slope, value);
+        Ok(result(x, value, slope, tolerance.max_iterations, converged))
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+// The objective f(x) and its gradient.
+pub type Objective<'a> = dyn Fn(&[f64]) -> f64 + 'a;
+pub type Gradient<'a> = dyn Fn(&[f64]) -> Vec<f64> + 'a;
+
+/// The stopping criteria of the multivariate minimizers.
+///
+/// The gradient-based methods stop when the largest component of the gradient is at most
+/// `gradient * max(1, |f|)`, or when a step changes no component of `x` by more than
+/// `step * max(1, |x|)`. Nelder–Mead stops when the function values at the vertices of the simplex agree
+/// to `function * max(1, |f|)`, or when the simplex is smaller than the step tolerance. The `Default`
+/// tolerances are `1e-8`, `1e-12`, `1e-14` and 1000 iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimizeTolerance {
+    /// The tolerance on the gradient, relative to the magnitude of the function when it is larger than one.
+    pub gradient: f64,
+    /// The tolerance on the change of `x`, relative to its magnitude when it is larger than one.
+    pub step: f64,
+    /// The tolerance on the spread of the function values of the Nelder–Mead simplex.
+    pub function: f64,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for OptimizeTolerance {
+    fn default() -> Self {
+        Self {
+            gradient: 1e-8,
+            step: 1e-12,
+            function: 1e-14,
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl OptimizeTolerance {
+    pub(crate) fn gradient_converged(&self, gradient: &[f64], value: f64) -> bool {
+        largest(gradient) <= self.gradient * value.abs().max(1.0)
+    }
+
+    pub(crate) fn step_converged(&self, step: &[f64], x: &[f64]) -> bool {
+        largest(step) <= self.step * largest(x).max(1.0)
+    }
+}
+
+/// The result of a multivariate minimizer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizeResult {
+    /// The approximation of the minimizer.
+    pub argmin: Vec<f64>,
+    /// The value of the function at `argmin`.
+    pub minimum: f64,
+    /// The gradient at `argmin`, which is empty for Nelder–Mead.
+    pub gradient: Vec<f64>,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The number of times the function was evaluated, including the evaluations of finite difference
+    /// gradients.
+    pub evaluations: usize,
+    /// The number of times the gradient was evaluated.
+    pub gradient_evaluations: usize,
+    /// Whether the tolerance was reached within the maximum number of iterations.
+    pub converged: bool,
+}
+
+/// The reasons a multivariate minimizer, a curve fit or a linear program can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptimizeError {
+    /// The function or its gradient returned NaN or an infinite value at the starting point `x`.
+    NonFiniteValue { x: Vec<f64> },
+    /// A fit has no more data points than parameters.
+    InsufficientData { points: usize, parameters: usize },
+    /// The normal equations of a fit are singular at `parameters`, so the covariance does not exist.
+    SingularCovariance { parameters: Vec<f64> },
+    /// No point satisfies the constraints of a linear program.
+    Infeasible,
+    /// The objective of a linear program decreases without bound on the feasible set.
+    Unbounded,
+}
+
+impl fmt::Display for OptimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptimizeError::NonFiniteValue { x } => {
+                write!(f, "the function is not finite at x = {:?}", x)
+            }
+            OptimizeError::InsufficientData { points, parameters } => write!(
+                f,
+                "{} data points cannot determine {} parameters",
+                points, parameters
+            ),
+            OptimizeError::SingularCovariance { parameters } => write!(
+                f,
+                "the covariance is singular at the parameters {:?}",
+                parameters
+            ),
+            OptimizeError::Infeasible => write!(f, "the constraints cannot be satisfied"),
+            OptimizeError::Unbounded => write!(f, "the objective is unbounded below"),
+        }
+    }
+}
+
+impl std::error::Error for OptimizeError {}
+
+pub fn largest(values: &[f64]) -> f64 {
+    values
+        .iter()
+        .fold(0.0, |largest: f64, value| largest.max(value.abs()))
+}
+
+pub fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+// x + scale * direction
+pub fn axpy(x: &[f64], scale: f64, direction: &[f64]) -> Vec<f64> {
+    x.iter()
+        .zip(direction)
+        .map(|(x, d)| x + scale * d)
+        .collect()
+}
+
+pub fn subtract(a: &[f64], b: &[f64]) -> Vec<f64> {
+    a.iter().zip(b).map(|(a, b)| a - b).collect()
+}
+
+pub fn is_finite(values: &[f64]) -> bool {
+    values.iter().all(|value| value.is_finite())
+}
+
+// Evaluates the function and the gradient at the starting point.
+pub fn start(
+    function: &Objective,
+    gradient: &Gradient,
+    guess: &[f64],
+) -> Result<(f64, Vec<f64>), OptimizeError> {
+    let value = function(guess);
+    let slope = gradient(guess);
+
+    if value.is_finite() && is_finite(&slope) {
+        Ok((value, slope))
+    } else {
+        Err(OptimizeError::NonFiniteValue { x: guess.to_vec() })
+    }
+}
+
+pub fn result(
+    argmin: Vec<f64>,
+    minimum: f64,
+    gradient: Vec<f64>,
+    iterations: usize,
+    converged: bool,
+) -> OptimizeResult {
+    OptimizeResult {
+        argmin,
+        minimum,
+        gradient,
+        iterations,
+        evaluations: 0,
+        gradient_evaluations: 0,
+        converged,
+    }
+}
@@ -0,0 +1,3 @@
+mod quaternion_num;
+
+pub use quaternion_num::*;
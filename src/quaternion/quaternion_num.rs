@@ -0,0 +1,800 @@
+use crate::Matrix;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A module containing Quaternions and their operations.
+///
+/// Unit Quaternions represent rotations in 3D space. All angles are in radians and rotations follow
+/// the right-hand rule.
+///
+/// # Arithmetic:
+///
+/// Addition, subtraction, negation, scaling by an `f64` and the (non-commutative) Hamilton product.
+///
+/// # Example:
+///
+/// ```
+/// use numerilib::Quaternion;
+///
+/// let q1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+///
+/// let q2 = Quaternion::new(4.0, 3.0, 2.0, 1.0);
+///
+/// let quaternion_sum = q1 + q2;
+/// let quaternion_minus = q1 - q2;
+/// let hamilton_product = q1 * q2;
+/// let scaled = q1 * 2.0;
+///
+/// println!("{} + {} = {}", q1, q2, quaternion_sum);
+/// println!("{} - {} = {}", q1, q2, quaternion_minus);
+/// println!("{} * {} = {}", q1, q2, hamilton_product);
+/// println!("{} * 2 = {}", q1, scaled);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Quaternion {
+    /// The identity Quaternion `1 + 0i + 0j + 0k`, which represents no rotation.
+    pub const IDENTITY: Quaternion = Quaternion {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Create a new Quaternion given a scalar part and the three components of its vector part.
+    ///
+    /// # Format:
+    /// `w + xi + yj + zk`
+    ///
+    /// # Parameters:
+    ///
+    /// - `w`: the scalar part of the Quaternion
+    /// - `x`: the `i` component of the vector part
+    /// - `y`: the `j` component of the vector part
+    /// - `z`: the `k` component of the vector part
+    ///
+    /// # Returns:
+    ///
+    /// A new Quaternion with the given components.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Quaternion q: {}", q);
+    /// ```
+    /// * * *
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Returns the scalar part of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to get the scalar part of
+    ///
+    /// # Returns:
+    ///
+    /// The scalar part `w` of the Quaternion.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Scalar part of {}: {}", q, q.scalar_part());
+    /// ```
+    /// * * *
+    pub fn scalar_part(&self) -> f64 {
+        self.w
+    }
+
+    /// Returns the vector part of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to get the vector part of
+    ///
+    /// # Returns:
+    ///
+    /// The vector part `[x, y, z]` of the Quaternion.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Vector part of {}: {:?}", q, q.vector_part());
+    /// ```
+    /// * * *
+    pub fn vector_part(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Calculates the squared norm of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to calculate the squared norm of
+    ///
+    /// # Returns:
+    ///
+    /// The squared norm `w² + x² + y² + z²` of the Quaternion.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Squared norm of {}: {}", q, q.norm_sqr());
+    /// ```
+    /// * * *
+    pub fn norm_sqr(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Calculates the norm (magnitude) of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to calculate the norm of
+    ///
+    /// # Returns:
+    ///
+    /// The norm of the Quaternion.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Norm of {}: {}", q, q.norm());
+    /// ```
+    /// * * *
+    pub fn norm(&self) -> f64 {
+        self.w.hypot(self.vector_norm())
+    }
+
+    /// Calculates the dot product of two Quaternions treated as 4D vectors.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the first Quaternion
+    /// - `other`: the second Quaternion
+    ///
+    /// # Returns:
+    ///
+    /// The dot product `w₁w₂ + x₁x₂ + y₁y₂ + z₁z₂`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let q2 = Quaternion::new(4.0, 3.0, 2.0, 1.0);
+    ///
+    /// println!("{} · {} = {}", q1, q2, q1.dot(&q2));
+    /// ```
+    /// * * *
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the conjugate of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to conjugate
+    ///
+    /// # Returns:
+    ///
+    /// The Quaternion `w - xi - yj - zk`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Conjugate of {}: {}", q, q.conj());
+    /// ```
+    /// * * *
+    pub fn conj(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Calculates the multiplicative inverse of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to invert
+    ///
+    /// # Returns:
+    ///
+    /// The Quaternion `q⁻¹` such that `q * q⁻¹ = 1`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Inverse of {}: {}", q, q.inverse());
+    /// ```
+    /// * * *
+    pub fn inverse(&self) -> Quaternion {
+        self.conj() / self.norm_sqr()
+    }
+
+    /// Normalizes a Quaternion to unit length.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to normalize
+    ///
+    /// # Returns:
+    ///
+    /// A unit Quaternion pointing in the same direction.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// println!("Normalized {}: {}", q, q.normalize());
+    /// ```
+    /// * * *
+    pub fn normalize(&self) -> Quaternion {
+        *self / self.norm()
+    }
+
+    /// Calculates the exponential `e^q` of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to exponentiate
+    ///
+    /// # Returns:
+    ///
+    /// `e` raised to the power of the Quaternion.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, std::f64::consts::FRAC_PI_2, 0.0, 0.0);
+    ///
+    /// println!("e^({}) = {}", q, q.exp());
+    /// ```
+    /// * * *
+    pub fn exp(&self) -> Quaternion {
+        let scale = self.w.exp();
+        let theta = self.vector_norm();
+
+        // sin(θ)/θ, which tends to 1 as θ goes to 0
+        let sinc = if theta < 1e-8 {
+            1.0 - theta * theta / 6.0
+        } else {
+            theta.sin() / theta
+        };
+
+        Quaternion::new(
+            scale * theta.cos(),
+            scale * sinc * self.x,
+            scale * sinc * self.y,
+            scale * sinc * self.z,
+        )
+    }
+
+    /// Calculates the principal natural logarithm of a Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the Quaternion to calculate the logarithm of
+    ///
+    /// # Returns:
+    ///
+    /// The logarithm of the Quaternion, whose vector part has a norm in the range `[0, π]`.
+    /// For negative real Quaternions the rotation axis is ambiguous and the `i` axis is used.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    ///
+    /// println!("ln({}) = {}", q, q.ln());
+    /// ```
+    /// * * *
+    pub fn ln(&self) -> Quaternion {
+        let norm = self.norm();
+        let vector_norm = self.vector_norm();
+        let theta = vector_norm.atan2(self.w);
+
+        if vector_norm == 0.0 {
+            return if self.w < 0.0 {
+                Quaternion::new(norm.ln(), std::f64::consts::PI, 0.0, 0.0)
+            } else {
+                Quaternion::new(norm.ln(), 0.0, 0.0, 0.0)
+            };
+        }
+
+        let scale = theta / vector_norm;
+
+        Quaternion::new(norm.ln(), scale * self.x, scale * self.y, scale * self.z)
+    }
+
+    /// Calculates the spherical linear interpolation (slerp) between two unit Quaternions.
+    ///
+    /// The shortest arc is always taken, so `q` and `-q` (which represent the same rotation) interpolate identically.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the starting unit Quaternion (returned when `t = 0`)
+    /// - `other`: the ending unit Quaternion (returned when `t = 1`)
+    /// - `t`: the interpolation parameter
+    ///
+    /// # Returns:
+    ///
+    /// The unit Quaternion a fraction `t` of the way along the arc from `self` to `other`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let start = Quaternion::IDENTITY;
+    /// let end = Quaternion::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2);
+    ///
+    /// let halfway = start.slerp(&end, 0.5);
+    ///
+    /// println!("Halfway between {} and {}: {}", start, end, halfway);
+    /// ```
+    /// * * *
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut cos_theta = self.dot(other);
+        let mut end = *other;
+
+        if cos_theta < 0.0 {
+            cos_theta = -cos_theta;
+            end = -end;
+        }
+
+        // Nearly parallel Quaternions fall back to a normalized linear interpolation
+        if cos_theta > 1.0 - 1e-10 {
+            return (*self * (1.0 - t) + end * t).normalize();
+        }
+
+        let theta = cos_theta.min(1.0).acos();
+        let sin_theta = theta.sin();
+
+        *self * (((1.0 - t) * theta).sin() / sin_theta) + end * ((t * theta).sin() / sin_theta)
+    }
+
+    /// Creates a unit Quaternion representing a rotation about an axis.
+    ///
+    /// # Parameters:
+    ///
+    /// - `axis`: the axis of rotation `[x, y, z]` (it does not need to be normalized)
+    /// - `angle`: the angle of rotation in radians
+    ///
+    /// # Returns:
+    ///
+    /// The unit Quaternion representing the rotation, or the identity if the axis has zero length.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2);
+    ///
+    /// println!("90° about the z axis: {}", q);
+    /// ```
+    /// * * *
+    pub fn from_axis_angle(axis: [f64; 3], angle: f64) -> Quaternion {
+        let length = axis[0].hypot(axis[1]).hypot(axis[2]);
+
+        if length == 0.0 {
+            return Quaternion::IDENTITY;
+        }
+
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let scale = sin / length;
+
+        Quaternion::new(cos, scale * axis[0], scale * axis[1], scale * axis[2])
+    }
+
+    /// Converts a unit Quaternion into an axis of rotation and an angle.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the unit Quaternion to convert
+    ///
+    /// # Returns:
+    ///
+    /// A tuple `(axis, angle)` where `axis` is a unit vector and `angle` is in the range `[0, π]`.
+    /// The `x` axis is returned for the identity rotation.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_axis_angle([0.0, 2.0, 0.0], 1.0);
+    ///
+    /// let (axis, angle) = q.to_axis_angle();
+    ///
+    /// println!("Axis: {:?}, Angle: {}", axis, angle);
+    /// ```
+    /// * * *
+    pub fn to_axis_angle(&self) -> ([f64; 3], f64) {
+        let q = if self.w < 0.0 { -*self } else { *self };
+        let vector_norm = q.vector_norm();
+
+        if vector_norm == 0.0 {
+            return ([1.0, 0.0, 0.0], 0.0);
+        }
+
+        let angle = 2.0 * vector_norm.atan2(q.w);
+
+        (
+            [q.x / vector_norm, q.y / vector_norm, q.z / vector_norm],
+            angle,
+        )
+    }
+
+    /// Creates a unit Quaternion from Euler angles.
+    ///
+    /// The angles follow the aerospace (Tait-Bryan, intrinsic Z-Y'-X'') convention: the rotation is a yaw about `z`,
+    /// followed by a pitch about the new `y` axis and a roll about the new `x` axis.
+    ///
+    /// # Parameters:
+    ///
+    /// - `roll`: the rotation about the `x` axis in radians
+    /// - `pitch`: the rotation about the `y` axis in radians
+    /// - `yaw`: the rotation about the `z` axis in radians
+    ///
+    /// # Returns:
+    ///
+    /// The unit Quaternion representing the rotation.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+    ///
+    /// println!("Quaternion: {}", q);
+    /// ```
+    /// * * *
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Quaternion {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+
+        Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+
+    /// Converts a unit Quaternion into Euler angles.
+    ///
+    /// Uses the same convention as [`Quaternion::from_euler`]. At gimbal lock (`pitch = ±π/2`) the roll is set to `0`.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the unit Quaternion to convert
+    ///
+    /// # Returns:
+    ///
+    /// A tuple `(roll, pitch, yaw)` in radians, with `pitch` in the range `[-π/2, π/2]`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+    ///
+    /// let (roll, pitch, yaw) = q.to_euler();
+    ///
+    /// println!("Roll: {}, Pitch: {}, Yaw: {}", roll, pitch, yaw);
+    /// ```
+    /// * * *
+    pub fn to_euler(&self) -> (f64, f64, f64) {
+        let q = self.normalize();
+        let sin_pitch = 2.0 * (q.w * q.y - q.z * q.x);
+
+        if sin_pitch.abs() >= 1.0 - 1e-12 {
+            let pitch = std::f64::consts::FRAC_PI_2.copysign(sin_pitch);
+            let yaw = -2.0 * sin_pitch.signum() * q.x.atan2(q.w);
+            return (0.0, pitch, yaw);
+        }
+
+        let roll = (2.0 * (q.w * q.x + q.y * q.z)).atan2(1.0 - 2.0 * (q.x * q.x + q.y * q.y));
+        let pitch = sin_pitch.asin();
+        let yaw = (2.0 * (q.w * q.z + q.x * q.y)).atan2(1.0 - 2.0 * (q.y * q.y + q.z * q.z));
+
+        (roll, pitch, yaw)
+    }
+
+    /// Creates a unit Quaternion from a 3x3 rotation Matrix.
+    ///
+    /// Uses Shepperd's method, which picks the largest diagonal term to stay numerically stable.
+    ///
+    /// # Parameters:
+    ///
+    /// - `matrix`: an orthonormal rotation Matrix
+    ///
+    /// # Returns:
+    ///
+    /// The unit Quaternion representing the same rotation.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::{Matrix, Quaternion};
+    ///
+    /// let matrix = Matrix::new([
+    ///     [0_f64, -1_f64, 0_f64],
+    ///     [1_f64, 0_f64, 0_f64],
+    ///     [0_f64, 0_f64, 1_f64]
+    /// ]);
+    ///
+    /// let q = Quaternion::from_rotation_matrix(&matrix);
+    ///
+    /// println!("Quaternion: {}", q);
+    /// ```
+    /// * * *
+    pub fn from_rotation_matrix(matrix: &Matrix<3, 3>) -> Quaternion {
+        let m = |i: usize, j: usize| matrix[i][j];
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        let q = if trace > 0.0 {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Quaternion::new(
+                s / 4.0,
+                (m(2, 1) - m(1, 2)) / s,
+                (m(0, 2) - m(2, 0)) / s,
+                (m(1, 0) - m(0, 1)) / s,
+            )
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = 2.0 * (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt();
+            Quaternion::new(
+                (m(2, 1) - m(1, 2)) / s,
+                s / 4.0,
+                (m(0, 1) + m(1, 0)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+            )
+        } else if m(1, 1) > m(2, 2) {
+            let s = 2.0 * (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt();
+            Quaternion::new(
+                (m(0, 2) - m(2, 0)) / s,
+                (m(0, 1) + m(1, 0)) / s,
+                s / 4.0,
+                (m(1, 2) + m(2, 1)) / s,
+            )
+        } else {
+            let s = 2.0 * (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt();
+            Quaternion::new(
+                (m(1, 0) - m(0, 1)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+                (m(1, 2) + m(2, 1)) / s,
+                s / 4.0,
+            )
+        };
+
+        q.normalize()
+    }
+
+    /// Converts a unit Quaternion into a 3x3 rotation Matrix.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the unit Quaternion to convert
+    ///
+    /// # Returns:
+    ///
+    /// The rotation Matrix `R` such that `R * v` rotates the column vector `v`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2);
+    ///
+    /// let matrix = q.to_rotation_matrix();
+    ///
+    /// println!("Rotation Matrix:\n{:?}", matrix);
+    /// ```
+    /// * * *
+    pub fn to_rotation_matrix(&self) -> Matrix<3, 3> {
+        let q = self.normalize();
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+
+        Matrix::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// Rotates a 3D vector by a unit Quaternion.
+    ///
+    /// # Parameters:
+    ///
+    /// - `&self`: the unit Quaternion representing the rotation
+    /// - `vector`: the vector `[x, y, z]` to rotate
+    ///
+    /// # Returns:
+    ///
+    /// The rotated vector `q v q⁻¹`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use numerilib::Quaternion;
+    ///
+    /// let q = Quaternion::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2);
+    ///
+    /// let rotated = q.rotate_vector([1.0, 0.0, 0.0]);
+    ///
+    /// println!("Rotated Vector: {:?}", rotated);
+    /// ```
+    /// * * *
+    pub fn rotate_vector(&self, vector: [f64; 3]) -> [f64; 3] {
+        let q = self.normalize();
+        let v = Quaternion::new(0.0, vector[0], vector[1], vector[2]);
+
+        (q * v * q.conj()).vector_part()
+    }
+
+    fn vector_norm(&self) -> f64 {
+        self.x.hypot(self.y).hypot(self.z)
+    }
+}
+
+impl std::fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let sign = |value: f64| if value >= 0.0 { '+' } else { '-' };
+        write!(
+            f,
+            "{}{}{}i{}{}j{}{}k",
+            self.w,
+            sign(self.x),
+            self.x.abs(),
+            sign(self.y),
+            self.y.abs(),
+            sign(self.z),
+            self.z.abs()
+        )
+    }
+}
+
+// Implement addition for Quaternions
+impl Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, other: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+// Implement subtraction for Quaternions
+impl Sub<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, other: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w - other.w,
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+        )
+    }
+}
+
+// Implement the Hamilton product for Quaternions
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+}
+
+// Implement scaling for Quaternions
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: f64) -> Quaternion {
+        Quaternion::new(
+            self.w * other,
+            self.x * other,
+            self.y * other,
+            self.z * other,
+        )
+    }
+}
+
+impl Mul<Quaternion> for f64 {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        other * self
+    }
+}
+
+impl Div<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, other: f64) -> Quaternion {
+        Quaternion::new(
+            self.w / other,
+            self.x / other,
+            self.y / other,
+            self.z / other,
+        )
+    }
+}
+
+// Implement negation for Quaternions
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
@@ -1,6 +1,5 @@
-use crate::extra::Extra;
 use crate::special::Gamma;
-use crate::Functions;
+use crate::{Functions, RootTolerance};
 
 /// A module containing functions to work with the Beta Functions.
 pub struct Beta;
@@ -150,7 +149,7 @@ impl Beta {
     ///
     /// # Returns
     ///
-    /// An approximation of the inverse of the regularized incomplete Beta function.
+    /// An approximation of the inverse of the regularized incomplete Beta function, found with Brent's method on `[0, 1]`.
     ///
     /// # Example
     ///
@@ -170,25 +169,23 @@ impl Beta {
             return f64::NAN;
         }
 
-        let target_prob = x;
-        let func = |t: f64| Beta::regincbeta(z1, z2, t) - target_prob;
-
-        let mut guess = 0.5;
-
-        if x - 1e-1 <= 1e-6 {
-            let mut low = 0.0;
-            let mut high = 1.0;
-            while high - low > Extra::EPSILON2 {
-                if func(guess) < 0.0 {
-                    low = guess;
-                } else {
-                    high = guess;
-                }
-
-                guess = (low + high) / 2.0;
+        // The series of `regincbeta` converges slowly near t = 1, so I_t(z1, z2) = 1 - I_(1-t)(z2, z1) is used above 1/2.
+        let func = |t: f64| {
+            if t <= 0.5 {
+                Beta::regincbeta(z1, z2, t) - x
+            } else {
+                1_f64 - Beta::regincbeta(z2, z1, 1_f64 - t) - x
             }
-        }
+        };
+
+        let tolerance = RootTolerance {
+            absolute: f64::MIN_POSITIVE,
+            max_iterations: 200,
+            ..RootTolerance::default()
+        };
 
-        Functions::newmet(guess, func)
+        Functions::brent(func, 0_f64, 1_f64, tolerance)
+            .map(|result| result.root)
+            .unwrap_or(f64::NAN)
     }
 }
@@ -1,7 +1,54 @@
 use crate::special::Probability;
-use crate::Functions;
+use crate::{Complex, Dual, Functions, RootTolerance};
 use std::f64::consts::FRAC_2_SQRT_PI;
 
+// Scale parameter sqrt(N / sqrt(2)) of Weideman's rational approximation with N = 40 terms
+const WEIDEMAN_L: f64 = 5.3182958969449885;
+
+// Coefficients a_1 ... a_40 of Weideman's rational approximation of the Faddeeva function
+const WEIDEMAN_N40: [f64; 40] = [
+    2.8996245093897053,
+    2.61605415276186,
+    2.201513794878312,
+    1.7253830848179779,
+    1.2563815675765133,
+    0.8472174576593818,
+    0.5266528988277086,
+    0.29989437996150065,
+    0.15504263802479495,
+    0.07182361779074337,
+    0.029202916471241867,
+    0.010048186242783424,
+    0.0027054056330737914,
+    0.0004398070159869668,
+    -3.939363145489569e-05,
+    -5.591309264248318e-05,
+    -1.8007447144750956e-05,
+    -1.0660138984947143e-06,
+    1.483566113220078e-06,
+    5.912136951899494e-07,
+    1.4198642399935674e-08,
+    -6.35177348504429e-08,
+    -1.8315616783040462e-08,
+    3.2497465180436973e-09,
+    3.0177805400090707e-09,
+    2.1086006347066517e-10,
+    -3.5632339865976533e-10,
+    -9.055124450928292e-11,
+    3.47272670930455e-11,
+    1.7714495214011192e-11,
+    -2.7276023158200452e-12,
+    -2.907688342182867e-12,
+    1.2031458219387989e-13,
+    4.5329666782606727e-13,
+    1.37256205867155e-14,
+    -7.074086260286855e-14,
+    -5.409310282882142e-15,
+    1.1357687198999241e-14,
+    1.128073562364402e-15,
+    -1.899694947394927e-15,
+];
+
 /// Provides methods for calculating error functions and their inverses.
 pub struct Error;
 
@@ -69,6 +116,198 @@ impl Error {
         1_f64 - Error::erf(z)
     }
 
+    /// Calculates the Error Function (erf) for a Dual argument.
+    ///
+    /// The value is given by [`Error::erf`] and the derivative by erf'(z) = 2/√π e^(-z^2).
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Dual value at which to calculate the Error Function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Error Function and its derivative at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Error;
+    /// use numerilib::Dual;
+    ///
+    /// let z = Dual::variable(0.5);
+    /// let erf = Error::dual_erf(z);
+    ///
+    /// println!("erf'(0.5) is: {}", erf.dual_part());
+    /// ```
+    /// <hr/>
+    pub fn dual_erf(z: Dual) -> Dual {
+        let x = z.real_part();
+        let derivative = FRAC_2_SQRT_PI * (-x * x).exp();
+
+        Dual::new(Error::erf(x), derivative * z.dual_part())
+    }
+
+    /// Calculates the Complementary Error Function (erfc) for a Dual argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Dual value at which to calculate the Complementary Error Function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Complementary Error Function and its derivative at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Error;
+    /// use numerilib::Dual;
+    ///
+    /// let z = Dual::variable(0.5);
+    /// let erfc = Error::dual_erfc(z);
+    ///
+    /// println!("erfc'(0.5) is: {}", erfc.dual_part());
+    /// ```
+    /// <hr/>
+    pub fn dual_erfc(z: Dual) -> Dual {
+        1_f64 - Error::dual_erf(z)
+    }
+
+    /// Calculates the Faddeeva function w(z) for a Complex argument.
+    ///
+    /// The Faddeeva function is defined as w(z) = exp(-z^2) * erfc(-iz). On the upper half plane it is
+    /// evaluated with Weideman's rational approximation (40 terms), and the lower half plane uses
+    /// the reflection w(z) = 2 exp(-z^2) - w(-z).
+    ///
+    /// The relative error is below `1e-15` on the upper half plane and around `1e-13` on the lower half
+    /// plane, where the result grows like exp(-z^2) and overflows once `Im(z)^2 - Re(z)^2` exceeds roughly 709.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Complex value at which to calculate the Faddeeva function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Faddeeva function at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Error;
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 1.0);
+    /// let faddeeva = Error::faddeeva(z);
+    ///
+    /// println!("Faddeeva Function at {} is: {}", z, faddeeva);
+    /// ```
+    /// <hr/>
+    pub fn faddeeva(z: Complex) -> Complex {
+        if z.imag_part() < 0.0 {
+            return 2.0 * (-z * z).exp() - Self::faddeeva(-z);
+        }
+
+        let denominator = WEIDEMAN_L - Complex::I * z;
+        let ratio = (WEIDEMAN_L + Complex::I * z) / denominator;
+
+        let polynomial = WEIDEMAN_N40
+            .iter()
+            .rev()
+            .fold(Complex::ZERO, |acc, &coefficient| acc * ratio + coefficient);
+
+        2.0 * polynomial / (denominator * denominator) + (FRAC_2_SQRT_PI / 2.0) / denominator
+    }
+
+    /// Calculates the Error Function (erf) for a Complex argument.
+    ///
+    /// A Maclaurin Series is used when `|z| < 0.5`, everywhere else the Error Function is computed
+    /// from the Faddeeva function as erf(z) = 1 - exp(-z^2) * w(iz).
+    ///
+    /// The relative error is around `1e-15` near the real axis and around `1e-13` where |Im(z)| > |Re(z)|,
+    /// apart from components that cancel to zero (ie: the real part along the imaginary axis) which
+    /// carry an absolute error around `1e-16`.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Complex value at which to calculate the Error Function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Error Function at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Error;
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 1.0);
+    /// let erf = Error::complex_erf(z);
+    ///
+    /// println!("Error Function at {} is: {}", z, erf);
+    /// ```
+    /// <hr/>
+    pub fn complex_erf(z: Complex) -> Complex {
+        if z.magnitude() < 0.5 {
+            let z_squared = z * z;
+            let mut power = z;
+            let mut sum = z;
+            let mut n = 0_f64;
+
+            while n < 50.0 {
+                n += 1.0;
+                power *= -z_squared / n;
+                let term = power / (2.0 * n + 1.0);
+                sum += term;
+
+                if term.magnitude() <= f64::EPSILON * sum.magnitude() {
+                    break;
+                }
+            }
+
+            return FRAC_2_SQRT_PI * sum;
+        }
+
+        if z.real_part() >= 0.0 {
+            1.0 - Self::complex_erfc(z)
+        } else {
+            Self::complex_erfc(-z) - 1.0
+        }
+    }
+
+    /// Calculates the Complementary Error Function (erfc) for a Complex argument.
+    ///
+    /// The Complementary Error Function is computed from the Faddeeva function as
+    /// erfc(z) = exp(-z^2) * w(iz), which keeps full relative accuracy for large `Re(z)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Complex value at which to calculate the Complementary Error Function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Complementary Error Function at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Error;
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 1.0);
+    /// let erfc = Error::complex_erfc(z);
+    ///
+    /// println!("Complementary Error Function at {} is: {}", z, erfc);
+    /// ```
+    /// <hr/>
+    pub fn complex_erfc(z: Complex) -> Complex {
+        if z.real_part() >= 0.0 {
+            (-z * z).exp() * Self::faddeeva(Complex::I * z)
+        } else {
+            2.0 - Self::complex_erfc(-z)
+        }
+    }
+
     /// Calculates the Inverse Error Function (inverf).
     ///
     /// The Inverse Error Function, inverf(x), returns the value `z` such that `erf(z) = x`.
@@ -79,7 +318,7 @@ impl Error {
     ///
     /// # Returns
     ///
-    /// The value `z` such that `erf(z) = x`.
+    /// The value `z` such that `erf(z) = x`, found with Brent's method. At `x = ±1` the result is `±∞`, and outside `[-1, 1]` it is `NaN`.
     ///
     /// # Example
     ///
@@ -93,12 +332,25 @@ impl Error {
     /// ```
     /// <hr/>
     pub fn inverf(x: f64) -> f64 {
-        let target = x;
+        if x.abs() == 1_f64 {
+            return f64::INFINITY.copysign(x);
+        }
+        if x.abs() > 1_f64 {
+            return f64::NAN;
+        }
 
-        let func = |t: f64| Self::erf(t) - target;
+        // The series of `erf` loses accuracy beyond |t| = 4, so the Faddeeva based `complex_erf` is inverted instead.
+        let func = |t: f64| Self::complex_erf(Complex::new(t, 0_f64)).real_part() - x;
 
-        let guess = 0_f64;
+        let tolerance = RootTolerance {
+            absolute: f64::MIN_POSITIVE,
+            max_iterations: 200,
+            ..RootTolerance::default()
+        };
 
-        Functions::newmet(guess, func)
+        // erf(±6) is ±1 to double precision, so the bracket contains every root.
+        Functions::brent(func, -6_f64, 6_f64, tolerance)
+            .map(|result| result.root)
+            .unwrap_or(f64::NAN)
     }
 }
@@ -1,5 +1,5 @@
 use crate::special::Probability;
-use crate::Functions;
+use crate::{Complex, Dual, Functions, HyperDual};
 
 const G: f64 = 5f64;
 
@@ -13,6 +13,18 @@ const LG5N7: [f64; 7] = [
     -0.00000539702438713199,
 ];
 
+// B_2k / (2k(2k - 1)) for the Stirling series of ln Γ(z)
+const STIRLING_LN: [f64; 8] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+    -3617.0 / 122400.0,
+];
+
 /// Provides methods for calculating the Gamma function and related functions.
 pub struct Gamma;
 
@@ -107,6 +119,249 @@ impl Gamma {
         Self::lanczosln(z).exp()
     }
 
+    /// Calculates the natural logarithm of the Gamma function using Lanczos approximation for a Dual argument.
+    ///
+    /// The Lanczos approximation is evaluated with Dual arithmetic, so the dual part of the result is
+    /// the exact derivative of [`Gamma::lanczosln`] (the digamma function to the accuracy of the approximation).
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Dual value for which to calculate the logarithm of the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The natural logarithm of the Gamma function and its derivative at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::Dual;
+    ///
+    /// let z = Dual::variable(6.0);
+    /// let lanczos_ln = Gamma::dual_lanczosln(z);
+    ///
+    /// println!("d/dz ln Γ(z) at z = 6 is: {}", lanczos_ln.dual_part());
+    /// ```
+    /// <hr/>
+    pub fn dual_lanczosln(z: Dual) -> Dual {
+        let z = z - 1f64;
+        let base = z + G + 0.5;
+
+        let s = LG5N7
+            .iter()
+            .skip(1)
+            .enumerate()
+            .fold(Dual::constant(LG5N7[0]), |acc, (i, &val)| {
+                acc + val / (z + (i + 1) as f64)
+            });
+
+        (std::f64::consts::TAU).sqrt().ln() + s.ln() - base + base.ln() * (z + 0.5)
+    }
+
+    /// Calculates the Gamma function using Lanczos approximation for a Dual argument.
+    ///
+    /// The dual part of the result is the exact derivative of [`Gamma::lanczos`].
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Dual value for which to calculate the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Gamma function and its derivative at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::Dual;
+    ///
+    /// let z = Dual::variable(6.0);
+    /// let lanczos = Gamma::dual_lanczos(z);
+    ///
+    /// println!("Γ(6) is: {} and Γ'(6) is: {}", lanczos.real_part(), lanczos.dual_part());
+    /// ```
+    /// <hr/>
+    pub fn dual_lanczos(z: Dual) -> Dual {
+        Self::dual_lanczosln(z).exp()
+    }
+
+    /// Calculates the natural logarithm of the Gamma function using Lanczos approximation for a Hyper-Dual argument.
+    ///
+    /// The `ε₁ε₂` part of the result is the exact second derivative of [`Gamma::lanczosln`]
+    /// (the trigamma function to the accuracy of the approximation).
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Hyper-Dual value for which to calculate the logarithm of the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The natural logarithm of the Gamma function and its first and second derivatives at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::HyperDual;
+    ///
+    /// let z = HyperDual::variable(6.0);
+    /// let lanczos_ln = Gamma::hyper_dual_lanczosln(z);
+    ///
+    /// println!("d²/dz² ln Γ(z) at z = 6 is: {}", lanczos_ln.mixed_part());
+    /// ```
+    /// <hr/>
+    pub fn hyper_dual_lanczosln(z: HyperDual) -> HyperDual {
+        let z = z - 1f64;
+        let base = z + G + 0.5;
+
+        let s = LG5N7
+            .iter()
+            .skip(1)
+            .enumerate()
+            .fold(HyperDual::constant(LG5N7[0]), |acc, (i, &val)| {
+                acc + val / (z + (i + 1) as f64)
+            });
+
+        (std::f64::consts::TAU).sqrt().ln() + s.ln() - base + base.ln() * (z + 0.5)
+    }
+
+    /// Calculates the Gamma function using Lanczos approximation for a Hyper-Dual argument.
+    ///
+    /// The `ε₁` and `ε₁ε₂` parts of the result are the exact first and second derivatives of [`Gamma::lanczos`].
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Hyper-Dual value for which to calculate the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Gamma function and its first and second derivatives at the given `z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::HyperDual;
+    ///
+    /// let z = HyperDual::variable(6.0);
+    /// let lanczos = Gamma::hyper_dual_lanczos(z);
+    ///
+    /// println!("Γ''(6) is: {}", lanczos.mixed_part());
+    /// ```
+    /// <hr/>
+    pub fn hyper_dual_lanczos(z: HyperDual) -> HyperDual {
+        Self::hyper_dual_lanczosln(z).exp()
+    }
+
+    /// Calculates the natural logarithm of the Gamma function for a Complex argument.
+    ///
+    /// The principal branch of ln Γ(z) is returned: it is analytic everywhere except along the
+    /// non-positive real axis and, unlike `ln(Γ(z))`, its imaginary part is not wrapped into `(-π, π]`.
+    /// The Stirling series is used once `|z| >= 10`, the recurrence ln Γ(z) = ln Γ(z + 1) - ln(z)
+    /// shifts smaller arguments, and the reflection formula handles `Re(z) < 0.5`.
+    ///
+    /// The relative error is around `1e-14` across the complex plane, growing near the poles at the non-positive integers.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Complex value for which to calculate the logarithm of the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The natural logarithm of the Gamma function at the given `z`, or infinity at the poles.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    /// let lngamma = Gamma::complex_lngamma(z);
+    ///
+    /// println!("ln Γ({}) is: {}", z, lngamma);
+    /// ```
+    /// <hr/>
+    pub fn complex_lngamma(z: Complex) -> Complex {
+        let (x, y) = (z.real_part(), z.imag_part());
+
+        if y == 0.0 && x <= 0.0 && x.fract() == 0.0 {
+            return Complex::new(f64::INFINITY, 0.0);
+        }
+
+        if y < 0.0 {
+            return Self::complex_lngamma(z.conj()).conj();
+        }
+
+        if x < 0.5 {
+            // ln sin(πz) on the upper half plane, written so that no branch cut is crossed
+            let w = std::f64::consts::PI * z;
+            let lnsin = -Complex::I * w
+                + Complex::new(-std::f64::consts::LN_2, std::f64::consts::FRAC_PI_2)
+                + (-(2.0 * Complex::I * w).exp_m1()).ln();
+
+            return std::f64::consts::PI.ln() - lnsin - Self::complex_lngamma(1.0 - z);
+        }
+
+        let mut shifted = z;
+        let mut shift = Complex::ZERO;
+
+        while shifted.magnitude() < 10.0 {
+            shift += shifted.ln();
+            shifted += 1.0;
+        }
+
+        let inverse = shifted.recip();
+        let inverse_squared = inverse * inverse;
+        let mut power = inverse;
+        let mut series = Complex::ZERO;
+
+        for coefficient in STIRLING_LN {
+            series += coefficient * power;
+            power *= inverse_squared;
+        }
+
+        (shifted - 0.5) * shifted.ln() - shifted + (std::f64::consts::TAU).sqrt().ln() + series
+            - shift
+    }
+
+    /// Calculates the Gamma function for a Complex argument.
+    ///
+    /// The value is computed as the exponential of [`Gamma::complex_lngamma`], so the relative
+    /// error is around `1e-14` multiplied by `|ln Γ(z)|`.
+    ///
+    /// # Parameters
+    ///
+    /// - `z`: The Complex value for which to calculate the Gamma function.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Gamma function at the given `z`, or infinity at the poles.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use numerilib::special::Gamma;
+    /// use numerilib::Complex;
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    /// let gamma = Gamma::complex_gamma(z);
+    ///
+    /// println!("Γ({}) is: {}", z, gamma);
+    /// ```
+    /// <hr/>
+    pub fn complex_gamma(z: Complex) -> Complex {
+        let (x, y) = (z.real_part(), z.imag_part());
+
+        if y == 0.0 && x <= 0.0 && x.fract() == 0.0 {
+            return Complex::new(f64::INFINITY, 0.0);
+        }
+
+        Self::complex_lngamma(z).exp()
+    }
+
     /// Calculates the incomplete gamma function.
     ///
     /// The incomplete gamma function represents the integral of the Gamma probability density function
@@ -159,7 +159,7 @@ impl Gaussian {
         let tail_val: f64 = if tail_str.eq_ignore_ascii_case("Right") {
             function + mean.into()
         } else if tail_str.eq_ignore_ascii_case("Left") {
-            (-1_f64 * function) + mean.into()
+            -function + mean.into()
         } else {
             return Err("Tail side Invalid, the only options are:\nRight\nleft".to_string());
         };
@@ -250,6 +250,7 @@ impl Generic {
     /// println!("Five-Number Summary: {:?}", summary);
     /// ```
     /// <hr/>
+    #[allow(clippy::manual_is_multiple_of)]
     pub fn five_number_summary(list: &[f64]) -> Vec<f64> {
         let mut sorted_list = list.to_vec();
         sorted_list.sort_by(|a, b| a.partial_cmp(b).unwrap());
@@ -20,6 +20,115 @@ mod tests {
         assert_approx_eq!(5_f64.sqrt(), z.magnitude());
     }
 
+    #[test]
+    fn test_magnitude_large_components() {
+        let z = Complex::new(3e200, 4e200);
+
+        assert_approx_eq!(5.0, z.magnitude() / 1e200);
+    }
+
+    #[test]
+    fn test_norm_sqr() {
+        let z = Complex::new(3.0, 4.0);
+
+        assert_approx_eq!(25.0, z.norm_sqr());
+    }
+
+    #[test]
+    fn test_arg() {
+        let z = Complex::new(-1.0, 1.0);
+
+        assert_approx_eq!(3.0 * std::f64::consts::FRAC_PI_4, z.arg());
+    }
+
+    #[test]
+    fn test_conj() {
+        let z = Complex::new(1.0, 2.0).conj();
+
+        assert_approx_eq!(1.0, z.real_part());
+        assert_approx_eq!(-2.0, z.imag_part());
+    }
+
+    #[test]
+    fn test_recip() {
+        let z = Complex::new(3.0, 4.0).recip();
+
+        assert_approx_eq!(0.12, z.real_part());
+        assert_approx_eq!(-0.16, z.imag_part());
+    }
+
+    #[test]
+    fn test_polar() {
+        let z = Complex::new(-2.0, 2.0);
+
+        let (magnitude, angle) = z.to_polar();
+        let w = Complex::from_polar(magnitude, angle);
+
+        assert_approx_eq!(8_f64.sqrt(), magnitude);
+        assert_approx_eq!(-2.0, w.real_part());
+        assert_approx_eq!(2.0, w.imag_part());
+    }
+
+    #[test]
+    fn test_nth_roots() {
+        let z = Complex::new(-8.0, 0.0);
+
+        let roots = z.nth_roots(3);
+
+        assert_eq!(3, roots.len());
+        for root in roots {
+            let cube = root.powi(3);
+            assert_approx_eq!(-8.0, cube.real_part());
+            assert_approx_eq!(0.0, cube.imag_part());
+        }
+    }
+
+    #[test]
+    fn test_powi_second_quadrant() {
+        let z = Complex::new(-1.0, 1.0);
+
+        let z_squared = z.powi(2);
+
+        assert_approx_eq!(0.0, z_squared.real_part());
+        assert_approx_eq!(-2.0, z_squared.imag_part());
+    }
+
+    #[test]
+    fn test_exp_and_ln() {
+        let z = Complex::new(1.0, 2.0);
+
+        let w = z.exp().ln();
+
+        assert_approx_eq!(1.0, w.real_part());
+        assert_approx_eq!(2.0, w.imag_part());
+    }
+
+    #[test]
+    fn test_exp_m1() {
+        let z = Complex::new(1e-10, 1e-10).exp_m1();
+
+        assert_approx_eq!(1e-10, z.real_part(), 1e-25);
+        assert_approx_eq!(1.0000000001e-10, z.imag_part(), 1e-25);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let z = Complex::new(-3.0, -4.0).sqrt();
+
+        assert_approx_eq!(1.0, z.real_part());
+        assert_approx_eq!(-2.0, z.imag_part());
+    }
+
+    #[test]
+    fn test_sin_and_cos() {
+        let z = Complex::new(1.0, 2.0);
+
+        let identity = z.sin() * z.sin() + z.cos() * z.cos();
+
+        assert_approx_eq!(1.0, identity.real_part());
+        assert_approx_eq!(0.0, identity.imag_part());
+    }
+
     #[test]
     fn test_powi() {
         let z = Complex::new(1.0, 2.0);
@@ -84,6 +193,63 @@ mod tests {
         assert_approx_eq!(0.08, z3.imag_part());
     }
 
+    #[test]
+    fn test_div_extreme_components() {
+        let z1 = Complex::new(1e300, 1e300);
+        let z2 = Complex::new(1e300, 1e300);
+
+        let z3 = z1 / z2;
+
+        assert_approx_eq!(1.0, z3.real_part());
+        assert_approx_eq!(0.0, z3.imag_part());
+    }
+
+    #[test]
+    fn test_mixed_arithmetic() {
+        let z = Complex::new(1.0, 2.0);
+
+        assert_eq!(Complex::new(3.0, 2.0), z + 2.0);
+        assert_eq!(Complex::new(3.0, 2.0), 2.0 + z);
+        assert_eq!(Complex::new(-1.0, 2.0), z - 2.0);
+        assert_eq!(Complex::new(1.0, -2.0), 2.0 - z);
+        assert_eq!(Complex::new(2.0, 4.0), z * 2.0);
+        assert_eq!(Complex::new(2.0, 4.0), 2.0 * z);
+        assert_eq!(Complex::new(0.5, 1.0), z / 2.0);
+        assert_eq!(Complex::new(0.4, -0.8), 2.0 / z);
+        assert_eq!(Complex::new(-1.0, -2.0), -z);
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut z = Complex::ONE;
+
+        z += Complex::I;
+        z *= 2.0;
+        z -= 1.0;
+        z /= Complex::new(1.0, 2.0);
+
+        assert_approx_eq!(1.0, z.real_part());
+        assert_approx_eq!(0.0, z.imag_part());
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Complex::ZERO, Complex::default());
+        assert_eq!(Complex::ONE, Complex::from(1.0));
+        assert_eq!(-Complex::ONE, Complex::I * Complex::I);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let numbers = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+
+        let sum: Complex = numbers.iter().sum();
+        let product: Complex = numbers.into_iter().product();
+
+        assert_eq!(Complex::new(4.0, 6.0), sum);
+        assert_eq!(Complex::new(-5.0, 10.0), product);
+    }
+
     #[test]
     fn test_display() {
         let z = Complex::new(1.0, 2.0);
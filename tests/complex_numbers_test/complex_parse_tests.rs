@@ -0,0 +1,81 @@
+use numerilib::{Complex, ParseComplexError};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_parse_rectangular() {
+        let z: Complex = "3+4i".parse().unwrap();
+
+        assert_eq!(Complex::new(3.0, 4.0), z);
+    }
+
+    #[test]
+    fn test_parse_imaginary() {
+        let z: Complex = "-2.5i".parse().unwrap();
+        let w: Complex = "-i".parse().unwrap();
+
+        assert_eq!(Complex::new(0.0, -2.5), z);
+        assert_eq!(Complex::new(0.0, -1.0), w);
+    }
+
+    #[test]
+    fn test_parse_real() {
+        let z: Complex = "7".parse().unwrap();
+
+        assert_eq!(Complex::new(7.0, 0.0), z);
+    }
+
+    #[test]
+    fn test_parse_exponent() {
+        let z: Complex = "1e3-2j".parse().unwrap();
+        let w: Complex = "1e-3+2E+2i".parse().unwrap();
+
+        assert_eq!(Complex::new(1000.0, -2.0), z);
+        assert_eq!(Complex::new(0.001, 200.0), w);
+    }
+
+    #[test]
+    fn test_parse_tuple() {
+        let z: Complex = "( 1, 2 )".parse().unwrap();
+
+        assert_eq!(Complex::new(1.0, 2.0), z);
+    }
+
+    #[test]
+    fn test_parse_polar() {
+        let z: Complex = "5∠0.9272952180016122".parse().unwrap();
+
+        assert_approx_eq!(3.0, z.real_part());
+        assert_approx_eq!(4.0, z.imag_part());
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let numbers = [
+            Complex::new(1.0, 2.0),
+            Complex::new(-0.1, -1e-20),
+            Complex::new(1e300, 1.0 / 3.0),
+        ];
+
+        for z in numbers {
+            let parsed: Complex = z.to_string().parse().unwrap();
+            assert_eq!(z, parsed);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ParseComplexError::Empty), "  ".parse::<Complex>());
+        assert_eq!(
+            Err(ParseComplexError::InvalidNumber("+x".to_string())),
+            "3+xi".parse::<Complex>()
+        );
+        assert_eq!(
+            Err(ParseComplexError::InvalidFormat("(1;2)".to_string())),
+            "(1;2)".parse::<Complex>()
+        );
+    }
+}
@@ -1 +1,2 @@
 mod complex_numbers_tests;
+mod complex_parse_tests;
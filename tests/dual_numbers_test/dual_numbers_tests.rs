@@ -0,0 +1,102 @@
+use numerilib::Dual;
+use std::f64::consts::E;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_new_and_parts() {
+        let x = Dual::new(3.0, 1.0);
+
+        assert_eq!(3.0, x.real_part());
+        assert_eq!(1.0, x.dual_part());
+        assert_eq!(Dual::new(2.0, 1.0), Dual::variable(2.0));
+        assert_eq!(Dual::new(2.0, 0.0), Dual::constant(2.0));
+        assert_eq!(Dual::constant(2.0), Dual::from(2.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = Dual::variable(2.0);
+
+        // f(x) = (3x^2 + 1) / x - x, f'(x) = 3 - 1/x^2 - 1
+        let f = (3.0 * x * x + 1.0) / x - x;
+
+        assert_approx_eq!(4.5, f.real_part());
+        assert_approx_eq!(1.75, f.dual_part());
+        assert_eq!(Dual::new(-2.0, -1.0), -x);
+        assert_eq!(Dual::new(0.5, -0.25), 1.0 / x);
+        assert_eq!(Dual::new(-1.0, -1.0), 1.0 - x);
+    }
+
+    #[test]
+    fn test_assignment_and_iterators() {
+        let x = Dual::variable(2.0);
+        let mut y = x;
+        y *= x;
+        y += 1.0;
+        y /= 2.0;
+        y -= x;
+
+        assert_eq!(Dual::new(0.5, 1.0), y);
+
+        let values = [x, x, Dual::constant(3.0)];
+        assert_eq!(Dual::new(7.0, 2.0), values.iter().sum());
+        assert_eq!(Dual::new(12.0, 12.0), values.iter().product());
+    }
+
+    #[test]
+    fn test_elementary_functions() {
+        let x = Dual::variable(0.5);
+
+        assert_approx_eq!(0.5_f64.exp(), x.exp().dual_part());
+        assert_approx_eq!(2.0, x.ln().dual_part());
+        assert_approx_eq!(0.5 / 0.5_f64.sqrt(), x.sqrt().dual_part());
+        assert_approx_eq!(0.5_f64.cos(), x.sin().dual_part());
+        assert_approx_eq!(-0.5_f64.sin(), x.cos().dual_part());
+        assert_approx_eq!(1.0 / 0.5_f64.cos().powi(2), x.tan().dual_part());
+        assert_approx_eq!(1.0 / 0.75_f64.sqrt(), x.asin().dual_part());
+        assert_approx_eq!(-1.0 / 0.75_f64.sqrt(), x.acos().dual_part());
+        assert_approx_eq!(0.8, x.atan().dual_part());
+        assert_approx_eq!(0.5_f64.cosh(), x.sinh().dual_part());
+        assert_approx_eq!(0.5_f64.sinh(), x.cosh().dual_part());
+        assert_approx_eq!(1.0 / 0.5_f64.cosh().powi(2), x.tanh().dual_part());
+        assert_approx_eq!(1.0, (-x).abs().dual_part());
+        assert_approx_eq!(-4.0, x.recip().dual_part());
+    }
+
+    #[test]
+    fn test_powers() {
+        let x = Dual::variable(2.0);
+
+        assert_eq!(Dual::new(8.0, 12.0), x.powi(3));
+        assert_approx_eq!(1.5 * 2_f64.sqrt(), x.powf(1.5).dual_part());
+
+        // d/dx x^x = x^x (ln(x) + 1)
+        let y = Dual::variable(E);
+        assert_approx_eq!(2.0 * E.powf(E), y.pow(y).dual_part(), 1e-12);
+    }
+
+    #[test]
+    fn test_powers_at_zero() {
+        let x = Dual::variable(0.0);
+
+        assert_eq!(Dual::new(1.0, 0.0), x.powi(0));
+        assert_eq!(Dual::new(0.0, 1.0), x.powi(1));
+        assert_eq!(Dual::new(0.0, 0.0), x.powi(2));
+        assert_eq!(Dual::new(1.0, 0.0), x.powf(0.0));
+        assert_eq!(Dual::new(0.0, 1.0), x.powf(1.0));
+
+        // p(x) = x^3 - 2x^2 + 5x + 4, p'(0) = 5
+        let p = x.powi(3) - 2.0 * x.powi(2) + 5.0 * x.powi(1) + 4.0 * x.powi(0);
+        assert_eq!(Dual::new(4.0, 5.0), p);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("3+1ε", format!("{}", Dual::new(3.0, 1.0)));
+        assert_eq!("-1.5-2ε", format!("{}", Dual::new(-1.5, -2.0)));
+    }
+}
@@ -0,0 +1,71 @@
+use numerilib::HyperDual;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_new_and_parts() {
+        let x = HyperDual::new(3.0, 1.0, 2.0, 4.0);
+
+        assert_eq!(3.0, x.real_part());
+        assert_eq!(1.0, x.first_part());
+        assert_eq!(2.0, x.second_part());
+        assert_eq!(4.0, x.mixed_part());
+        assert_eq!(HyperDual::new(2.0, 1.0, 1.0, 0.0), HyperDual::variable(2.0));
+        assert_eq!(HyperDual::new(2.0, 0.0, 0.0, 0.0), HyperDual::from(2.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = HyperDual::variable(2.0);
+
+        // f(x) = (3x^2 + 1) / x, f'(x) = 3 - 1/x^2, f''(x) = 2/x^3
+        let f = (3.0 * x * x + 1.0) / x;
+
+        assert_approx_eq!(6.5, f.real_part());
+        assert_approx_eq!(2.75, f.first_part());
+        assert_approx_eq!(2.75, f.second_part());
+        assert_approx_eq!(0.25, f.mixed_part());
+    }
+
+    #[test]
+    fn test_second_derivatives() {
+        let x = HyperDual::variable(0.5);
+
+        assert_approx_eq!(0.5_f64.exp(), x.exp().mixed_part());
+        assert_approx_eq!(-4.0, x.ln().mixed_part());
+        assert_approx_eq!(-0.5_f64.sin(), x.sin().mixed_part());
+        assert_approx_eq!(-0.5_f64.cos(), x.cos().mixed_part());
+        assert_approx_eq!(-0.64, x.atan().mixed_part());
+        assert_approx_eq!(0.5_f64.sinh(), x.sinh().mixed_part());
+        assert_approx_eq!(-0.25 / 0.125_f64.sqrt(), x.sqrt().mixed_part());
+        assert_approx_eq!(6.0 * 0.5, x.powi(3).mixed_part());
+        assert_approx_eq!(16.0, x.recip().mixed_part());
+    }
+
+    #[test]
+    fn test_powers_at_zero() {
+        let x = HyperDual::variable(0.0);
+
+        assert_eq!(HyperDual::new(1.0, 0.0, 0.0, 0.0), x.powi(0));
+        assert_eq!(HyperDual::new(0.0, 1.0, 1.0, 0.0), x.powi(1));
+        assert_eq!(HyperDual::new(0.0, 0.0, 0.0, 2.0), x.powi(2));
+        assert_eq!(HyperDual::new(1.0, 0.0, 0.0, 0.0), x.powf(0.0));
+        assert_eq!(HyperDual::new(0.0, 1.0, 1.0, 0.0), x.powf(1.0));
+        assert_eq!(HyperDual::new(0.0, 0.0, 0.0, 2.0), x.powf(2.0));
+
+        // p(x) = x^3 - 2x^2 + 5x + 4, p'(0) = 5 and p''(0) = -4
+        let p = x.powi(3) - 2.0 * x.powi(2) + 5.0 * x.powi(1) + 4.0 * x.powi(0);
+        assert_eq!(HyperDual::new(4.0, 5.0, 5.0, -4.0), p);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "1-2ε₁+3ε₂-4ε₁ε₂",
+            format!("{}", HyperDual::new(1.0, -2.0, 3.0, -4.0))
+        );
+    }
+}
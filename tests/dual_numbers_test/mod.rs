@@ -0,0 +1,2 @@
+mod dual_numbers_tests;
+mod hyper_dual_numbers_tests;
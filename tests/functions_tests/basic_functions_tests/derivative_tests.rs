@@ -75,4 +75,18 @@ mod test {
         let derivative = Functions::derivative(function, 2_f64);
         assert_approx_eq!(6.772589387082917, derivative, 1e-2)
     }
+
+    #[test]
+    pub fn no_rounding_test() {
+        let function = |x: f64| 2.00000001 * x;
+        let derivative = Functions::derivative(function, 3_f64);
+        assert_approx_eq!(2.00000001, derivative, 1e-12)
+    }
+
+    #[test]
+    pub fn large_argument_test() {
+        let function = |x: f64| x.ln();
+        let derivative = Functions::derivative(function, 1e8_f64);
+        assert_approx_eq!(1e-8, derivative, 1e-18)
+    }
 }
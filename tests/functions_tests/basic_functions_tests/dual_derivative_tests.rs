@@ -0,0 +1,31 @@
+use numerilib::special::Gamma;
+use numerilib::{Dual, Functions, HyperDual};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn dual_derivative_test() {
+        let function = |x: Dual| x.sin() * x.exp();
+        let derivative = Functions::dual_derivative(function, 2_f64);
+        assert_approx_eq!(2_f64.exp() * (2_f64.sin() + 2_f64.cos()), derivative, 1e-15)
+    }
+
+    #[test]
+    pub fn dual_derivative_special_function_test() {
+        let derivative = Functions::dual_derivative(Gamma::dual_lanczos, 6_f64);
+        let finite = Functions::richardson_derivative(Gamma::lanczos, 6_f64, 1);
+        assert_approx_eq!(finite.value, derivative, 1e-10)
+    }
+
+    #[test]
+    pub fn dual_second_derivative_test() {
+        let function = |x: HyperDual| x.powi(4) / x.ln();
+        let second = Functions::dual_second_derivative(function, 2_f64);
+        let ln2 = std::f64::consts::LN_2;
+        let expected = 12.0 * 4.0 / ln2 - 7.0 * 4.0 / ln2.powi(2) + 2.0 * 4.0 / ln2.powi(3);
+        assert_approx_eq!(expected, second, 1e-12)
+    }
+}
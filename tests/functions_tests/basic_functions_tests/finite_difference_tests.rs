@@ -0,0 +1,38 @@
+use numerilib::{Functions, Stencil};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn forward_test() {
+        let function = |x: f64| x.exp();
+        let derivative = Functions::finite_difference(function, 1_f64, 1, Stencil::Forward, 4);
+        assert_approx_eq!(std::f64::consts::E, derivative, 1e-11)
+    }
+
+    #[test]
+    pub fn backward_test() {
+        let function = |x: f64| x.sin();
+        let derivative = Functions::finite_difference(function, 2_f64, 1, Stencil::Backward, 4);
+        assert_approx_eq!(2_f64.cos(), derivative, 1e-11)
+    }
+
+    #[test]
+    pub fn high_accuracy_central_test() {
+        let function = |x: f64| x.sin();
+        let derivative = Functions::finite_difference(function, 2_f64, 2, Stencil::Central, 8);
+        assert_approx_eq!(-2_f64.sin(), derivative, 1e-11)
+    }
+
+    #[test]
+    pub fn stencil_weights_test() {
+        let weights = Functions::stencil_weights(&[-2.0, -1.0, 0.0, 1.0, 2.0], 1);
+        let expected = [1.0 / 12.0, -2.0 / 3.0, 0.0, 2.0 / 3.0, -1.0 / 12.0];
+
+        for (weight, expected) in weights.iter().zip(expected) {
+            assert_approx_eq!(expected, weight, 1e-15)
+        }
+    }
+}
@@ -1,4 +1,9 @@
 mod derivative_tests;
+mod dual_derivative_tests;
+mod finite_difference_tests;
 mod newton_method_tests;
+mod nth_derivative_tests;
 mod product_tests;
+mod richardson_derivative_tests;
+mod richardson_extrapolation_tests;
 mod summation_tests;
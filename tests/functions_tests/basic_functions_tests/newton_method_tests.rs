@@ -11,7 +11,7 @@ mod test {
         let guess = 1.5;
         let root = Functions::newmet(guess, function);
 
-        assert_approx_eq!(1.4142135623730951, root);
+        assert_approx_eq!(std::f64::consts::SQRT_2, root);
     }
 
     #[test]
@@ -0,0 +1,35 @@
+use numerilib::Functions;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn zeroth_derivative_test() {
+        let function = |x: f64| x.sin();
+        let derivative = Functions::nth_derivative(function, 2_f64, 0);
+        assert_eq!(2_f64.sin(), derivative)
+    }
+
+    #[test]
+    pub fn second_derivative_test() {
+        let function = |x: f64| x.powi(3);
+        let derivative = Functions::nth_derivative(function, 2_f64, 2);
+        assert_approx_eq!(12_f64, derivative, 1e-9)
+    }
+
+    #[test]
+    pub fn third_derivative_test() {
+        let function = |x: f64| x.sin();
+        let derivative = Functions::nth_derivative(function, 2_f64, 3);
+        assert_approx_eq!(-2_f64.cos(), derivative, 1e-8)
+    }
+
+    #[test]
+    pub fn fourth_derivative_test() {
+        let function = |x: f64| x.exp();
+        let derivative = Functions::nth_derivative(function, 1_f64, 4);
+        assert_approx_eq!(std::f64::consts::E, derivative, 1e-7)
+    }
+}
@@ -7,7 +7,7 @@ mod test {
 
     #[test]
     pub fn constant_test() {
-        let constant = |x: f64| 3_f64;
+        let constant = |_x: f64| 3_f64;
         let lower_bound = 2_f64;
         let upper_bound = 7_f64;
         let product = Functions::product(lower_bound, upper_bound, constant);
@@ -0,0 +1,40 @@
+use numerilib::Functions;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn first_derivative_test() {
+        let function = |x: f64| x.sin();
+        let result = Functions::richardson_derivative(function, 2_f64, 1);
+        assert_approx_eq!(2_f64.cos(), result.value, 1e-13);
+        assert!(result.abs_error < 1e-12);
+    }
+
+    #[test]
+    pub fn second_derivative_test() {
+        let function = |x: f64| x.ln();
+        let result = Functions::richardson_derivative(function, 3_f64, 2);
+        assert_approx_eq!(-1_f64 / 9_f64, result.value, 1e-12);
+        assert!(result.abs_error < 1e-10);
+    }
+
+    #[test]
+    pub fn error_estimate_test() {
+        let function = |x: f64| x.powf(x);
+        let result = Functions::richardson_derivative(function, 2_f64, 1);
+        assert!((result.value - 6.772588722239781).abs() <= 10_f64 * result.abs_error + 1e-14);
+        assert!(result.evaluations > 0);
+    }
+
+    #[test]
+    pub fn zeroth_derivative_test() {
+        let function = |x: f64| x.cos();
+        let result = Functions::richardson_derivative(function, 1_f64, 0);
+        assert_eq!(1_f64.cos(), result.value);
+        assert_eq!(0_f64, result.abs_error);
+        assert_eq!(1, result.evaluations);
+    }
+}
@@ -0,0 +1,53 @@
+use numerilib::Functions;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn forward_difference_test() {
+        let sequence: Vec<f64> = [0.1_f64, 0.05, 0.025, 0.0125]
+            .iter()
+            .map(|h| (h.exp() - 1_f64) / h)
+            .collect();
+
+        let result = Functions::richardson_extrapolation(&sequence, 2_f64, 1_f64, 1_f64);
+
+        assert_approx_eq!(1_f64, result.value, 1e-7);
+        assert!((result.value - 1_f64).abs() < result.abs_error);
+    }
+
+    #[test]
+    pub fn inscribed_polygon_test() {
+        let sequence: Vec<f64> = [6_f64, 12_f64, 24_f64, 48_f64]
+            .iter()
+            .map(|n| n * (std::f64::consts::PI / n).sin())
+            .collect();
+
+        let result = Functions::richardson_extrapolation(&sequence, 2_f64, 2_f64, 2_f64);
+
+        assert_approx_eq!(std::f64::consts::PI, result.value, 1e-10);
+    }
+
+    #[test]
+    pub fn exact_for_error_terms_test() {
+        // A(h) = 3 + h² + h⁴ is eliminated exactly after two columns.
+        let sequence: Vec<f64> = [1_f64, 0.5, 0.25]
+            .iter()
+            .map(|h| 3_f64 + h.powi(2) + h.powi(4))
+            .collect();
+
+        let result = Functions::richardson_extrapolation(&sequence, 2_f64, 2_f64, 2_f64);
+
+        assert_approx_eq!(3_f64, result.value, 1e-15);
+    }
+
+    #[test]
+    pub fn single_estimate_test() {
+        let result = Functions::richardson_extrapolation(&[2.5], 2_f64, 2_f64, 2_f64);
+
+        assert_eq!(2.5, result.value);
+        assert!(result.abs_error.is_infinite());
+    }
+}
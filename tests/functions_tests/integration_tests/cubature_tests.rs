@@ -0,0 +1,80 @@
+use numerilib::{Functions, Region};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn unit_cube(dimension: usize) -> Region {
+        Region::Cuboid {
+            lower: vec![0_f64; dimension],
+            upper: vec![1_f64; dimension],
+        }
+    }
+
+    #[test]
+    pub fn nested_gauss_box_test() {
+        let region = Region::Cuboid {
+            lower: vec![0_f64, 0_f64],
+            upper: vec![1_f64, 2_f64],
+        };
+        let function = |x: &[f64]| x[0] * x[1];
+
+        let integral = Functions::nested_gauss(function, &region, 5);
+
+        assert_approx_eq!(1_f64, integral, 1e-15);
+    }
+
+    #[test]
+    pub fn nested_gauss_simplex_test() {
+        let region = Region::Simplex {
+            vertices: vec![
+                vec![1_f64, 0_f64, 0_f64],
+                vec![3_f64, 0_f64, 0_f64],
+                vec![1_f64, 2_f64, 0_f64],
+                vec![1_f64, 0_f64, 4_f64],
+            ],
+        };
+
+        let volume = Functions::nested_gauss(|_| 1_f64, &region, 3);
+        let moment = Functions::nested_gauss(|x: &[f64]| x[0], &region, 3);
+
+        assert_approx_eq!(8_f64 / 3_f64, volume, 1e-14);
+        assert_approx_eq!(4_f64, moment, 1e-14);
+    }
+
+    #[test]
+    pub fn genz_malik_degree_seven_test() {
+        let function = |x: &[f64]| x[0].powi(3) * x[1].powi(2) * x[2].powi(2);
+
+        let integral = Functions::genz_malik(function, &unit_cube(3), 1e-12, 1);
+
+        assert_approx_eq!(1_f64 / 36_f64, integral.value, 1e-15);
+        assert_eq!(33, integral.evaluations);
+        assert!(!integral.converged);
+    }
+
+    #[test]
+    pub fn genz_malik_adaptive_test() {
+        let function = |x: &[f64]| x.iter().map(|v| v.cos()).product::<f64>();
+
+        let integral = Functions::genz_malik(function, &unit_cube(3), 1e-8, 100_000);
+
+        assert_approx_eq!(0.5958232365909556, integral.value, 1e-10);
+        assert!(integral.abs_error < 1e-8);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn genz_malik_simplex_test() {
+        let region = Region::Simplex {
+            vertices: vec![vec![0_f64, 0_f64], vec![1_f64, 0_f64], vec![0_f64, 1_f64]],
+        };
+        let function = |x: &[f64]| (x[0] + x[1]).exp();
+
+        let integral = Functions::genz_malik(function, &region, 1e-10, 100_000);
+
+        assert_approx_eq!(1_f64, integral.value, 1e-12);
+        assert!(integral.converged);
+    }
+}
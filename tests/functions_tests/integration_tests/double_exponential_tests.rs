@@ -0,0 +1,72 @@
+use numerilib::Functions;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn tanh_sinh_test() {
+        let function = |x: f64| (1_f64 - x * x).sqrt();
+
+        let integral = Functions::double_exponential(function, -1_f64, 1_f64, 1e-12);
+
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, integral.value, 1e-14);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn endpoint_singularity_test() {
+        let function = |x: f64| x.ln() / x.sqrt();
+
+        let integral = Functions::double_exponential(function, 0_f64, 1_f64, 1e-12);
+
+        assert_approx_eq!(-4_f64, integral.value, 1e-13);
+        assert!(integral.converged);
+        assert!(integral.abs_error < 1e-12);
+    }
+
+    #[test]
+    pub fn exp_sinh_test() {
+        let function = |x: f64| x * x * (-x).exp();
+
+        let integral = Functions::double_exponential(function, 1_f64, f64::INFINITY, 1e-12);
+
+        assert_approx_eq!(1.8393972058572117, integral.value, 1e-14);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn negative_half_line_test() {
+        let function = |x: f64| 1_f64 / (1_f64 + x * x);
+
+        let integral = Functions::double_exponential(function, f64::NEG_INFINITY, 0_f64, 1e-12);
+
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, integral.value, 1e-14);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn sinh_sinh_test() {
+        let function = |x: f64| (-x * x).exp();
+
+        let integral =
+            Functions::double_exponential(function, f64::NEG_INFINITY, f64::INFINITY, 1e-12);
+
+        assert_approx_eq!(std::f64::consts::PI.sqrt(), integral.value, 1e-14);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn reversed_limits_test() {
+        let function = |x: f64| (-x).exp();
+
+        let integral = Functions::double_exponential(function, f64::INFINITY, 0_f64, 1e-12);
+
+        assert_approx_eq!(-1_f64, integral.value, 1e-14);
+        assert_eq!(
+            0_f64,
+            Functions::double_exponential(function, 2_f64, 2_f64, 1e-12).value
+        );
+    }
+}
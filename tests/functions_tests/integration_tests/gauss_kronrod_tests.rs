@@ -0,0 +1,70 @@
+use numerilib::{Functions, KronrodRule};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn polynomial_test() {
+        let function = |x: f64| x.powi(2);
+
+        let integral = Functions::gauss_kronrod(function, 0_f64, 6_f64, 1e-12, KronrodRule::G7K15);
+
+        assert_approx_eq!(72_f64, integral.value, 1e-12);
+        assert!(integral.converged);
+        assert_eq!(15, integral.evaluations);
+    }
+
+    #[test]
+    pub fn gaussian_test() {
+        let function = |x: f64| (-x * x).exp();
+
+        let integral =
+            Functions::gauss_kronrod(function, -5_f64, 5_f64, 1e-12, KronrodRule::G10K21);
+
+        assert_approx_eq!(1.7724538509027912, integral.value, 1e-13);
+        assert!(integral.abs_error < 1e-12);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn endpoint_singularity_test() {
+        let function = |x: f64| x.ln() / x.sqrt();
+
+        let integral = Functions::gauss_kronrod(function, 0_f64, 1_f64, 1e-10, KronrodRule::G10K21);
+
+        assert_approx_eq!(-4_f64, integral.value, 1e-12);
+        assert!(integral.converged);
+        assert!(integral.abs_error < 1e-10);
+    }
+
+    #[test]
+    pub fn strong_singularity_test() {
+        let function = |x: f64| x.powf(-0.9);
+
+        let integral = Functions::gauss_kronrod(function, 0_f64, 1_f64, 1e-10, KronrodRule::G7K15);
+
+        assert_approx_eq!(10_f64, integral.value, 1e-11);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn interior_kink_test() {
+        let function = |x: f64| (x - 1_f64 / 3_f64).abs().sqrt();
+
+        let integral = Functions::gauss_kronrod(function, 0_f64, 1_f64, 1e-10, KronrodRule::G7K15);
+
+        assert_approx_eq!(0.4911874291211284, integral.value, 1e-12);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn divergent_test() {
+        let function = |x: f64| 1_f64 / x;
+
+        let integral = Functions::gauss_kronrod(function, 0_f64, 1_f64, 1e-10, KronrodRule::G7K15);
+
+        assert!(!integral.converged);
+    }
+}
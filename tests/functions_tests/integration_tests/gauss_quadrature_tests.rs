@@ -0,0 +1,79 @@
+use numerilib::{Functions, GaussRule};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn legendre_nodes_test() {
+        let (nodes, weights) = Functions::gauss_nodes(GaussRule::Legendre, 5);
+
+        assert_eq!(5, nodes.len());
+        assert_approx_eq!(-0.906179845938664, nodes[0], 1e-15);
+        assert_approx_eq!(0_f64, nodes[2], 1e-15);
+        assert_approx_eq!(0.906179845938664, nodes[4], 1e-15);
+        assert_approx_eq!(0.23692688505618908, weights[0], 1e-15);
+        assert_approx_eq!(0.5688888888888889, weights[2], 1e-15);
+    }
+
+    #[test]
+    pub fn gauss_legendre_test() {
+        let function = |x: f64| x.cos();
+
+        let integral = Functions::gauss_legendre(function, 0_f64, 1_f64, 10);
+
+        assert_approx_eq!(0.8414709848078965, integral, 1e-15);
+        assert_approx_eq!(
+            2_f64 / 39_f64,
+            Functions::gauss_quadrature(|x: f64| x.powi(38), GaussRule::Legendre, 20),
+            1e-15
+        );
+    }
+
+    #[test]
+    pub fn laguerre_test() {
+        let integral =
+            Functions::gauss_quadrature(|x: f64| x.powi(5), GaussRule::Laguerre(0_f64), 3);
+        let generalized =
+            Functions::gauss_quadrature(|x: f64| x.cos(), GaussRule::Laguerre(0.5), 40);
+
+        assert_approx_eq!(120_f64, integral, 1e-12);
+        assert_approx_eq!(0.20165644396539353, generalized, 1e-12);
+    }
+
+    #[test]
+    pub fn hermite_test() {
+        let integral = Functions::gauss_quadrature(|x: f64| x.powi(2), GaussRule::Hermite, 20);
+        let (nodes, weights) = Functions::gauss_nodes(GaussRule::Hermite, 100);
+
+        assert_approx_eq!(std::f64::consts::PI.sqrt() / 2_f64, integral, 1e-14);
+        assert_approx_eq!(-nodes[0], nodes[99], 1e-12);
+        assert_approx_eq!(
+            std::f64::consts::PI.sqrt(),
+            weights.iter().sum::<f64>(),
+            1e-14
+        );
+    }
+
+    #[test]
+    pub fn jacobi_test() {
+        let integral = Functions::gauss_quadrature(|_| 1_f64, GaussRule::Jacobi(0.5, -0.3), 1);
+        let legendre =
+            Functions::gauss_quadrature(|x: f64| x.exp(), GaussRule::Jacobi(0_f64, 0_f64), 10);
+
+        assert_approx_eq!(2.3986693804178205, integral, 1e-14);
+        assert_approx_eq!(1_f64.exp() - (-1_f64).exp(), legendre, 1e-14);
+    }
+
+    #[test]
+    pub fn chebyshev_test() {
+        let first =
+            Functions::gauss_quadrature(|x: f64| x.powi(2), GaussRule::ChebyshevFirstKind, 4);
+        let second =
+            Functions::gauss_quadrature(|x: f64| x.powi(2), GaussRule::ChebyshevSecondKind, 4);
+
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, first, 1e-15);
+        assert_approx_eq!(std::f64::consts::PI / 8_f64, second, 1e-15);
+    }
+}
@@ -0,0 +1,71 @@
+use numerilib::{Functions, KronrodRule};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn trapezoid_test() {
+        let function = |x: f64| (-x).exp();
+
+        let integral = Functions::trapezoid(function, 0_f64, f64::INFINITY, 1000_f64);
+
+        assert_approx_eq!(1_f64, integral, 1e-6);
+    }
+
+    #[test]
+    pub fn simpson_test() {
+        let function = |x: f64| (-x * x).exp();
+
+        let integral = Functions::simpson(function, f64::NEG_INFINITY, f64::INFINITY, 1000_f64);
+
+        assert_approx_eq!(std::f64::consts::PI.sqrt(), integral, 1e-12);
+    }
+
+    #[test]
+    pub fn boole_rule_test() {
+        let function = |x: f64| 1_f64 / (1_f64 + x * x);
+
+        let integral = Functions::boole_rule(function, 0_f64, f64::INFINITY);
+
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, integral, 1e-1);
+    }
+
+    #[test]
+    pub fn adaptive_quadrature_test() {
+        let function = |x: f64| x.exp();
+
+        let integral = Functions::adaptive_quadrature(function, f64::NEG_INFINITY, 0_f64, 1e-10);
+
+        assert_approx_eq!(1_f64, integral, 1e-6);
+    }
+
+    #[test]
+    pub fn gauss_kronrod_test() {
+        let function = |x: f64| {
+            x * x * (-(x - 1_f64).powi(2) / 2_f64).exp() / (2_f64 * std::f64::consts::PI).sqrt()
+        };
+
+        let integral = Functions::gauss_kronrod(
+            function,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            1e-12,
+            KronrodRule::G10K21,
+        );
+
+        assert_approx_eq!(2_f64, integral.value, 1e-13);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn reversed_limits_test() {
+        let function = |x: f64| (-x).exp();
+
+        let integral =
+            Functions::gauss_kronrod(function, f64::INFINITY, 0_f64, 1e-12, KronrodRule::G7K15);
+
+        assert_approx_eq!(-1_f64, integral.value, 1e-13);
+    }
+}
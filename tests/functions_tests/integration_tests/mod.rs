@@ -1,7 +1,14 @@
 mod adaptive_quadrature_tests;
 mod boole_rule_test;
+mod cubature_tests;
+mod double_exponential_tests;
+mod gauss_kronrod_tests;
+mod gauss_quadrature_tests;
+mod infinite_interval_tests;
 mod left_riemann_tests;
 mod midpoint_riemann_tests;
+mod monte_carlo_tests;
 mod right_riemann_tests;
+mod romberg_tests;
 mod simpson_rule_tests;
 mod trapezoid_tests;
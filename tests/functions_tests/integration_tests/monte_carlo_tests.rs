@@ -0,0 +1,132 @@
+use numerilib::{Functions, QuasiRandomSequence, Region};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn unit_cube(dimension: usize) -> Region {
+        Region::Cuboid {
+            lower: vec![0_f64; dimension],
+            upper: vec![1_f64; dimension],
+        }
+    }
+
+    #[test]
+    pub fn monte_carlo_test() {
+        let region = Region::Cuboid {
+            lower: vec![-1_f64; 3],
+            upper: vec![1_f64; 3],
+        };
+        let ball = |x: &[f64]| {
+            if x.iter().map(|v| v * v).sum::<f64>() <= 1_f64 {
+                1_f64
+            } else {
+                0_f64
+            }
+        };
+
+        let integral = Functions::monte_carlo(ball, &region, 1e-3, 1_000_000, 42);
+
+        assert!(integral.converged);
+        assert!(
+            (integral.value - 4_f64 * std::f64::consts::PI / 3_f64).abs()
+                < 4_f64 * integral.abs_error
+        );
+    }
+
+    #[test]
+    pub fn monte_carlo_seed_test() {
+        let function = |x: &[f64]| x[0] * x[1];
+
+        let first = Functions::monte_carlo(function, &unit_cube(2), 1e-12, 5000, 7);
+        let second = Functions::monte_carlo(function, &unit_cube(2), 1e-12, 5000, 7);
+
+        assert_eq!(first, second);
+        assert_eq!(5000, first.evaluations);
+        assert!(!first.converged);
+    }
+
+    #[test]
+    pub fn sobol_test() {
+        let function = |x: &[f64]| x.iter().map(|v| v.cos()).product::<f64>();
+
+        let integral = Functions::quasi_monte_carlo(
+            function,
+            &unit_cube(6),
+            QuasiRandomSequence::Sobol,
+            1e-5,
+            1_000_000,
+            42,
+        );
+
+        assert!(integral.converged);
+        assert_approx_eq!(0.3550053292617218, integral.value, 5e-5);
+    }
+
+    #[test]
+    pub fn halton_test() {
+        let function = |x: &[f64]| x.iter().map(|v| v.cos()).product::<f64>();
+
+        let integral = Functions::quasi_monte_carlo(
+            function,
+            &unit_cube(3),
+            QuasiRandomSequence::Halton,
+            1e-5,
+            1_000_000,
+            42,
+        );
+
+        assert!(integral.converged);
+        assert_approx_eq!(0.5958232365909556, integral.value, 5e-5);
+    }
+
+    #[test]
+    pub fn quasi_monte_carlo_simplex_test() {
+        let region = Region::Simplex {
+            vertices: vec![vec![0_f64, 0_f64], vec![2_f64, 0_f64], vec![0_f64, 2_f64]],
+        };
+
+        let integral = Functions::quasi_monte_carlo(
+            |_| 1_f64,
+            &region,
+            QuasiRandomSequence::Sobol,
+            1e-6,
+            100_000,
+            1,
+        );
+
+        assert_approx_eq!(2_f64, integral.value, 1e-3);
+    }
+
+    #[test]
+    pub fn quasi_monte_carlo_small_budget_test() {
+        // 100 evaluations allow 6 points for every one of the 16 shifts.
+        let integral = Functions::quasi_monte_carlo(
+            |x: &[f64]| x[0] * x[1],
+            &unit_cube(2),
+            QuasiRandomSequence::Sobol,
+            1e-12,
+            100,
+            1,
+        );
+
+        assert_eq!(96, integral.evaluations);
+        assert!(!integral.converged);
+        assert_approx_eq!(0.25_f64, integral.value, 0.05);
+        assert!(integral.abs_error.is_finite());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn sobol_dimension_test() {
+        Functions::quasi_monte_carlo(
+            |_| 1_f64,
+            &unit_cube(11),
+            QuasiRandomSequence::Sobol,
+            1e-3,
+            1000,
+            1,
+        );
+    }
+}
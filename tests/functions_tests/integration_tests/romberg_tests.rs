@@ -0,0 +1,58 @@
+use numerilib::Functions;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn polynomial_test() {
+        let function = |x: f64| x.powi(2);
+
+        let integral = Functions::romberg(function, 0_f64, 6_f64, 1e-12);
+
+        assert_approx_eq!(72_f64, integral.value, 1e-12);
+        assert!(integral.converged);
+        assert_eq!(17, integral.evaluations);
+    }
+
+    #[test]
+    pub fn sine_test() {
+        let function = |x: f64| x.sin();
+
+        let integral = Functions::romberg(function, 0_f64, std::f64::consts::PI, 1e-12);
+
+        assert_approx_eq!(2_f64, integral.value, 1e-14);
+        assert!(integral.abs_error < 1e-11);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn infinite_interval_test() {
+        let function = |x: f64| (-x * x).exp();
+
+        let integral = Functions::romberg(function, f64::NEG_INFINITY, f64::INFINITY, 1e-10);
+
+        assert_approx_eq!(std::f64::consts::PI.sqrt(), integral.value, 1e-12);
+        assert!(integral.converged);
+    }
+
+    #[test]
+    pub fn reversed_limits_test() {
+        let function = |x: f64| x.exp();
+
+        let integral = Functions::romberg(function, 1_f64, 0_f64, 1e-12);
+
+        assert_approx_eq!(1_f64 - 1_f64.exp(), integral.value, 1e-14);
+    }
+
+    #[test]
+    pub fn not_converged_test() {
+        let function = |x: f64| x.sqrt();
+
+        let integral = Functions::romberg(function, 0_f64, 1_f64, 1e-14);
+
+        assert!(!integral.converged);
+        assert_approx_eq!(2_f64 / 3_f64, integral.value, 1e-9);
+    }
+}
@@ -74,7 +74,7 @@ pub mod tests {
 
         let integral = Functions::simpson(function, lower_bound, upper_bound, intervals);
 
-        assert_approx_eq!(0.6931471805599453, integral);
+        assert_approx_eq!(std::f64::consts::LN_2, integral);
     }
 
     #[test]
@@ -0,0 +1,63 @@
+use numerilib::{Functions, MinimizationError, MinimumTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn bracket_test() {
+        for (function, minimizer) in [
+            (
+                Box::new(|x: f64| (x - 50_f64).powi(2)) as Box<dyn Fn(f64) -> f64>,
+                50_f64,
+            ),
+            (Box::new(|x: f64| (x + 7_f64).powi(2)), -7_f64),
+            (Box::new(|x: f64| x.cos()), std::f64::consts::PI),
+        ] {
+            let bracket = Functions::bracket_minimum(&function, 0_f64, 1_f64).unwrap();
+
+            assert!(bracket.lower < bracket.middle && bracket.middle < bracket.upper);
+            assert!(bracket.lower < minimizer && minimizer < bracket.upper);
+            assert!(bracket.f_middle <= bracket.f_lower && bracket.f_middle <= bracket.f_upper);
+            assert_eq!(function(bracket.middle), bracket.f_middle);
+        }
+    }
+
+    #[test]
+    pub fn unbounded_test() {
+        let result = Functions::bracket_minimum(|x: f64| -x, 0_f64, 1_f64);
+
+        assert!(matches!(
+            result,
+            Err(MinimizationError::NotBracketed { .. })
+        ));
+    }
+
+    #[test]
+    pub fn minimize_test() {
+        let function = |x: f64| x.cosh() - x;
+
+        let result =
+            Functions::minimize(function, 0_f64, 0.1, MinimumTolerance::default()).unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(1_f64.asinh(), result.argmin, 1e-8);
+        assert!(result.evaluations < 30);
+    }
+
+    #[test]
+    pub fn quadratic_test() {
+        // The parabolic extrapolation of the bracket search lands on the minimum of a parabola.
+        let function = |x: f64| 3_f64 * (x - 0.25).powi(2) - 1_f64;
+
+        let bracket = Functions::bracket_minimum(function, -10_f64, -9_f64).unwrap();
+        let result =
+            Functions::minimize(function, -10_f64, -9_f64, MinimumTolerance::default()).unwrap();
+
+        assert_approx_eq!(0.25, bracket.middle, 1e-12);
+        assert_approx_eq!(0.25, result.argmin, 1e-8);
+        assert_approx_eq!(-1_f64, result.minimum, 1e-15);
+        assert!(result.converged);
+    }
+}
@@ -0,0 +1,83 @@
+use numerilib::{Functions, MinimizationError, MinimumTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn golden_section_test() {
+        let function = |x: f64| (x - 2_f64).abs() + 1_f64;
+
+        let result =
+            Functions::golden_section(function, 5_f64, 0_f64, MinimumTolerance::default()).unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(2_f64, result.argmin, 1e-7);
+        assert_approx_eq!(1_f64, result.minimum, 1e-7);
+        assert!(result.width <= 2_f64 * (1e-10 + f64::EPSILON.sqrt() * 2_f64));
+        assert_eq!(result.iterations + 2, result.evaluations);
+    }
+
+    #[test]
+    pub fn brent_test() {
+        let function = |x: f64| x.cosh() - x;
+        let tolerance = MinimumTolerance::default();
+
+        let brent = Functions::brent_minimize(function, -3_f64, 3_f64, tolerance).unwrap();
+        let golden = Functions::golden_section(function, -3_f64, 3_f64, tolerance).unwrap();
+
+        assert!(brent.converged);
+        assert_approx_eq!(1_f64.asinh(), brent.argmin, 1e-8);
+        assert_approx_eq!(2_f64.sqrt() - 1_f64.asinh(), brent.minimum, 1e-15);
+        assert!(brent.evaluations * 2 < golden.evaluations);
+    }
+
+    #[test]
+    pub fn likelihood_test() {
+        // The negative log-likelihood of the rate of an exponential sample with mean 2.
+        let function = |rate: f64| -rate.ln() + 2_f64 * rate;
+
+        let result =
+            Functions::brent_minimize(function, 0.01, 10_f64, MinimumTolerance::default()).unwrap();
+
+        assert_approx_eq!(0.5, result.argmin, 1e-8);
+        assert_approx_eq!(1_f64 + 2_f64.ln(), result.minimum, 1e-15);
+    }
+
+    #[test]
+    pub fn endpoint_test() {
+        let function = |x: f64| x;
+
+        let result =
+            Functions::brent_minimize(function, 1_f64, 3_f64, MinimumTolerance::default()).unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(1_f64, result.argmin, 1e-7);
+    }
+
+    #[test]
+    pub fn iterations_test() {
+        let tolerance = MinimumTolerance {
+            max_iterations: 5,
+            ..MinimumTolerance::default()
+        };
+
+        let result = Functions::golden_section(|x: f64| x * x, -1_f64, 2_f64, tolerance).unwrap();
+
+        assert!(!result.converged);
+        assert_eq!(5, result.iterations);
+        assert_approx_eq!(3_f64 * 0.6180339887498949_f64.powi(5), result.width, 1e-12);
+    }
+
+    #[test]
+    pub fn non_finite_test() {
+        let result =
+            Functions::brent_minimize(|x: f64| x.ln(), -2_f64, -1_f64, MinimumTolerance::default());
+
+        assert!(matches!(
+            result,
+            Err(MinimizationError::NonFiniteValue { .. })
+        ));
+    }
+}
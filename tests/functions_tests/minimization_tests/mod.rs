@@ -0,0 +1,2 @@
+mod bracket_tests;
+mod interval_tests;
@@ -1,2 +1,6 @@
 mod basic_functions_tests;
 mod integration_tests;
+mod minimization_tests;
+mod multivariate_tests;
+mod polynomial_tests;
+mod root_finding_tests;
@@ -0,0 +1,41 @@
+use numerilib::{Complex, Functions, Stencil};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn forward_gradient_test() {
+        let function = |x: &[f64]| x[0].powi(2) * x[1];
+        let gradient = Functions::gradient(function, &[1_f64, 2_f64], Stencil::Forward);
+        assert_approx_eq!(4_f64, gradient[0], 1e-6);
+        assert_approx_eq!(1_f64, gradient[1], 1e-6);
+    }
+
+    #[test]
+    pub fn central_gradient_test() {
+        let function = |x: &[f64]| x[0].exp() * x[1].sin() + x[2];
+        let gradient = Functions::gradient(function, &[1_f64, 2_f64, 3_f64], Stencil::Central);
+        assert_approx_eq!(std::f64::consts::E * 2_f64.sin(), gradient[0], 1e-9);
+        assert_approx_eq!(std::f64::consts::E * 2_f64.cos(), gradient[1], 1e-9);
+        assert_approx_eq!(1_f64, gradient[2], 1e-9);
+    }
+
+    #[test]
+    pub fn backward_gradient_test() {
+        let function = |x: &[f64]| x[0].ln() + x[1].powi(3);
+        let gradient = Functions::gradient(function, &[2_f64, -1_f64], Stencil::Backward);
+        assert_approx_eq!(0.5, gradient[0], 1e-6);
+        assert_approx_eq!(3_f64, gradient[1], 1e-6);
+    }
+
+    #[test]
+    pub fn complex_step_gradient_test() {
+        let function = |x: &[Complex]| x[0].exp() * x[1].sin() + x[0] * x[1].powi(2);
+        let gradient = Functions::complex_step_gradient(function, &[1_f64, 2_f64]);
+        let e = std::f64::consts::E;
+        assert_approx_eq!(e * 2_f64.sin() + 4_f64, gradient[0], 1e-14);
+        assert_approx_eq!(e * 2_f64.cos() + 4_f64, gradient[1], 1e-14);
+    }
+}
@@ -0,0 +1,52 @@
+use numerilib::{Complex, Functions, Stencil};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn central_hessian_test() {
+        let function = |x: &[f64]| x[0].powi(2) * x[1] + x[1].powi(3);
+        let hessian = Functions::hessian(function, &[1_f64, 2_f64], Stencil::Central);
+
+        assert_approx_eq!(4_f64, hessian.get(1, 1), 1e-6);
+        assert_approx_eq!(2_f64, hessian.get(1, 2), 1e-6);
+        assert_approx_eq!(2_f64, hessian.get(2, 1), 1e-6);
+        assert_approx_eq!(12_f64, hessian.get(2, 2), 1e-6);
+    }
+
+    #[test]
+    pub fn forward_hessian_test() {
+        let function = |x: &[f64]| x[0].exp() * x[1].sin();
+        let hessian = Functions::hessian(function, &[1_f64, 2_f64], Stencil::Forward);
+        let e = std::f64::consts::E;
+
+        assert_approx_eq!(e * 2_f64.sin(), hessian.get(1, 1), 1e-4);
+        assert_approx_eq!(e * 2_f64.cos(), hessian.get(1, 2), 1e-4);
+        assert_approx_eq!(-e * 2_f64.sin(), hessian.get(2, 2), 1e-4);
+    }
+
+    #[test]
+    pub fn backward_hessian_test() {
+        let function = |x: &[f64]| x[0] * x[1] * x[2];
+        let hessian = Functions::hessian(function, &[1_f64, 2_f64, 3_f64], Stencil::Backward);
+
+        assert_approx_eq!(0_f64, hessian.get(1, 1), 1e-4);
+        assert_approx_eq!(3_f64, hessian.get(1, 2), 1e-4);
+        assert_approx_eq!(2_f64, hessian.get(1, 3), 1e-4);
+        assert_approx_eq!(1_f64, hessian.get(2, 3), 1e-4);
+    }
+
+    #[test]
+    pub fn complex_step_hessian_test() {
+        let function = |x: &[Complex]| x[0].exp() * x[1].sin() + x[0] * x[1].powi(2);
+        let hessian = Functions::complex_step_hessian(function, &[1_f64, 2_f64]);
+        let e = std::f64::consts::E;
+
+        assert_approx_eq!(e * 2_f64.sin(), hessian.get(1, 1), 1e-9);
+        assert_approx_eq!(e * 2_f64.cos() + 4_f64, hessian.get(1, 2), 1e-9);
+        assert_approx_eq!(e * 2_f64.cos() + 4_f64, hessian.get(2, 1), 1e-9);
+        assert_approx_eq!(2_f64 - e * 2_f64.sin(), hessian.get(2, 2), 1e-9);
+    }
+}
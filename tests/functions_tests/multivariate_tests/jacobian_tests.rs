@@ -0,0 +1,52 @@
+use numerilib::{Complex, Functions, Matrix, Stencil};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn central_jacobian_test() {
+        let function = |x: &[f64]| vec![x[0] * x[1], x[0] + x[1].sin(), x[0].powi(2)];
+        let jacobian: Matrix<3, 2> =
+            Functions::jacobian(function, &[1_f64, 2_f64], Stencil::Central);
+
+        assert_eq!(vec![3, 2], jacobian.dimensions());
+        assert_approx_eq!(2_f64, jacobian.get(1, 1), 1e-9);
+        assert_approx_eq!(1_f64, jacobian.get(1, 2), 1e-9);
+        assert_approx_eq!(1_f64, jacobian.get(2, 1), 1e-9);
+        assert_approx_eq!(2_f64.cos(), jacobian.get(2, 2), 1e-9);
+        assert_approx_eq!(2_f64, jacobian.get(3, 1), 1e-9);
+        assert_approx_eq!(0_f64, jacobian.get(3, 2), 1e-9);
+    }
+
+    #[test]
+    pub fn forward_jacobian_test() {
+        let function = |x: &[f64]| vec![x[0].exp(), x[0] * x[1]];
+        let jacobian: Matrix<2, 2> =
+            Functions::jacobian(function, &[0_f64, 3_f64], Stencil::Forward);
+
+        assert_approx_eq!(1_f64, jacobian.get(1, 1), 1e-6);
+        assert_approx_eq!(0_f64, jacobian.get(1, 2), 1e-6);
+        assert_approx_eq!(3_f64, jacobian.get(2, 1), 1e-6);
+        assert_approx_eq!(0_f64, jacobian.get(2, 2), 1e-6);
+    }
+
+    #[test]
+    pub fn complex_step_jacobian_test() {
+        let function = |x: &[Complex]| vec![x[0] * x[1], x[0] + x[1].sin()];
+        let jacobian: Matrix<2, 2> = Functions::complex_step_jacobian(function, &[1_f64, 2_f64]);
+
+        assert_approx_eq!(2_f64, jacobian.get(1, 1), 1e-15);
+        assert_approx_eq!(1_f64, jacobian.get(1, 2), 1e-15);
+        assert_approx_eq!(1_f64, jacobian.get(2, 1), 1e-15);
+        assert_approx_eq!(2_f64.cos(), jacobian.get(2, 2), 1e-15);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn wrong_output_length_test() {
+        let function = |x: &[f64]| vec![x[0], x[1]];
+        let _: Matrix<3, 2> = Functions::jacobian(function, &[1_f64, 2_f64], Stencil::Central);
+    }
+}
@@ -0,0 +1,3 @@
+mod gradient_tests;
+mod hessian_tests;
+mod jacobian_tests;
@@ -0,0 +1,3 @@
+mod polynomial_methods_tests;
+mod polynomial_multiplicity_tests;
+mod polynomial_roots_tests;
@@ -0,0 +1,77 @@
+use numerilib::{Complex, Polynomial};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn evaluate_test() {
+        let coefficients = [2_f64, -3_f64, 1_f64];
+
+        let value = Polynomial::evaluate(&coefficients, Complex::new(1.0, 1.0));
+
+        assert_approx_eq!(-2.0, value.real_part());
+        assert_approx_eq!(1.0, value.imag_part());
+    }
+
+    #[test]
+    pub fn aberth_test() {
+        let coefficients = [1_f64, 0_f64, 1_f64];
+
+        let roots = Polynomial::aberth(&coefficients, 100).unwrap();
+
+        assert_eq!(2, roots.len());
+        for root in roots {
+            assert_approx_eq!(0.0, root.real_part(), 1e-12);
+            assert_approx_eq!(1.0, root.imag_part().abs(), 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn aberth_no_convergence_test() {
+        let coefficients = [1_f64, -55_f64, 1320_f64, -18150_f64, 157773_f64];
+
+        let roots = Polynomial::aberth(&coefficients, 1);
+
+        assert!(roots.is_none());
+    }
+
+    #[test]
+    pub fn companion_roots_test() {
+        // Wilkinson's polynomial (x - 1)(x - 2)...(x - 10)
+        let coefficients = [
+            1_f64,
+            -55_f64,
+            1320_f64,
+            -18150_f64,
+            157773_f64,
+            -902055_f64,
+            3416930_f64,
+            -8409500_f64,
+            12753576_f64,
+            -10628640_f64,
+            3628800_f64,
+        ];
+
+        let mut roots = Polynomial::companion_roots(&coefficients);
+        roots.sort_by(|a, b| a.real_part().total_cmp(&b.real_part()));
+
+        for (k, root) in roots.iter().enumerate() {
+            assert_approx_eq!((k + 1) as f64, root.real_part(), 1e-8);
+            assert_approx_eq!(0.0, root.imag_part(), 1e-8);
+        }
+    }
+
+    #[test]
+    pub fn polish_test() {
+        let coefficients = [1_f64, 0_f64, -2_f64];
+        let roots = [Complex::new(1.4, 0.0), Complex::new(-1.4, 0.1)];
+
+        let polished = Polynomial::polish(&coefficients, &roots);
+
+        assert_approx_eq!(std::f64::consts::SQRT_2, polished[0].real_part());
+        assert_approx_eq!(-std::f64::consts::SQRT_2, polished[1].real_part());
+        assert_approx_eq!(0.0, polished[1].imag_part());
+    }
+}
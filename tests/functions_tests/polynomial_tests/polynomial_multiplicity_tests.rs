@@ -0,0 +1,66 @@
+use numerilib::{Complex, Polynomial};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn triple_root_test() {
+        // (x - 1)^3 (x + 2)
+        let coefficients = [1_f64, -1_f64, -3_f64, 5_f64, -2_f64];
+
+        let mut roots = Polynomial::roots_with_multiplicity(&coefficients, 0.0);
+        roots.sort_by(|a, b| a.0.real_part().total_cmp(&b.0.real_part()));
+
+        assert_eq!(2, roots.len());
+        assert_approx_eq!(-2.0, roots[0].0.real_part(), 1e-12);
+        assert_eq!(1, roots[0].1);
+        assert_approx_eq!(1.0, roots[1].0.real_part(), 1e-12);
+        assert_approx_eq!(0.0, roots[1].0.imag_part(), 1e-12);
+        assert_eq!(3, roots[1].1);
+    }
+
+    #[test]
+    pub fn quadruple_root_test() {
+        // (x - 1)^4
+        let coefficients = [1_f64, -4_f64, 6_f64, -4_f64, 1_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        assert_eq!(4, roots.len());
+        for root in roots {
+            assert_approx_eq!(1.0, root.real_part(), 1e-12);
+            assert_approx_eq!(0.0, root.imag_part(), 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn repeated_complex_root_test() {
+        // (z^2 + 1)^2
+        let coefficients = [1_f64, 0_f64, 2_f64, 0_f64, 1_f64];
+
+        let roots = Polynomial::roots_with_multiplicity(&coefficients, 0.0);
+
+        assert_eq!(2, roots.len());
+        for (root, multiplicity) in roots {
+            assert_eq!(2, multiplicity);
+            assert_approx_eq!(0.0, root.real_part(), 1e-12);
+            assert_approx_eq!(1.0, root.imag_part().abs(), 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn tolerance_test() {
+        // (x - 1)(x - 1.001)
+        let coefficients = [1_f64, -2.001_f64, 1.001_f64];
+
+        let separate = Polynomial::roots_with_multiplicity(&coefficients, 0.0);
+        let merged = Polynomial::roots_with_multiplicity(&coefficients, 1e-2);
+
+        assert_eq!(2, separate.len());
+        assert_eq!(1, merged.len());
+        assert_eq!(2, merged[0].1);
+        assert!((merged[0].0 - Complex::new(1.0005, 0.0)).magnitude() < 1e-12);
+    }
+}
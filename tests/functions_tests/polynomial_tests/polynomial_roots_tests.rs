@@ -0,0 +1,80 @@
+use numerilib::{Complex, Polynomial};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn assert_roots(expected: &[Complex], actual: &[Complex], tolerance: f64) {
+        assert_eq!(expected.len(), actual.len());
+        for root in expected {
+            assert!(
+                actual.iter().any(|r| (*r - *root).magnitude() < tolerance),
+                "missing root {} in {:?}",
+                root,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    pub fn real_roots_test() {
+        let coefficients = [1_f64, -6_f64, 11_f64, -6_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        let expected = [1.0, 2.0, 3.0].map(Complex::from);
+        assert_roots(&expected, &roots, 1e-12);
+    }
+
+    #[test]
+    pub fn complex_conjugate_roots_test() {
+        let coefficients = [1_f64, -3_f64, 4_f64, -2_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        let expected = [
+            Complex::new(1.0, 0.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(1.0, -1.0),
+        ];
+        assert_roots(&expected, &roots, 1e-12);
+    }
+
+    #[test]
+    pub fn complex_coefficients_test() {
+        // (z - 1)(z - i) = z^2 - (1 + i)z + i
+        let coefficients = [Complex::ONE, Complex::new(-1.0, -1.0), Complex::I];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        assert_roots(&[Complex::ONE, Complex::I], &roots, 1e-12);
+    }
+
+    #[test]
+    pub fn roots_of_unity_test() {
+        let coefficients = [1_f64, 0_f64, 0_f64, 0_f64, 0_f64, -1_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        let expected = Complex::ONE.nth_roots(5);
+        assert_roots(&expected, &roots, 1e-12);
+    }
+
+    #[test]
+    pub fn zero_and_leading_zero_test() {
+        let coefficients = [0_f64, 2_f64, -2_f64, 0_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        assert_roots(&[Complex::ZERO, Complex::ONE], &roots, 1e-12);
+    }
+
+    #[test]
+    pub fn constant_test() {
+        let coefficients = [5_f64];
+
+        let roots = Polynomial::roots(&coefficients);
+
+        assert!(roots.is_empty());
+    }
+}
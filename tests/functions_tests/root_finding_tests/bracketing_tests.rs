@@ -0,0 +1,94 @@
+use numerilib::{Functions, RootError, RootTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn bisection_test() {
+        let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+
+        let result =
+            Functions::bisection(function, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(2.0945514815423265, result.root, 1e-11);
+        assert!(result.converged);
+        assert_eq!(result.iterations + 2, result.evaluations);
+    }
+
+    #[test]
+    pub fn brent_test() {
+        let function = |x: f64| x.cos() - x;
+
+        let result = Functions::brent(function, 0_f64, 1_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(0.7390851332151607, result.root, 1e-14);
+        assert!(result.converged);
+        assert!(result.iterations < 10);
+    }
+
+    #[test]
+    pub fn ridders_test() {
+        let function = |x: f64| (20_f64 * x).exp() - 1e6;
+
+        let result = Functions::ridders(function, 0_f64, 2_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(0.6907755278982137, result.root, 1e-14);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn illinois_test() {
+        let function = |x: f64| x.exp() - 1e-10;
+
+        let result =
+            Functions::illinois(function, -30_f64, 0_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(1e-10_f64.ln(), result.root, 1e-12);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn itp_test() {
+        let smooth = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+        let flat = |x: f64| (x - 1_f64).powi(3);
+
+        let fast = Functions::itp(smooth, 2_f64, 3_f64, RootTolerance::default()).unwrap();
+        let slow = Functions::itp(flat, 0_f64, 3_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(2.0945514815423265, fast.root, 1e-15);
+        assert!(fast.iterations < 10);
+        assert_approx_eq!(1_f64, slow.root, 1e-11);
+        assert!(slow.converged);
+    }
+
+    #[test]
+    pub fn errors_test() {
+        let tolerance = RootTolerance::default();
+
+        assert_eq!(
+            Err(RootError::NotBracketed {
+                f_lower: 2_f64,
+                f_upper: 2_f64
+            }),
+            Functions::brent(|x: f64| x * x + 1_f64, -1_f64, 1_f64, tolerance)
+        );
+        assert_eq!(
+            Err(RootError::NonFiniteValue { x: 0.5 }),
+            Functions::bisection(|x: f64| 1_f64 / (x - 0.5), 0_f64, 0.5, tolerance)
+        );
+
+        let endpoint = Functions::ridders(|x: f64| x - 1_f64, 1_f64, 2_f64, tolerance).unwrap();
+        assert_eq!(1_f64, endpoint.root);
+        assert_eq!(0, endpoint.iterations);
+
+        let limited = RootTolerance {
+            max_iterations: 5,
+            ..tolerance
+        };
+        let result = Functions::bisection(|x: f64| x - 0.3, 0_f64, 1_f64, limited).unwrap();
+        assert!(!result.converged);
+        assert_eq!(5, result.iterations);
+    }
+}
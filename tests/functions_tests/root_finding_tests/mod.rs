@@ -0,0 +1,4 @@
+mod bracketing_tests;
+mod newton_tests;
+mod nonlinear_systems_tests;
+mod open_methods_tests;
@@ -0,0 +1,100 @@
+use numerilib::{Newton, RootError, RootTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn analytic_derivative_test() {
+        let result = Newton::new(|x: f64| x.powi(2) - 2_f64)
+            .derivative(|x: f64| 2_f64 * x)
+            .solve(1.5)
+            .unwrap();
+
+        assert_approx_eq!(std::f64::consts::SQRT_2, result.root, 1e-15);
+        assert!(result.converged);
+        assert!(result.iterations <= 6);
+    }
+
+    #[test]
+    pub fn numerical_derivative_test() {
+        let result = Newton::new(|x: f64| x.cos() - x).solve(1_f64).unwrap();
+
+        assert_approx_eq!(0.7390851332151607, result.root, 1e-14);
+        assert!(result.converged);
+        assert!(result.evaluations > result.iterations);
+    }
+
+    #[test]
+    pub fn halley_test() {
+        let newton = Newton::new(|x: f64| x.powi(3) - 2_f64 * x - 5_f64)
+            .derivative(|x: f64| 3_f64 * x.powi(2) - 2_f64)
+            .solve(3_f64)
+            .unwrap();
+        let halley = Newton::new(|x: f64| x.powi(3) - 2_f64 * x - 5_f64)
+            .derivative(|x: f64| 3_f64 * x.powi(2) - 2_f64)
+            .halley(|x: f64| 6_f64 * x)
+            .solve(3_f64)
+            .unwrap();
+
+        assert_approx_eq!(2.0945514815423265, halley.root, 1e-14);
+        assert!(halley.converged);
+        assert!(halley.iterations < newton.iterations);
+    }
+
+    #[test]
+    pub fn line_search_test() {
+        let plain = Newton::new(f64::atan)
+            .derivative(|x: f64| 1_f64 / (1_f64 + x * x))
+            .max_iterations(20)
+            .solve(2_f64);
+        let damped = Newton::new(f64::atan)
+            .derivative(|x: f64| 1_f64 / (1_f64 + x * x))
+            .line_search(true)
+            .solve(2_f64)
+            .unwrap();
+
+        assert!(plain.map_or(true, |result| !result.converged));
+        assert_approx_eq!(0_f64, damped.root, 1e-12);
+        assert!(damped.converged);
+    }
+
+    #[test]
+    pub fn damping_test() {
+        let result = Newton::new(|x: f64| x.powi(2) - 4_f64)
+            .derivative(|x: f64| 2_f64 * x)
+            .damping(0.5)
+            .tolerance(RootTolerance {
+                residual: 1e-12,
+                ..RootTolerance::default()
+            })
+            .solve(3_f64)
+            .unwrap();
+
+        assert_approx_eq!(2_f64, result.root, 1e-12);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn diagnostics_test() {
+        let not_converged = Newton::new(|x: f64| x.powi(2) + 1_f64)
+            .derivative(|x: f64| 2_f64 * x)
+            .max_iterations(10)
+            .solve(0.5)
+            .unwrap();
+        let zero_slope = Newton::new(|x: f64| x.powi(2) + 1_f64)
+            .derivative(|x: f64| 2_f64 * x)
+            .solve(0_f64);
+
+        assert!(!not_converged.converged);
+        assert_eq!(10, not_converged.iterations);
+        assert_eq!(Err(RootError::ZeroSlope { x: 0_f64 }), zero_slope);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn invalid_damping_test() {
+        Newton::new(|x: f64| x).damping(0_f64);
+    }
+}
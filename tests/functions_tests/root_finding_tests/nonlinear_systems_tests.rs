@@ -0,0 +1,113 @@
+use numerilib::{Functions, Matrix, RootTolerance, SystemError};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use std::f64::consts::SQRT_2;
+
+    fn circle(x: &[f64]) -> Vec<f64> {
+        vec![x[0].powi(2) + x[1].powi(2) - 4_f64, x[0] - x[1]]
+    }
+
+    // A system with the root (1, 2, 3).
+    fn system(x: &[f64]) -> Vec<f64> {
+        vec![
+            x[0].powi(2) + x[1] + x[2] - 6_f64,
+            x[0] + x[1].powi(2) - x[2] - 2_f64,
+            x[0] * x[1] * x[2] - 6_f64,
+        ]
+    }
+
+    #[test]
+    pub fn newton_system_test() {
+        let result =
+            Functions::newton_system(circle, &[1_f64, 2_f64], RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(SQRT_2, result.root[0], 1e-14);
+        assert_approx_eq!(SQRT_2, result.root[1], 1e-14);
+        assert!(result.residual_norm < 1e-14);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn newton_system_jacobian_test() {
+        let jacobian = |x: &[f64]| Matrix::new([[2_f64 * x[0], 2_f64 * x[1]], [1_f64, -1_f64]]);
+
+        let analytic = Functions::newton_system_jacobian(
+            circle,
+            jacobian,
+            &[1_f64, 2_f64],
+            RootTolerance::default(),
+        )
+        .unwrap();
+        let numerical =
+            Functions::newton_system(circle, &[1_f64, 2_f64], RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(SQRT_2, analytic.root[0], 1e-15);
+        assert_approx_eq!(SQRT_2, analytic.root[1], 1e-15);
+        assert!(analytic.converged);
+        assert!(analytic.evaluations < numerical.evaluations);
+    }
+
+    #[test]
+    pub fn broyden_test() {
+        let result =
+            Functions::broyden(system, &[1.1, 2.1, 2.9], RootTolerance::default()).unwrap();
+
+        for (expected, value) in [1_f64, 2_f64, 3_f64].iter().zip(&result.root) {
+            assert_approx_eq!(expected, value, 1e-12);
+        }
+        assert!(result.residual_norm < 1e-10);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn dogleg_test() {
+        let rosenbrock = |x: &[f64]| vec![10_f64 * (x[1] - x[0].powi(2)), 1_f64 - x[0]];
+
+        let result =
+            Functions::dogleg(rosenbrock, &[-1.2, 1_f64], RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(1_f64, result.root[0], 1e-12);
+        assert_approx_eq!(1_f64, result.root[1], 1e-12);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn system_errors_test() {
+        let parallel = |x: &[f64]| vec![x[0] + x[1], x[0] + x[1] - 1_f64];
+        let singular =
+            Functions::newton_system(parallel, &[1_f64, 1_f64], RootTolerance::default());
+
+        let logarithm = |x: &[f64]| vec![x[0].ln(), x[1]];
+        let non_finite = Functions::broyden(logarithm, &[-1_f64, 1_f64], RootTolerance::default());
+
+        assert_eq!(
+            Err(SystemError::SingularJacobian {
+                x: vec![1_f64, 1_f64]
+            }),
+            singular
+        );
+        assert_eq!(
+            Err(SystemError::NonFiniteValue {
+                x: vec![-1_f64, 1_f64]
+            }),
+            non_finite
+        );
+    }
+
+    #[test]
+    pub fn max_iterations_test() {
+        let no_root = |x: &[f64]| vec![x[0].powi(2) + 1_f64, x[1]];
+        let tolerance = RootTolerance {
+            max_iterations: 5,
+            ..RootTolerance::default()
+        };
+
+        let result = Functions::broyden(no_root, &[0.5, 1_f64], tolerance).unwrap();
+
+        assert!(!result.converged);
+        assert_eq!(5, result.iterations);
+    }
+}
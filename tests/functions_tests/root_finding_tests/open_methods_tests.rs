@@ -0,0 +1,50 @@
+use numerilib::{Functions, RootError, RootTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn secant_test() {
+        let function = |x: f64| x.cos() - x;
+
+        let result = Functions::secant(function, 0_f64, 1_f64, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(0.7390851332151607, result.root, 1e-15);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn steffensen_test() {
+        let function = |x: f64| x.powi(2) - 2_f64;
+
+        let result = Functions::steffensen(function, 1.5, RootTolerance::default()).unwrap();
+
+        assert_approx_eq!(std::f64::consts::SQRT_2, result.root, 1e-15);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn residual_tolerance_test() {
+        let function = |x: f64| x.powi(3) - 2_f64 * x - 5_f64;
+        let tolerance = RootTolerance {
+            residual: 1e-3,
+            ..RootTolerance::default()
+        };
+
+        let result = Functions::secant(function, 2_f64, 3_f64, tolerance).unwrap();
+
+        assert!(result.function_value.abs() <= 1e-3);
+        assert!(result.converged);
+    }
+
+    #[test]
+    pub fn zero_slope_test() {
+        let function = |x: f64| x * x + 1_f64;
+
+        let result = Functions::secant(function, -1_f64, 1_f64, RootTolerance::default());
+
+        assert_eq!(Err(RootError::ZeroSlope { x: 1_f64 }), result);
+    }
+}
@@ -0,0 +1,90 @@
+use numerilib::interpolate::{Barycentric, Extrapolation};
+use numerilib::Functions;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use std::f64::consts::PI;
+
+    fn chebyshev(n: usize) -> Vec<f64> {
+        (0..=n)
+            .rev()
+            .map(|k| (k as f64 * PI / n as f64).cos())
+            .collect()
+    }
+
+    #[test]
+    pub fn polynomial_test() {
+        let x = [0_f64, 0.7, 1.5, 2_f64, 3.2];
+        let y = x.map(|x: f64| 1_f64 - 2_f64 * x + x.powi(4));
+        let polynomial = Barycentric::new(&x, &y);
+
+        for t in [-1_f64, 0.3, 2.5, 4_f64] {
+            assert_approx_eq!(polynomial.evaluate(t), 1_f64 - 2_f64 * t + t.powi(4), 1e-11);
+            assert_approx_eq!(
+                polynomial.derivative(t, 1),
+                -2_f64 + 4_f64 * t.powi(3),
+                1e-10
+            );
+            assert_approx_eq!(polynomial.derivative(t, 2), 12_f64 * t * t, 1e-9);
+        }
+
+        assert_approx_eq!(polynomial.derivative(1_f64, 4), 24_f64, 1e-9);
+        assert_eq!(polynomial.derivative(1_f64, 5), 0_f64);
+        assert_eq!(polynomial.evaluate(1.5), y[2]);
+    }
+
+    #[test]
+    pub fn runge_test() {
+        let runge = |x: f64| 1_f64 / (1_f64 + 25_f64 * x * x);
+        let x = chebyshev(80);
+        let y: Vec<f64> = x.iter().map(|&x| runge(x)).collect();
+        let polynomial = Barycentric::new(&x, &y);
+
+        let error = (0..=1000)
+            .map(|i| -1_f64 + i as f64 / 500_f64)
+            .map(|t| (polynomial.evaluate(t) - runge(t)).abs())
+            .fold(0_f64, f64::max);
+
+        assert!(error < 1e-6);
+        assert_approx_eq!(
+            polynomial.integrate(-1_f64, 1_f64),
+            0.4 * 5_f64.atan(),
+            1e-7
+        );
+    }
+
+    #[test]
+    pub fn integrate_test() {
+        let x = chebyshev(12);
+        let y: Vec<f64> = x.iter().map(|x| x.powi(7) - 3_f64 * x.powi(2)).collect();
+        let polynomial = Barycentric::new(&x, &y);
+
+        let exact = |a: f64, b: f64| (b.powi(8) - a.powi(8)) / 8_f64 - (b.powi(3) - a.powi(3));
+
+        assert_approx_eq!(polynomial.integrate(-1_f64, 1_f64), -2_f64, 1e-13);
+        assert_approx_eq!(polynomial.integrate(1.5, -0.5), exact(1.5, -0.5), 1e-11);
+        assert_approx_eq!(
+            Functions::simpson(polynomial.as_fn(), -1_f64, 0.5, 600_f64),
+            exact(-1_f64, 0.5),
+            1e-9
+        );
+    }
+
+    #[test]
+    pub fn extrapolation_test() {
+        let x = [0_f64, 1_f64, 2_f64];
+        let y = [1_f64, 2_f64, 5_f64];
+        let parabola = Barycentric::new(&x, &y);
+
+        let linear = parabola.clone().extrapolation(Extrapolation::Linear);
+        let fill = parabola.clone().extrapolation(Extrapolation::Fill(-1_f64));
+
+        assert_approx_eq!(parabola.evaluate(3_f64), 10_f64, 1e-13);
+        assert_approx_eq!(linear.evaluate(3_f64), 9_f64, 1e-13);
+        assert_approx_eq!(linear.integrate(-1_f64, 0_f64), 1_f64, 1e-13);
+        assert_eq!(fill.evaluate(-0.5), -1_f64);
+        assert_eq!(fill.derivative(2.5, 2), -1_f64);
+    }
+}
@@ -0,0 +1,2 @@
+mod barycentric_tests;
+mod piecewise_tests;
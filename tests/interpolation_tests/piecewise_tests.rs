@@ -0,0 +1,157 @@
+use numerilib::interpolate::{Extrapolation, Interpolant, SplineBoundary};
+use numerilib::Functions;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    const X: [f64; 6] = [0_f64, 0.7, 1.5, 2.0, 3.2, 4.0];
+
+    fn cubic(x: f64) -> f64 {
+        2_f64 - x + 0.5 * x * x - 0.3 * x * x * x
+    }
+
+    fn cubic_derivative(x: f64) -> f64 {
+        -1_f64 + x - 0.9 * x * x
+    }
+
+    fn cubic_integral(a: f64, b: f64) -> f64 {
+        let primitive = |x: f64| 2_f64 * x - x * x / 2_f64 + x.powi(3) / 6_f64 - 0.075 * x.powi(4);
+        primitive(b) - primitive(a)
+    }
+
+    #[test]
+    pub fn linear_test() {
+        let x = [0_f64, 1_f64, 2_f64, 4_f64];
+        let y = [1_f64, 3_f64, 2_f64, 6_f64];
+        let interpolant = Interpolant::linear(&x, &y);
+
+        assert_approx_eq!(interpolant.evaluate(0.5), 2_f64, 1e-15);
+        assert_approx_eq!(interpolant.evaluate(3_f64), 4_f64, 1e-15);
+        assert_approx_eq!(interpolant.derivative(1.5, 1), -1_f64, 1e-15);
+        assert_eq!(interpolant.derivative(1.5, 2), 0_f64);
+        assert_approx_eq!(
+            interpolant.integrate(0_f64, 4_f64),
+            2_f64 + 2.5 + 8_f64,
+            1e-14
+        );
+    }
+
+    #[test]
+    pub fn cubic_spline_test() {
+        let y = X.map(cubic);
+        let not_a_knot = Interpolant::cubic_spline(&X, &y, SplineBoundary::NotAKnot);
+        let clamped = Interpolant::cubic_spline(
+            &X,
+            &y,
+            SplineBoundary::Clamped(cubic_derivative(0_f64), cubic_derivative(4_f64)),
+        );
+
+        // Both end conditions reproduce a cubic exactly, also when extrapolating.
+        for spline in [not_a_knot, clamped] {
+            for x in [-1_f64, 0.3, 1.9, 3.7, 5_f64] {
+                assert_approx_eq!(spline.evaluate(x), cubic(x), 1e-12);
+                assert_approx_eq!(spline.derivative(x, 1), cubic_derivative(x), 1e-12);
+                assert_approx_eq!(spline.derivative(x, 3), -1.8, 1e-11);
+            }
+            assert_approx_eq!(
+                spline.integrate(-1_f64, 5_f64),
+                cubic_integral(-1_f64, 5_f64),
+                1e-12
+            );
+            assert_approx_eq!(
+                spline.integrate(4_f64, 0.5),
+                cubic_integral(4_f64, 0.5),
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    pub fn natural_spline_test() {
+        let y = X.map(cubic);
+        let spline = Interpolant::cubic_spline(&X, &y, SplineBoundary::Natural);
+
+        assert_approx_eq!(spline.derivative(0_f64, 2), 0_f64, 1e-13);
+        assert_approx_eq!(spline.derivative(4_f64, 2), 0_f64, 1e-13);
+
+        for (&x, &y) in X.iter().zip(&y) {
+            assert_approx_eq!(spline.evaluate(x), y, 1e-14);
+        }
+
+        // The first and second derivatives are continuous at the interior data points.
+        for &x in &X[1..5] {
+            for order in 1..=2 {
+                let left = spline.derivative(x - 1e-9, order);
+                assert_approx_eq!(spline.derivative(x, order), left, 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    pub fn monotone_test() {
+        let x = [0_f64, 1_f64, 2_f64, 3_f64, 4_f64, 5_f64];
+        let y = [0_f64, 0_f64, 0.1, 5_f64, 5_f64, 5_f64];
+
+        let pchip = Interpolant::pchip(&x, &y);
+        let akima = Interpolant::akima(&x, &y);
+        let spline = Interpolant::cubic_spline(&x, &y, SplineBoundary::Natural);
+
+        let points: Vec<f64> = (0..=500).map(|i| i as f64 / 100_f64).collect();
+        let pchip_values: Vec<f64> = points.iter().map(|&x| pchip.evaluate(x)).collect();
+
+        assert!(pchip_values.windows(2).all(|pair| pair[1] >= pair[0]));
+        assert!(points
+            .iter()
+            .all(|&x| (0_f64..=5_f64).contains(&pchip.evaluate(x))));
+        assert!(points.iter().any(|&x| spline.evaluate(x) < -0.4));
+
+        // Akima's slopes vanish on the flat intervals away from the step.
+        assert_approx_eq!(akima.evaluate(4.5), 5_f64, 1e-15);
+        assert_approx_eq!(akima.evaluate(2.5), 2.5745, 1e-12);
+        assert_approx_eq!(pchip.evaluate(2.5), 2.5745, 1e-12);
+        assert_approx_eq!(akima.evaluate(0.5), -0.006505102040816, 1e-12);
+    }
+
+    #[test]
+    pub fn extrapolation_test() {
+        let x = [0_f64, 1_f64, 2_f64, 3_f64];
+        let y = [0_f64, 1_f64, 4_f64, 9_f64];
+        let spline = Interpolant::cubic_spline(&x, &y, SplineBoundary::NotAKnot);
+
+        let linear = spline.clone().extrapolation(Extrapolation::Linear);
+        let constant = spline.clone().extrapolation(Extrapolation::Constant);
+        let fill = spline.clone().extrapolation(Extrapolation::Fill(f64::NAN));
+
+        assert_approx_eq!(spline.evaluate(4_f64), 16_f64, 1e-12);
+        assert_approx_eq!(linear.evaluate(4_f64), 15_f64, 1e-12);
+        assert_approx_eq!(linear.derivative(-1_f64, 1), 0_f64, 1e-12);
+        assert_approx_eq!(
+            linear.integrate(3_f64, 5_f64),
+            2_f64 * 9_f64 + 2_f64 * 6_f64,
+            1e-12
+        );
+        assert_eq!(constant.evaluate(-2_f64), 0_f64);
+        assert_eq!(constant.derivative(5_f64, 1), 0_f64);
+        assert_approx_eq!(constant.integrate(0_f64, 5_f64), 9_f64 + 18_f64, 1e-12);
+        assert!(fill.evaluate(3.5).is_nan() && fill.evaluate(2.5).is_finite());
+    }
+
+    #[test]
+    pub fn as_fn_test() {
+        let x = [0_f64, 1_f64, 2_f64, 3_f64];
+        let y = [0_f64, 1_f64, 4_f64, 9_f64];
+        let interpolant = Interpolant::pchip(&x, &y);
+
+        let simpson = Functions::simpson(interpolant.as_fn(), 0_f64, 3_f64, 300_f64);
+
+        assert_approx_eq!(simpson, interpolant.integrate(0_f64, 3_f64), 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn unsorted_test() {
+        Interpolant::akima(&[0_f64, 2_f64, 1_f64], &[0_f64, 1_f64, 2_f64]);
+    }
+}
@@ -0,0 +1,65 @@
+use numerilib::Matrix;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn lu_factors_test() {
+        let matrix = Matrix::new([
+            [1_f64, 2_f64, 3_f64],
+            [4_f64, 5_f64, 6_f64],
+            [7_f64, 8_f64, 10_f64],
+        ]);
+
+        let lu = matrix.lu().unwrap();
+        let lower = lu.lower();
+        let upper = lu.upper();
+
+        for (i, &row) in lu.permutation().iter().enumerate() {
+            for j in 0..3 {
+                let product: f64 = (0..3).map(|k| lower[i][k] * upper[k][j]).sum();
+                assert_approx_eq!(matrix[row][j], product, 1e-14);
+            }
+        }
+        assert_eq!(&[2, 0, 1], lu.permutation());
+    }
+
+    #[test]
+    pub fn lu_determinant_test() {
+        let matrix = Matrix::new([
+            [78_f64, 94_f64, 25_f64, 1_f64],
+            [795_f64, 64_f64, 25_f64, 12_f64],
+            [37_f64, 52_f64, 81_f64, 64_f64],
+            [0_f64, 15_f64, 6_f64, 4_f64],
+        ]);
+
+        let determinant = matrix.lu().unwrap().determinant();
+
+        assert_approx_eq!(matrix.determinant(), determinant, 1e-6);
+    }
+
+    #[test]
+    pub fn solve_test() {
+        let matrix = Matrix::new([
+            [2_f64, 1_f64, -1_f64],
+            [-3_f64, -1_f64, 2_f64],
+            [-2_f64, 1_f64, 2_f64],
+        ]);
+
+        let x = matrix.solve(&[8_f64, -11_f64, -3_f64]).unwrap();
+
+        assert_approx_eq!(2_f64, x[0], 1e-14);
+        assert_approx_eq!(3_f64, x[1], 1e-14);
+        assert_approx_eq!(-1_f64, x[2], 1e-14);
+    }
+
+    #[test]
+    pub fn singular_test() {
+        let matrix = Matrix::new([[1_f64, 2_f64], [2_f64, 4_f64]]);
+
+        assert!(matrix.lu().is_none());
+        assert!(matrix.solve(&[1_f64, 2_f64]).is_none());
+    }
+}
@@ -1,2 +1,4 @@
+mod lu_test;
 mod matrix_test1;
 mod matrix_test2;
+mod vector_test;
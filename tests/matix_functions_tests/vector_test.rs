@@ -0,0 +1,43 @@
+use numerilib::Vector;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn vector_test() {
+        let vector = Vector::new([1_f64, 2_f64, 3_f64]);
+
+        assert_approx_eq!(1_f64, vector.get(1));
+        assert_approx_eq!(2_f64, vector.get(2));
+        assert_approx_eq!(3_f64, vector[2]);
+        assert_eq!(3, vector.dimension());
+    }
+
+    #[test]
+    pub fn vector_update_test() {
+        let mut vector = Vector::new([1_f64, 2_f64, 3_f64]);
+
+        vector.update(2, 5_f64);
+        vector[0] = 4_f64;
+
+        assert_eq!(&[4_f64, 5_f64, 3_f64], vector.as_slice());
+    }
+
+    #[test]
+    pub fn vector_dot_test() {
+        let a = Vector::new([1_f64, 2_f64, 3_f64]);
+        let b = Vector::new([4_f64, 5_f64, 6_f64]);
+
+        assert_approx_eq!(32_f64, a.dot(&b));
+    }
+
+    #[test]
+    pub fn vector_norm_test() {
+        let vector = Vector::new([3_f64, 4_f64, 12_f64]);
+
+        assert_approx_eq!(13_f64, vector.norm());
+        assert_eq!("[3, 4, 12]", format!("{:?}", vector));
+    }
+}
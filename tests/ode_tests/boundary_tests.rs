@@ -0,0 +1,165 @@
+use numerilib::ode::{
+    BoundaryCondition, BoundaryValueProblem, BvpError, BvpMethod, BvpSolution, OdeStatus,
+};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn largest_error<E: Fn(f64) -> f64>(solution: &BvpSolution, exact: E) -> f64 {
+        let first = solution.x[0];
+        let last = solution.x[solution.x.len() - 1];
+
+        (0..=50)
+            .map(|i| {
+                let x = first + (last - first) * i as f64 / 50_f64;
+                (solution.evaluate(x) - exact(x)).abs()
+            })
+            .fold(0_f64, f64::max)
+    }
+
+    #[test]
+    pub fn dirichlet_test() {
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| -y,
+            0_f64,
+            FRAC_PI_2,
+            BoundaryCondition::Dirichlet(0_f64),
+            BoundaryCondition::Dirichlet(1_f64),
+        );
+
+        let shooting = problem.solve(BvpMethod::Shooting).unwrap();
+        let multiple = problem.solve(BvpMethod::MultipleShooting(5)).unwrap();
+        let collocation = problem.solve(BvpMethod::FiniteDifference(200)).unwrap();
+
+        assert!(shooting.converged && multiple.converged && collocation.converged);
+        assert!(largest_error(&shooting, f64::sin) < 1e-8);
+        assert!(largest_error(&multiple, f64::sin) < 1e-8);
+        assert!(largest_error(&collocation, f64::sin) < 2e-6);
+        assert_approx_eq!(1_f64, shooting.dy[0], 1e-8);
+    }
+
+    #[test]
+    pub fn neumann_test() {
+        // y = cosh(x) with y'(0) = 0.
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| y,
+            0_f64,
+            1_f64,
+            BoundaryCondition::Neumann(0_f64),
+            BoundaryCondition::Dirichlet(1_f64.cosh()),
+        );
+
+        for (method, tolerance) in [
+            (BvpMethod::Shooting, 1e-8),
+            (BvpMethod::MultipleShooting(3), 1e-8),
+            (BvpMethod::FiniteDifference(200), 1e-6),
+        ] {
+            let solution = problem.solve(method).unwrap();
+
+            assert!(solution.converged);
+            assert!(largest_error(&solution, f64::cosh) < tolerance);
+            assert_approx_eq!(0_f64, solution.dy[0], 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn mixed_test() {
+        // y = cosh(x) with 2y - y' = 2 at 0 and y + y' = e at 1.
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| y,
+            0_f64,
+            1_f64,
+            BoundaryCondition::Mixed {
+                a: 2_f64,
+                b: -1_f64,
+                c: 2_f64,
+            },
+            BoundaryCondition::Mixed {
+                a: 1_f64,
+                b: 1_f64,
+                c: 1_f64.exp(),
+            },
+        );
+
+        for (method, tolerance) in [
+            (BvpMethod::Shooting, 1e-8),
+            (BvpMethod::MultipleShooting(4), 1e-8),
+            (BvpMethod::FiniteDifference(400), 2e-6),
+        ] {
+            let solution = problem.solve(method).unwrap();
+
+            assert!(solution.converged);
+            assert!(largest_error(&solution, f64::cosh) < tolerance);
+        }
+    }
+
+    #[test]
+    pub fn bratu_test() {
+        // y'' = -exp(y) with y(0) = y(1) = 0, whose lower solution is
+        // y = -2 ln(cosh((x - 1/2) θ/2) / cosh(θ/4)) with θ = √2 cosh(θ/4).
+        let theta = 1.5171645990507543;
+        let exact =
+            |x: f64| -2_f64 * (((x - 0.5) * theta / 2_f64).cosh() / (theta / 4_f64).cosh()).ln();
+
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| -y.exp(),
+            0_f64,
+            1_f64,
+            BoundaryCondition::Dirichlet(0_f64),
+            BoundaryCondition::Dirichlet(0_f64),
+        );
+
+        let shooting = problem.solve(BvpMethod::Shooting).unwrap();
+        let coarse = problem.solve(BvpMethod::FiniteDifference(50)).unwrap();
+        let fine = problem.solve(BvpMethod::FiniteDifference(100)).unwrap();
+
+        assert!(largest_error(&shooting, exact) < 1e-8);
+        assert_approx_eq!(exact(0.5), shooting.evaluate(0.5), 1e-9);
+
+        // The finite differences are second order accurate.
+        let ratio = (coarse.evaluate(0.5) - exact(0.5)) / (fine.evaluate(0.5) - exact(0.5));
+        assert_approx_eq!(4_f64, ratio, 0.05);
+    }
+
+    #[test]
+    pub fn troesch_test() {
+        // Troesch's problem y'' = λ sinh(λy) with λ = 5, where single shooting overflows.
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| 5_f64 * (5_f64 * y).sinh(),
+            0_f64,
+            1_f64,
+            BoundaryCondition::Dirichlet(0_f64),
+            BoundaryCondition::Dirichlet(1_f64),
+        );
+
+        assert_eq!(
+            Err(BvpError::IntegrationFailed {
+                status: OdeStatus::StepSizeTooSmall
+            }),
+            problem.solve(BvpMethod::Shooting)
+        );
+
+        let solution = problem.solve(BvpMethod::MultipleShooting(20)).unwrap();
+
+        assert!(solution.converged);
+        assert_approx_eq!(0.0457504614, solution.dy[0], 1e-9);
+        assert_approx_eq!(1_f64, *solution.y.last().unwrap(), 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn intervals_test() {
+        let problem = BoundaryValueProblem::new(
+            |_x: f64, y: f64, _dy: f64| -y,
+            0_f64,
+            1_f64,
+            BoundaryCondition::Dirichlet(0_f64),
+            BoundaryCondition::Dirichlet(1_f64),
+        );
+
+        let _ = problem.solve(BvpMethod::FiniteDifference(1));
+    }
+}
@@ -0,0 +1,138 @@
+use numerilib::ode::{
+    Event, EventDirection, InitialValueProblem, OdeMethod, OdeOptions, OdeStatus,
+};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use std::f64::consts::PI;
+
+    fn oscillator(_t: f64, y: &[f64]) -> Vec<f64> {
+        vec![y[1], -y[0]]
+    }
+
+    #[test]
+    pub fn dense_output_test() {
+        for method in [
+            OdeMethod::DormandPrince,
+            OdeMethod::Tsit5,
+            OdeMethod::Rk4(0.01),
+        ] {
+            let solution = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+                .options(OdeOptions {
+                    absolute: 1e-12,
+                    relative: 1e-9,
+                    ..OdeOptions::default()
+                })
+                .solve(method);
+
+            for i in 0..=100 {
+                let t = i as f64 / 10_f64;
+                let y = solution.evaluate(t);
+
+                assert_approx_eq!(t.cos(), y[0], 1e-7);
+                assert_approx_eq!(-t.sin(), y[1], 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    pub fn terminal_event_test() {
+        let fall = |_t: f64, y: &[f64]| vec![y[1], -9.81];
+        let ground = Event::new(|_t: f64, y: &[f64]| y[0])
+            .direction(EventDirection::Falling)
+            .terminal(true);
+
+        let solution = InitialValueProblem::new(fall, 0_f64, &[10_f64, 0_f64], 5_f64)
+            .event(ground)
+            .solve(OdeMethod::Tsit5);
+
+        let impact = (20_f64 / 9.81).sqrt();
+
+        assert_eq!(OdeStatus::Terminated, solution.status);
+        assert_eq!(1, solution.events.len());
+        assert_approx_eq!(impact, solution.events[0].t, 1e-12);
+        assert_approx_eq!(impact, *solution.t.last().unwrap(), 1e-12);
+        assert_approx_eq!(-9.81 * impact, solution.events[0].y[1], 1e-10);
+    }
+
+    #[test]
+    pub fn event_direction_test() {
+        let crossing = |_t: f64, y: &[f64]| y[0];
+
+        let solution = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .event(Event::new(crossing))
+            .event(Event::new(crossing).direction(EventDirection::Rising))
+            .solve(OdeMethod::DormandPrince);
+
+        let both: Vec<f64> = solution
+            .events
+            .iter()
+            .filter(|event| event.index == 0)
+            .map(|event| event.t)
+            .collect();
+        let rising: Vec<f64> = solution
+            .events
+            .iter()
+            .filter(|event| event.index == 1)
+            .map(|event| event.t)
+            .collect();
+
+        assert_eq!(3, both.len());
+        for (k, t) in both.iter().enumerate() {
+            assert_approx_eq!((k as f64 + 0.5) * PI, t, 1e-5);
+        }
+        assert_eq!(1, rising.len());
+        assert_approx_eq!(1.5 * PI, rising[0], 1e-5);
+        assert_eq!(OdeStatus::Success, solution.status);
+    }
+
+    #[test]
+    pub fn event_at_step_boundary_test() {
+        for method in [
+            OdeMethod::Rk4(0.1),
+            OdeMethod::DormandPrince,
+            OdeMethod::RosenbrockW,
+        ] {
+            let steps =
+                InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 1.4_f64).solve(method);
+
+            // Every event function vanishes exactly at one of the accepted steps.
+            for k in 1..steps.t.len() - 1 {
+                let level = steps.y[k][0];
+                let solution =
+                    InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 1.4_f64)
+                        .event(
+                            Event::new(move |_t: f64, y: &[f64]| y[0] - level)
+                                .direction(EventDirection::Falling),
+                        )
+                        .solve(method);
+
+                assert_eq!(1, solution.events.len());
+                assert_approx_eq!(steps.t[k], solution.events[0].t, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    pub fn callback_test() {
+        let mut times = Vec::new();
+
+        let solution = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .callback(|t: f64, _y: &[f64]| times.push(t))
+            .solve(OdeMethod::Tsit5);
+
+        assert_eq!(solution.steps, times.len());
+        assert_eq!(&solution.t[1..], &times[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn evaluate_outside_test() {
+        let solution = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 1_f64)
+            .solve(OdeMethod::Tsit5);
+
+        solution.evaluate(2_f64);
+    }
+}
@@ -0,0 +1,4 @@
+mod boundary_tests;
+mod events_tests;
+mod runge_kutta_tests;
+mod stiff_tests;
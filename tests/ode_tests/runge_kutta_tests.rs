@@ -0,0 +1,101 @@
+use numerilib::ode::{InitialValueProblem, OdeMethod, OdeOptions, OdeStatus};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn oscillator(_t: f64, y: &[f64]) -> Vec<f64> {
+        vec![y[1], -y[0]]
+    }
+
+    fn tight() -> OdeOptions {
+        OdeOptions {
+            absolute: 1e-13,
+            relative: 1e-10,
+            ..OdeOptions::default()
+        }
+    }
+
+    #[test]
+    pub fn rk4_test() {
+        let coarse = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .solve(OdeMethod::Rk4(0.1));
+        let fine = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .solve(OdeMethod::Rk4(0.05));
+
+        let coarse_error = (coarse.y[100][0] - 10_f64.cos()).abs();
+        let fine_error = (fine.y[200][0] - 10_f64.cos()).abs();
+
+        assert_eq!(100, coarse.steps);
+        assert_eq!(401, coarse.evaluations);
+        assert_approx_eq!(10_f64, coarse.t[100]);
+        assert!(coarse_error < 1e-5);
+        assert!(coarse_error / fine_error > 14_f64);
+    }
+
+    #[test]
+    pub fn dormand_prince_test() {
+        let solution = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .options(tight())
+            .solve(OdeMethod::DormandPrince);
+        let last = solution.y.last().unwrap();
+
+        assert_eq!(OdeStatus::Success, solution.status);
+        assert_approx_eq!(10_f64.cos(), last[0], 1e-9);
+        assert_approx_eq!(-10_f64.sin(), last[1], 1e-9);
+        assert_eq!(solution.t.len(), solution.steps + 1);
+    }
+
+    #[test]
+    pub fn tsit5_test() {
+        let lotka_volterra =
+            |_t: f64, y: &[f64]| vec![1.5 * y[0] - y[0] * y[1], -3_f64 * y[1] + y[0] * y[1]];
+
+        let tsit5 = InitialValueProblem::new(lotka_volterra, 0_f64, &[1_f64, 1_f64], 10_f64)
+            .options(tight())
+            .solve(OdeMethod::Tsit5);
+        let reference = InitialValueProblem::new(lotka_volterra, 0_f64, &[1_f64, 1_f64], 10_f64)
+            .solve(OdeMethod::Rk4(1e-4));
+
+        let last = tsit5.y.last().unwrap();
+        let expected = reference.y.last().unwrap();
+
+        assert_eq!(OdeStatus::Success, tsit5.status);
+        assert_approx_eq!(expected[0], last[0], 1e-8);
+        assert_approx_eq!(expected[1], last[1], 1e-8);
+    }
+
+    #[test]
+    pub fn backward_test() {
+        let solution =
+            InitialValueProblem::new(|_t: f64, y: &[f64]| vec![-y[0]], 2_f64, &[1_f64], 0_f64)
+                .options(tight())
+                .solve(OdeMethod::Tsit5);
+
+        assert_approx_eq!(0_f64, *solution.t.last().unwrap());
+        assert_approx_eq!(2_f64.exp(), solution.y.last().unwrap()[0], 1e-8);
+    }
+
+    #[test]
+    pub fn status_test() {
+        let blow_up = InitialValueProblem::new(
+            |_t: f64, y: &[f64]| vec![y[0] * y[0]],
+            0_f64,
+            &[1_f64],
+            2_f64,
+        )
+        .solve(OdeMethod::DormandPrince);
+        let limited = InitialValueProblem::new(oscillator, 0_f64, &[1_f64, 0_f64], 10_f64)
+            .options(OdeOptions {
+                max_steps: 5,
+                ..OdeOptions::default()
+            })
+            .solve(OdeMethod::DormandPrince);
+
+        assert_eq!(OdeStatus::StepSizeTooSmall, blow_up.status);
+        assert_approx_eq!(1_f64, *blow_up.t.last().unwrap(), 1e-4);
+        assert_eq!(OdeStatus::MaxSteps, limited.status);
+        assert_eq!(5, limited.steps);
+    }
+}
@@ -0,0 +1,139 @@
+use numerilib::ode::{InitialValueProblem, OdeMethod, OdeOptions, OdeStatus};
+use numerilib::Matrix;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Robertson's chemical kinetics, the classical stiff test problem.
+    fn robertson(_t: f64, y: &[f64]) -> Vec<f64> {
+        vec![
+            -0.04 * y[0] + 1e4 * y[1] * y[2],
+            0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1] * y[1],
+            3e7 * y[1] * y[1],
+        ]
+    }
+
+    fn van_der_pol(_t: f64, y: &[f64]) -> Vec<f64> {
+        vec![y[1], 1000_f64 * (1_f64 - y[0] * y[0]) * y[1] - y[0]]
+    }
+
+    fn tight() -> OdeOptions {
+        OdeOptions {
+            absolute: 1e-12,
+            relative: 1e-8,
+            ..OdeOptions::default()
+        }
+    }
+
+    #[test]
+    pub fn robertson_test() {
+        for method in [OdeMethod::Bdf, OdeMethod::RosenbrockW] {
+            let solution =
+                InitialValueProblem::new(robertson, 0_f64, &[1_f64, 0_f64, 0_f64], 40_f64)
+                    .options(tight())
+                    .solve(method);
+            let y = solution.y.last().unwrap();
+
+            assert_eq!(OdeStatus::Success, solution.status);
+            assert_approx_eq!(0.715827068719, y[0], 1e-7);
+            assert_approx_eq!(9.18553476e-6, y[1], 1e-11);
+            assert_approx_eq!(0.284163745725, y[2], 1e-7);
+        }
+    }
+
+    #[test]
+    pub fn efficiency_test() {
+        let solve = |method: OdeMethod| {
+            InitialValueProblem::new(robertson, 0_f64, &[1_f64, 0_f64, 0_f64], 40_f64)
+                .options(tight())
+                .solve(method)
+        };
+
+        let explicit = solve(OdeMethod::DormandPrince);
+        let bdf = solve(OdeMethod::Bdf);
+
+        assert!(bdf.steps * 20 < explicit.steps);
+        assert!(bdf.evaluations * 20 < explicit.evaluations);
+    }
+
+    #[test]
+    pub fn dense_output_test() {
+        // y = cos(t) + exp(-1000t) with a fast transient.
+        let stiff = |t: f64, y: &[f64]| vec![-1000_f64 * (y[0] - t.cos()) - t.sin()];
+
+        for method in [OdeMethod::Bdf, OdeMethod::RosenbrockW] {
+            let solution = InitialValueProblem::new(stiff, 0_f64, &[2_f64], 2_f64).solve(method);
+
+            for i in 1..=20 {
+                let t = i as f64 / 10_f64;
+                assert_approx_eq!(
+                    t.cos() + (-1000_f64 * t).exp(),
+                    solution.evaluate(t)[0],
+                    1e-5
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn jacobian_test() {
+        let jacobian = |_t: f64, y: &[f64]| {
+            Matrix::new([
+                [0_f64, 1_f64],
+                [
+                    -2000_f64 * y[0] * y[1] - 1_f64,
+                    1000_f64 * (1_f64 - y[0] * y[0]),
+                ],
+            ])
+        };
+
+        for method in [OdeMethod::Bdf, OdeMethod::RosenbrockW] {
+            let numerical = InitialValueProblem::new(van_der_pol, 0_f64, &[2_f64, 0_f64], 3000_f64)
+                .options(tight())
+                .solve(method);
+            let analytic = InitialValueProblem::new(van_der_pol, 0_f64, &[2_f64, 0_f64], 3000_f64)
+                .options(tight())
+                .jacobian(jacobian)
+                .solve(method);
+
+            assert_eq!(OdeStatus::Success, analytic.status);
+            assert!(analytic.evaluations < numerical.evaluations);
+            assert_approx_eq!(-1.51060, analytic.y.last().unwrap()[0], 1e-4);
+            assert_approx_eq!(
+                numerical.y.last().unwrap()[0],
+                analytic.y.last().unwrap()[0],
+                1e-4
+            );
+        }
+    }
+
+    #[test]
+    pub fn auto_test() {
+        // Only stiff while t is between 5 and 10.
+        let switching = |t: f64, y: &[f64]| {
+            let rate = if t > 5_f64 && t < 10_f64 { 1e5 } else { 1_f64 };
+            vec![-rate * (y[0] - t.cos())]
+        };
+
+        let solution =
+            InitialValueProblem::new(switching, 0_f64, &[1_f64], 15_f64).solve(OdeMethod::Auto);
+
+        assert_eq!(OdeStatus::Success, solution.status);
+        assert_eq!(2, solution.switches.len());
+        assert_eq!(OdeMethod::Bdf, solution.switches[0].1);
+        assert_eq!(OdeMethod::DormandPrince, solution.switches[1].1);
+        assert!(solution.switches[0].0 > 5_f64 && solution.switches[0].0 < 6_f64);
+        assert!(solution.switches[1].0 > 10_f64 && solution.switches[1].0 < 11_f64);
+        assert!(solution.steps < 1000);
+        assert_approx_eq!(-0.05569405339075349, solution.y.last().unwrap()[0], 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn jacobian_size_test() {
+        let _ = InitialValueProblem::new(van_der_pol, 0_f64, &[2_f64, 0_f64], 1_f64)
+            .jacobian(|_t: f64, _y: &[f64]| Matrix::new([[1_f64]]));
+    }
+}
@@ -0,0 +1,81 @@
+use numerilib::optimize::{Minimizer, OptimizeMethod};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn rosenbrock(x: &[f64]) -> f64 {
+        (1_f64 - x[0]).powi(2) + 100_f64 * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    #[test]
+    pub fn corner_test() {
+        let function = |x: &[f64]| (x[0] - 3_f64).powi(2) + (x[1] + 1_f64).powi(2) + x[0] * x[1];
+
+        let result = Minimizer::new(function)
+            .bounds(&[0_f64, 0_f64], &[2_f64, 2_f64])
+            .minimize(OptimizeMethod::ProjectedGradient, &[1_f64, 1_f64])
+            .unwrap();
+
+        // The gradient points out of the box at the minimizing corner.
+        assert!(result.converged);
+        assert_eq!(result.argmin, vec![2_f64, 0_f64]);
+        assert!(result.gradient[0] < 0_f64 && result.gradient[1] > 0_f64);
+    }
+
+    #[test]
+    pub fn active_bound_test() {
+        let result = Minimizer::new(rosenbrock)
+            .bounds(&[-2_f64, -2_f64], &[0.5, 2_f64])
+            .minimize(OptimizeMethod::ProjectedGradient, &[-1.2_f64, 1_f64])
+            .unwrap();
+
+        assert!(result.converged);
+        assert_eq!(result.argmin[0], 0.5);
+        assert_approx_eq!(result.argmin[1], 0.25, 1e-8);
+        assert_approx_eq!(result.minimum, 0.25, 1e-12);
+        assert_approx_eq!(result.gradient[1], 0_f64, 1e-8);
+    }
+
+    #[test]
+    pub fn inactive_bounds_test() {
+        let bounded = Minimizer::new(rosenbrock)
+            .bounds(&[f64::NEG_INFINITY, -5_f64], &[5_f64, f64::INFINITY])
+            .minimize(OptimizeMethod::ProjectedGradient, &[-1.2_f64, 1_f64])
+            .unwrap();
+        let free = Minimizer::new(rosenbrock)
+            .minimize(OptimizeMethod::ProjectedGradient, &[-1.2_f64, 1_f64])
+            .unwrap();
+
+        for result in [bounded, free] {
+            assert!(result.converged);
+            assert_approx_eq!(result.argmin[0], 1_f64, 1e-6);
+            assert_approx_eq!(result.argmin[1], 1_f64, 1e-6);
+        }
+    }
+
+    #[test]
+    pub fn infeasible_guess_test() {
+        // The guess is projected onto the box, where the logarithm is defined.
+        let function = |x: &[f64]| x[0] - x[0].ln() + x[1] * x[1];
+
+        let result = Minimizer::new(function)
+            .gradient(|x: &[f64]| vec![1_f64 - 1_f64 / x[0], 2_f64 * x[1]])
+            .bounds(&[0.1, -1_f64], &[10_f64, 1_f64])
+            .minimize(OptimizeMethod::ProjectedGradient, &[-3_f64, 4_f64])
+            .unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(result.argmin[0], 1_f64, 1e-8);
+        assert_approx_eq!(result.argmin[1], 0_f64, 1e-8);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn unsupported_method_test() {
+        let _ = Minimizer::new(rosenbrock)
+            .bounds(&[0_f64, 0_f64], &[2_f64, 2_f64])
+            .minimize(OptimizeMethod::Bfgs, &[1_f64, 1_f64]);
+    }
+}
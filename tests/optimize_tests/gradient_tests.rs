@@ -0,0 +1,185 @@
+use numerilib::optimize::{Minimizer, OptimizeError, OptimizeMethod, OptimizeTolerance};
+use numerilib::stats::distr::Gaussian;
+use numerilib::Stencil;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    const METHODS: [OptimizeMethod; 3] = [
+        OptimizeMethod::Bfgs,
+        OptimizeMethod::Lbfgs(5),
+        OptimizeMethod::ConjugateGradient,
+    ];
+
+    fn rosenbrock(x: &[f64]) -> f64 {
+        (1_f64 - x[0]).powi(2) + 100_f64 * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    fn rosenbrock_gradient(x: &[f64]) -> Vec<f64> {
+        vec![
+            -2_f64 * (1_f64 - x[0]) - 400_f64 * x[0] * (x[1] - x[0] * x[0]),
+            200_f64 * (x[1] - x[0] * x[0]),
+        ]
+    }
+
+    #[test]
+    pub fn rosenbrock_test() {
+        let minimizer = Minimizer::new(rosenbrock).gradient(rosenbrock_gradient);
+
+        for method in METHODS {
+            let result = minimizer.minimize(method, &[-1.2_f64, 1_f64]).unwrap();
+
+            assert!(result.converged);
+            assert_approx_eq!(result.argmin[0], 1_f64, 1e-8);
+            assert_approx_eq!(result.argmin[1], 1_f64, 1e-8);
+            assert!(result.minimum < 1e-16);
+            assert!(result.gradient.iter().all(|g| g.abs() <= 1e-8));
+            assert!(result.iterations < 100);
+        }
+    }
+
+    #[test]
+    pub fn numerical_gradient_test() {
+        let analytic = Minimizer::new(rosenbrock).gradient(rosenbrock_gradient);
+        let numerical = Minimizer::new(rosenbrock).stencil(Stencil::Central);
+
+        for method in METHODS {
+            let exact = analytic.minimize(method, &[-1.2_f64, 1_f64]).unwrap();
+            let approximate = numerical.minimize(method, &[-1.2_f64, 1_f64]).unwrap();
+
+            assert_approx_eq!(approximate.argmin[0], 1_f64, 1e-6);
+            assert_approx_eq!(approximate.argmin[1], 1_f64, 1e-6);
+            // Every central difference gradient costs two evaluations per variable.
+            assert!(
+                approximate.evaluations >= exact.evaluations + 4 * approximate.gradient_evaluations
+            );
+        }
+    }
+
+    #[test]
+    pub fn extended_rosenbrock_test() {
+        let function = |x: &[f64]| {
+            x.windows(2)
+                .map(|pair| {
+                    100_f64 * (pair[1] - pair[0] * pair[0]).powi(2) + (1_f64 - pair[0]).powi(2)
+                })
+                .sum::<f64>()
+        };
+
+        // The truncation error of the central differences keeps the gradient above 1e-8 at the minimum.
+        let tolerance = OptimizeTolerance {
+            gradient: 1e-6,
+            ..OptimizeTolerance::default()
+        };
+
+        for method in METHODS {
+            let result = Minimizer::new(function)
+                .tolerance(tolerance)
+                .minimize(method, &[-1.2_f64; 10])
+                .unwrap();
+
+            assert!(result.converged);
+            assert!(result.argmin.iter().all(|x| (x - 1_f64).abs() < 1e-6));
+        }
+    }
+
+    #[test]
+    pub fn quadratic_test() {
+        // An ill-conditioned quadratic, where steepest descent would need thousands of iterations.
+        let scales = [1_f64, 10_f64, 100_f64, 1000_f64];
+        let function = |x: &[f64]| {
+            x.iter()
+                .zip(scales)
+                .map(|(x, scale)| scale * (x - 1_f64).powi(2))
+                .sum::<f64>()
+        };
+        let gradient = |x: &[f64]| {
+            x.iter()
+                .zip(scales)
+                .map(|(x, scale)| 2_f64 * scale * (x - 1_f64))
+                .collect()
+        };
+
+        let result = Minimizer::new(function)
+            .gradient(gradient)
+            .minimize(OptimizeMethod::Bfgs, &[0_f64; 4])
+            .unwrap();
+
+        assert!(result.converged);
+        assert!(result.iterations <= 30);
+        assert!(result.argmin.iter().all(|x| (x - 1_f64).abs() < 1e-8));
+    }
+
+    #[test]
+    pub fn gaussian_fit_test() {
+        let data = [4.9_f64, 5.3, 6.1, 4.4, 5.8, 5.0, 6.4, 4.7];
+        let likelihood = |p: &[f64]| {
+            -data
+                .iter()
+                .map(|&x| Gaussian::pdf(x, p[0], p[1]).ln())
+                .sum::<f64>()
+        };
+
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        let sd = (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / data.len() as f64).sqrt();
+
+        for method in METHODS {
+            let result = Minimizer::new(likelihood)
+                .minimize(method, &[4_f64, 2_f64])
+                .unwrap();
+
+            assert!(result.converged);
+            assert_approx_eq!(result.argmin[0], mean, 1e-7);
+            assert_approx_eq!(result.argmin[1], sd, 1e-7);
+        }
+    }
+
+    #[test]
+    pub fn tolerance_test() {
+        let result = Minimizer::new(rosenbrock)
+            .gradient(rosenbrock_gradient)
+            .tolerance(OptimizeTolerance {
+                max_iterations: 5,
+                ..OptimizeTolerance::default()
+            })
+            .minimize(OptimizeMethod::Bfgs, &[-1.2_f64, 1_f64])
+            .unwrap();
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 5);
+        assert!(result.minimum < rosenbrock(&[-1.2_f64, 1_f64]));
+    }
+
+    #[test]
+    pub fn step_tolerance_test() {
+        // Every step is below the step tolerance, which stops the methods far from the minimum.
+        let minimizer = Minimizer::new(rosenbrock)
+            .gradient(rosenbrock_gradient)
+            .tolerance(OptimizeTolerance {
+                step: 10_f64,
+                ..OptimizeTolerance::default()
+            });
+
+        for method in METHODS {
+            let result = minimizer.minimize(method, &[-1.2_f64, 1_f64]).unwrap();
+
+            assert!(!result.converged);
+            assert_eq!(result.iterations, 1);
+        }
+    }
+
+    #[test]
+    pub fn non_finite_test() {
+        let result = Minimizer::new(|x: &[f64]| x[0].ln() + x[1])
+            .minimize(OptimizeMethod::Bfgs, &[-1_f64, 0_f64]);
+
+        assert_eq!(
+            result,
+            Err(OptimizeError::NonFiniteValue {
+                x: vec![-1_f64, 0_f64]
+            })
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use numerilib::optimize::{CurveFit, OptimizeError, OptimizeTolerance};
+use numerilib::stats::distr::Chi2;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    const X: [f64; 9] = [0_f64, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0];
+    const Y: [f64; 9] = [5.02_f64, 3.85, 3.07, 2.49, 2.13, 1.85, 1.68, 1.58, 1.49];
+
+    fn decay(x: f64, p: &[f64]) -> f64 {
+        p[0] * (-p[1] * x).exp() + p[2]
+    }
+
+    #[test]
+    pub fn linear_regression_test() {
+        let x = [1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64];
+        let y = [2.1_f64, 3.9, 6.2, 7.8, 10.1, 12.2];
+
+        let fit = CurveFit::new(|x: f64, p: &[f64]| p[0] + p[1] * x, &x, &y)
+            .fit(&[0_f64, 0_f64])
+            .unwrap();
+
+        // The closed form of ordinary least squares.
+        let mean_x = x.iter().sum::<f64>() / 6_f64;
+        let sxx: f64 = x.iter().map(|x| (x - mean_x).powi(2)).sum();
+        let slope = x.iter().zip(y).map(|(x, y)| (x - mean_x) * y).sum::<f64>() / sxx;
+        let intercept = y.iter().sum::<f64>() / 6_f64 - slope * mean_x;
+        let variance = x
+            .iter()
+            .zip(y)
+            .map(|(x, y)| (y - intercept - slope * x).powi(2))
+            .sum::<f64>()
+            / 4_f64;
+
+        assert!(fit.converged);
+        assert_eq!(fit.degrees_of_freedom, 4);
+        assert_approx_eq!(fit.parameters[0], intercept, 1e-8);
+        assert_approx_eq!(fit.parameters[1], slope, 1e-8);
+        assert_approx_eq!(fit.reduced_chi_squared, variance, 1e-12);
+        assert_approx_eq!(fit.standard_errors[1], (variance / sxx).sqrt(), 1e-10);
+        assert_approx_eq!(
+            fit.standard_errors[0],
+            (variance * (1_f64 / 6_f64 + mean_x * mean_x / sxx)).sqrt(),
+            1e-10
+        );
+        assert_approx_eq!(fit.covariance[0][1], fit.covariance[1][0], 1e-14);
+        assert_approx_eq!(fit.covariance[0][1], -variance * mean_x / sxx, 1e-10);
+    }
+
+    #[test]
+    pub fn exponential_test() {
+        let numerical = CurveFit::new(decay, &X, &Y)
+            .fit(&[1_f64, 1_f64, 0_f64])
+            .unwrap();
+        let analytic = CurveFit::new(decay, &X, &Y)
+            .jacobian(|x: f64, p: &[f64]| {
+                let exponential = (-p[1] * x).exp();
+                vec![exponential, -p[0] * x * exponential, 1_f64]
+            })
+            .fit(&[1_f64, 1_f64, 0_f64])
+            .unwrap();
+
+        assert!(numerical.converged && analytic.converged);
+        assert!(analytic.evaluations < numerical.evaluations);
+
+        for j in 0..3 {
+            assert_approx_eq!(numerical.parameters[j], analytic.parameters[j], 1e-7);
+            assert_approx_eq!(
+                numerical.standard_errors[j],
+                analytic.standard_errors[j],
+                1e-7
+            );
+        }
+
+        let chi_squared: f64 = analytic.residuals.iter().map(|r| r * r).sum();
+        assert_approx_eq!(analytic.chi_squared, chi_squared, 1e-15);
+        assert!(analytic.residuals.iter().all(|r| r.abs() < 0.02));
+    }
+
+    #[test]
+    pub fn zero_tolerance_test() {
+        // χ² stops decreasing long before the iterations run out, which must end the fit.
+        let fit = CurveFit::new(decay, &X, &Y)
+            .tolerance(OptimizeTolerance {
+                gradient: 0_f64,
+                step: 0_f64,
+                function: 0_f64,
+                max_iterations: 200,
+            })
+            .fit(&[1_f64, 1_f64, 0_f64])
+            .unwrap();
+
+        assert!(!fit.converged);
+        assert!(fit.iterations < 200);
+        assert_approx_eq!(fit.parameters[1], 0.755277591848403, 1e-7);
+    }
+
+    #[test]
+    pub fn stalled_test() {
+        // The model is not finite below p = 1, which hides the minimum at p ≈ 0.67.
+        let fit = CurveFit::new(
+            |x: f64, p: &[f64]| if p[0] < 1_f64 { f64::NAN } else { p[0] * x },
+            &X,
+            &Y,
+        )
+        .jacobian(|x: f64, _p: &[f64]| vec![x])
+        .fit(&[2_f64])
+        .unwrap();
+
+        assert!(!fit.converged);
+        assert_approx_eq!(fit.parameters[0], 1_f64, 1e-8);
+    }
+
+    #[test]
+    pub fn sigma_test() {
+        let x = [0_f64, 1_f64, 2_f64, 3_f64, 4_f64];
+        let y = [2.1_f64, 1.2, 0.75, 0.45, 0.3];
+        let model = |x: f64, p: &[f64]| p[0] * (-p[1] * x).exp();
+
+        let unweighted = CurveFit::new(model, &x, &y).fit(&[1_f64, 1_f64]).unwrap();
+        let weighted = CurveFit::new(model, &x, &y)
+            .sigma(&[0.1_f64; 5])
+            .fit(&[1_f64, 1_f64])
+            .unwrap();
+
+        // Equal uncertainties do not move the parameters, but make the covariance absolute.
+        assert_approx_eq!(weighted.parameters[0], unweighted.parameters[0], 1e-8);
+        assert_approx_eq!(
+            weighted.chi_squared,
+            100_f64 * unweighted.chi_squared,
+            1e-10
+        );
+        assert_approx_eq!(
+            weighted.standard_errors[1],
+            unweighted.standard_errors[1] * 0.1 / unweighted.reduced_chi_squared.sqrt(),
+            1e-10
+        );
+
+        assert_approx_eq!(weighted.p_value(), 0.949586135649366, 1e-8);
+        assert_approx_eq!(
+            weighted.p_value(),
+            1_f64 - Chi2::cdf(weighted.chi_squared, 3_f64),
+            1e-15
+        );
+    }
+
+    #[test]
+    pub fn insufficient_data_test() {
+        let fit = CurveFit::new(decay, &X[..3], &Y[..3]).fit(&[1_f64, 1_f64, 0_f64]);
+
+        assert_eq!(
+            fit,
+            Err(OptimizeError::InsufficientData {
+                points: 3,
+                parameters: 3
+            })
+        );
+    }
+
+    #[test]
+    pub fn singular_covariance_test() {
+        // Only the product of the parameters can be determined.
+        let fit = CurveFit::new(|x: f64, p: &[f64]| p[0] * p[1] * x, &X, &Y).fit(&[1_f64, 1_f64]);
+
+        assert!(matches!(fit, Err(OptimizeError::SingularCovariance { .. })));
+    }
+
+    #[test]
+    pub fn non_finite_test() {
+        let fit = CurveFit::new(|x: f64, p: &[f64]| (p[0] * x).ln(), &X, &Y).fit(&[-1_f64]);
+
+        assert_eq!(fit, Err(OptimizeError::NonFiniteValue { x: vec![-1_f64] }));
+    }
+}
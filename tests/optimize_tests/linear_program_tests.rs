@@ -0,0 +1,116 @@
+use numerilib::optimize::{LinearProgram, OptimizeError, Relation};
+use numerilib::Matrix;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn maximize_test() {
+        let solution = LinearProgram::new(&[-3_f64, -5_f64])
+            .constraint(&[1_f64, 0_f64], Relation::LessEqual, 4_f64)
+            .constraint(&[0_f64, 2_f64], Relation::LessEqual, 12_f64)
+            .constraint(&[3_f64, 2_f64], Relation::LessEqual, 18_f64)
+            .solve()
+            .unwrap();
+
+        assert_approx_eq!(solution.objective, -36_f64, 1e-12);
+        assert_approx_eq!(solution.x[0], 2_f64, 1e-12);
+        assert_approx_eq!(solution.x[1], 6_f64, 1e-12);
+    }
+
+    #[test]
+    pub fn greater_equal_test() {
+        // The diet problem: the cheapest mix of two foods that meets three minimum requirements.
+        let solution = LinearProgram::new(&[0.6_f64, 0.35_f64])
+            .constraint(&[5_f64, 7_f64], Relation::GreaterEqual, 8_f64)
+            .constraint(&[4_f64, 2_f64], Relation::GreaterEqual, 15_f64)
+            .constraint(&[2_f64, 1_f64], Relation::GreaterEqual, 3_f64)
+            .solve()
+            .unwrap();
+
+        assert_approx_eq!(solution.objective, 2.25, 1e-12);
+        assert_approx_eq!(solution.x[0], 3.75, 1e-12);
+        assert_approx_eq!(solution.x[1], 0_f64, 1e-12);
+    }
+
+    #[test]
+    pub fn standard_form_test() {
+        // The third equation is the sum of the first two.
+        let a = Matrix::new([
+            [1_f64, 1_f64, 1_f64, 0_f64],
+            [1_f64, -1_f64, 0_f64, 1_f64],
+            [2_f64, 0_f64, 1_f64, 1_f64],
+        ]);
+
+        let solution =
+            LinearProgram::standard(&[-1_f64, -2_f64, 0_f64, 0_f64], &a, &[4_f64, 2_f64, 6_f64])
+                .solve()
+                .unwrap();
+
+        assert_approx_eq!(solution.objective, -8_f64, 1e-12);
+        for (x, expected) in solution.x.iter().zip([0_f64, 4_f64, 0_f64, 6_f64]) {
+            assert_approx_eq!(x, expected, 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn degenerate_test() {
+        // Beale's example, where the textbook pivoting rule cycles forever.
+        let solution = LinearProgram::new(&[-0.75_f64, 20_f64, -0.5_f64, 6_f64])
+            .constraint(
+                &[0.25_f64, -8_f64, -1_f64, 9_f64],
+                Relation::LessEqual,
+                0_f64,
+            )
+            .constraint(
+                &[0.5_f64, -12_f64, -0.5_f64, 3_f64],
+                Relation::LessEqual,
+                0_f64,
+            )
+            .constraint(&[0_f64, 0_f64, 1_f64, 0_f64], Relation::LessEqual, 1_f64)
+            .solve()
+            .unwrap();
+
+        assert_approx_eq!(solution.objective, -1.25, 1e-12);
+        assert_approx_eq!(solution.x[0], 1_f64, 1e-12);
+        assert_approx_eq!(solution.x[2], 1_f64, 1e-12);
+    }
+
+    #[test]
+    pub fn negative_rhs_test() {
+        let solution = LinearProgram::new(&[1_f64, 2_f64])
+            .constraint(&[1_f64, 1_f64], Relation::GreaterEqual, -3_f64)
+            .constraint(&[-1_f64, 1_f64], Relation::LessEqual, -1_f64)
+            .solve()
+            .unwrap();
+
+        assert_approx_eq!(solution.objective, 1_f64, 1e-12);
+        assert_approx_eq!(solution.x[0], 1_f64, 1e-12);
+        assert_approx_eq!(solution.x[1], 0_f64, 1e-12);
+    }
+
+    #[test]
+    pub fn infeasible_test() {
+        let contradictory = LinearProgram::new(&[1_f64, 1_f64])
+            .constraint(&[1_f64, 1_f64], Relation::LessEqual, 1_f64)
+            .constraint(&[1_f64, 1_f64], Relation::GreaterEqual, 2_f64)
+            .solve();
+        let negative = LinearProgram::new(&[1_f64, 1_f64])
+            .constraint(&[1_f64, 2_f64], Relation::Equal, -3_f64)
+            .solve();
+
+        assert_eq!(contradictory, Err(OptimizeError::Infeasible));
+        assert_eq!(negative, Err(OptimizeError::Infeasible));
+    }
+
+    #[test]
+    pub fn unbounded_test() {
+        let solution = LinearProgram::new(&[-1_f64, 0_f64])
+            .constraint(&[1_f64, -1_f64], Relation::LessEqual, 1_f64)
+            .solve();
+
+        assert_eq!(solution, Err(OptimizeError::Unbounded));
+    }
+}
@@ -0,0 +1,5 @@
+mod bounds_tests;
+mod gradient_tests;
+mod least_squares_tests;
+mod linear_program_tests;
+mod nelder_mead_tests;
@@ -0,0 +1,92 @@
+use numerilib::optimize::{Minimizer, OptimizeError, OptimizeMethod, OptimizeTolerance};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn rosenbrock_test() {
+        let rosenbrock =
+            |x: &[f64]| (1_f64 - x[0]).powi(2) + 100_f64 * (x[1] - x[0] * x[0]).powi(2);
+
+        let result = Minimizer::new(rosenbrock)
+            .minimize(OptimizeMethod::NelderMead, &[-1.2_f64, 1_f64])
+            .unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(result.argmin[0], 1_f64, 1e-6);
+        assert_approx_eq!(result.argmin[1], 1_f64, 1e-6);
+        assert!(result.minimum < 1e-13);
+        assert!(result.gradient.is_empty());
+        assert_eq!(result.gradient_evaluations, 0);
+    }
+
+    #[test]
+    pub fn one_dimension_test() {
+        let function = |x: &[f64]| (x[0] - 2_f64).powi(2) + x[0].cos();
+
+        let result = Minimizer::new(function)
+            .minimize(OptimizeMethod::NelderMead, &[0_f64])
+            .unwrap();
+
+        // The minimum solves 2(x - 2) = sin(x).
+        assert!(result.converged);
+        assert_approx_eq!(result.argmin[0], 2.354242758227, 1e-6);
+    }
+
+    #[test]
+    pub fn non_smooth_test() {
+        let function = |x: &[f64]| (x[0] - 3_f64).abs() + 2_f64 * (x[1] + 1_f64).abs();
+
+        let result = Minimizer::new(function)
+            .minimize(OptimizeMethod::NelderMead, &[0_f64; 2])
+            .unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(result.argmin[0], 3_f64, 1e-6);
+        assert_approx_eq!(result.argmin[1], -1_f64, 1e-6);
+    }
+
+    #[test]
+    pub fn non_finite_region_test() {
+        // NaN outside the domain of the logarithm pushes the simplex back.
+        let function = |x: &[f64]| x[0] - 2_f64 * x[0].ln() + (x[1] - 1_f64).powi(2);
+
+        let result = Minimizer::new(function)
+            .minimize(OptimizeMethod::NelderMead, &[0.1_f64, 0_f64])
+            .unwrap();
+
+        assert!(result.converged);
+        assert_approx_eq!(result.argmin[0], 2_f64, 1e-6);
+        assert_approx_eq!(result.argmin[1], 1_f64, 1e-6);
+    }
+
+    #[test]
+    pub fn tolerance_test() {
+        let function = |x: &[f64]| x.iter().map(|x| x * x).sum::<f64>();
+
+        let result = Minimizer::new(function)
+            .tolerance(OptimizeTolerance {
+                max_iterations: 10,
+                ..OptimizeTolerance::default()
+            })
+            .minimize(OptimizeMethod::NelderMead, &[1_f64; 4])
+            .unwrap();
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 10);
+        assert!(result.minimum < 4_f64);
+    }
+
+    #[test]
+    pub fn non_finite_test() {
+        let result =
+            Minimizer::new(|x: &[f64]| 1_f64 / x[0]).minimize(OptimizeMethod::NelderMead, &[0_f64]);
+
+        assert_eq!(
+            result,
+            Err(OptimizeError::NonFiniteValue { x: vec![0_f64] })
+        );
+    }
+}
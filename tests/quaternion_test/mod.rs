@@ -0,0 +1 @@
+mod quaternion_tests;
@@ -0,0 +1,152 @@
+use numerilib::{Matrix, Quaternion};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn assert_quaternion_eq(expected: Quaternion, actual: Quaternion) {
+        assert_approx_eq!(expected.scalar_part(), actual.scalar_part());
+        for (e, a) in expected.vector_part().iter().zip(actual.vector_part()) {
+            assert_approx_eq!(*e, a);
+        }
+    }
+
+    #[test]
+    fn test_hamilton_product() {
+        let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+        assert_eq!(k, i * j);
+        assert_eq!(-k, j * i);
+        assert_eq!(-Quaternion::IDENTITY, i * j * k);
+    }
+
+    #[test]
+    fn test_product() {
+        let q1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        let q2 = Quaternion::new(4.0, 3.0, 2.0, 1.0);
+
+        assert_eq!(Quaternion::new(-12.0, 6.0, 24.0, 12.0), q1 * q2);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_quaternion_eq(Quaternion::IDENTITY, q * q.inverse());
+        assert_quaternion_eq(Quaternion::IDENTITY, q.inverse() * q);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0).normalize();
+
+        assert_approx_eq!(1.0, q.norm());
+        assert_approx_eq!(1.0 / 30_f64.sqrt(), q.scalar_part());
+    }
+
+    #[test]
+    fn test_exp_and_ln() {
+        let q = Quaternion::new(0.5, 0.1, -0.2, 0.3);
+
+        assert_quaternion_eq(q, q.exp().ln());
+        assert_quaternion_eq(
+            Quaternion::new(0.0, 1.0, 0.0, 0.0),
+            Quaternion::new(0.0, FRAC_PI_2, 0.0, 0.0).exp(),
+        );
+    }
+
+    #[test]
+    fn test_slerp() {
+        let start = Quaternion::IDENTITY;
+        let end = Quaternion::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+
+        let halfway = start.slerp(&end, 0.5);
+
+        assert_quaternion_eq(
+            Quaternion::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_4),
+            halfway,
+        );
+        assert_quaternion_eq(halfway, start.slerp(&-end, 0.5));
+        assert_quaternion_eq(end, start.slerp(&end, 1.0));
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let q = Quaternion::from_axis_angle([0.0, 2.0, 0.0], 1.0);
+
+        let (axis, angle) = q.to_axis_angle();
+
+        assert_approx_eq!(1.0, angle);
+        assert_approx_eq!(0.0, axis[0]);
+        assert_approx_eq!(1.0, axis[1]);
+        assert_approx_eq!(0.0, axis[2]);
+    }
+
+    #[test]
+    fn test_rotate_vector() {
+        let q = Quaternion::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+
+        let rotated = q.rotate_vector([1.0, 0.0, 0.0]);
+
+        assert_approx_eq!(0.0, rotated[0]);
+        assert_approx_eq!(1.0, rotated[1]);
+        assert_approx_eq!(0.0, rotated[2]);
+    }
+
+    #[test]
+    fn test_euler() {
+        let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+
+        let (roll, pitch, yaw) = q.to_euler();
+
+        assert_approx_eq!(0.1, roll);
+        assert_approx_eq!(0.2, pitch);
+        assert_approx_eq!(0.3, yaw);
+    }
+
+    #[test]
+    fn test_euler_gimbal_lock() {
+        let q = Quaternion::from_euler(0.0, FRAC_PI_2, 0.4);
+
+        let (roll, pitch, yaw) = q.to_euler();
+
+        assert_approx_eq!(0.0, roll);
+        assert_approx_eq!(FRAC_PI_2, pitch);
+        assert_approx_eq!(0.4, yaw);
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        let q = Quaternion::from_euler(0.3, -0.7, 2.5);
+
+        let matrix = q.to_rotation_matrix();
+        let back = Quaternion::from_rotation_matrix(&matrix);
+
+        assert_quaternion_eq(q, back);
+    }
+
+    #[test]
+    fn test_from_rotation_matrix_half_turn() {
+        let matrix = Matrix::new([
+            [-1_f64, 0_f64, 0_f64],
+            [0_f64, 1_f64, 0_f64],
+            [0_f64, 0_f64, -1_f64],
+        ]);
+
+        let (axis, angle) = Quaternion::from_rotation_matrix(&matrix).to_axis_angle();
+
+        assert_approx_eq!(PI, angle);
+        assert_approx_eq!(1.0, axis[1].abs());
+    }
+
+    #[test]
+    fn test_display() {
+        let q = Quaternion::new(1.0, -2.0, 3.0, -4.0);
+
+        assert_eq!("1-2i+3j-4k", format!("{}", q));
+    }
+}
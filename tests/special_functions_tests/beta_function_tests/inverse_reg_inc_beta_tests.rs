@@ -48,4 +48,15 @@ pub mod tests {
 
         assert_approx_eq!(1_f64 / 7_f64, beta);
     }
+
+    #[test]
+    pub fn inv_reg_inc_beta_function_test5() {
+        let x = 0.5_f64;
+        let z1 = 2_f64;
+        let z2 = 0.5_f64;
+
+        let beta = Beta::invregincbeta(z1, z2, x);
+
+        assert_approx_eq!(0.879385241571817, beta, 1e-10);
+    }
 }
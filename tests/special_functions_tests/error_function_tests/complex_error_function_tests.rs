@@ -0,0 +1,38 @@
+use numerilib::special::Error;
+use numerilib::Complex;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn complex_erf_test1() {
+        let z = Complex::new(1.0, 1.0);
+
+        let result = Error::complex_erf(z);
+
+        assert_approx_eq!(1.3161512816979477, result.real_part(), 1e-12);
+        assert_approx_eq!(0.19045346923783469, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_erf_test2() {
+        let z = Complex::new(-2.0, 0.5);
+
+        let result = Error::complex_erf(z);
+
+        assert_approx_eq!(-1.0035022433130363, result.real_part(), 1e-12);
+        assert_approx_eq!(0.004740903031294336, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_erf_test3() {
+        let z = Complex::new(0.1, -0.2);
+
+        let result = Error::complex_erf(z);
+
+        assert_approx_eq!(0.1170214863039043, result.real_part(), 1e-12);
+        assert_approx_eq!(-0.2263844571814509, result.imag_part(), 1e-12);
+    }
+}
@@ -0,0 +1,32 @@
+use numerilib::special::Error;
+use numerilib::Dual;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn dual_error_function_test1() {
+        let result = Error::dual_erf(Dual::variable(0.5));
+
+        assert_approx_eq!(0.5204998778130465, result.real_part(), 1e-12);
+        assert_approx_eq!(0.8787825789354448, result.dual_part(), 1e-15);
+    }
+
+    #[test]
+    pub fn dual_error_function_test2() {
+        // d/dx erf(2x) = 4/√π e^(-4x^2)
+        let result = Error::dual_erf(2.0 * Dual::variable(0.25));
+
+        assert_approx_eq!(2.0 * 0.8787825789354448, result.dual_part(), 1e-15);
+    }
+
+    #[test]
+    pub fn dual_complimentary_error_function_test() {
+        let result = Error::dual_erfc(Dual::variable(0.5));
+
+        assert_approx_eq!(0.4795001221869535, result.real_part(), 1e-12);
+        assert_approx_eq!(-0.8787825789354448, result.dual_part(), 1e-15);
+    }
+}
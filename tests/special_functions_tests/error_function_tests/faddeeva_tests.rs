@@ -0,0 +1,38 @@
+use numerilib::special::Error;
+use numerilib::Complex;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn faddeeva_test1() {
+        let z = Complex::new(1.0, 1.0);
+
+        let result = Error::faddeeva(z);
+
+        assert_approx_eq!(0.3047442052569126, result.real_part(), 1e-12);
+        assert_approx_eq!(0.20821893820283163, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn faddeeva_test2() {
+        let z = Complex::new(-2.0, 0.5);
+
+        let result = Error::faddeeva(z);
+
+        assert_approx_eq!(0.10335882374136666, result.real_part(), 1e-12);
+        assert_approx_eq!(-0.28478588475009375, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn faddeeva_test3() {
+        let z = Complex::new(3.0, -4.0);
+
+        let result = Error::faddeeva(z);
+
+        assert_approx_eq!(930.2465952058438, result.real_part(), 1e-12);
+        assert_approx_eq!(-1986.10892633306, result.imag_part(), 1e-12);
+    }
+}
@@ -40,4 +40,15 @@ pub mod tests {
 
         assert_approx_eq!(2_f64, inverf);
     }
+
+    #[test]
+    pub fn inverse_error_function_test5() {
+        let z = 0.999999_f64;
+
+        let inverf = Error::inverf(z);
+
+        assert_approx_eq!(3.4589107372755, inverf, 1e-10);
+        assert_eq!(f64::INFINITY, Error::inverf(1_f64));
+        assert!(Error::inverf(1.5).is_nan());
+    }
 }
@@ -1,3 +1,8 @@
+mod complex_complimentary_error_function_tests;
+mod complex_error_function_tests;
 mod complimentary_error_function_tests;
+mod dual_error_function_tests;
+#[allow(clippy::module_inception)]
 mod error_function_tests;
+mod faddeeva_tests;
 mod inverse_error_function_tests;
@@ -0,0 +1,38 @@
+use numerilib::special::Gamma;
+use numerilib::Complex;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn complex_gamma_test1() {
+        let z = Complex::new(1.0, 2.0);
+
+        let result = Gamma::complex_gamma(z);
+
+        assert_approx_eq!(0.15190400267003614, result.real_part(), 1e-12);
+        assert_approx_eq!(0.01980488016185498, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_gamma_test2() {
+        let z = Complex::new(-2.5, 0.5);
+
+        let result = Gamma::complex_gamma(z);
+
+        assert_approx_eq!(-0.33387520352243234, result.real_part(), 1e-12);
+        assert_approx_eq!(-0.2064573079636084, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_gamma_test3() {
+        let z = Complex::new(-3.5, -1.0);
+
+        let result = Gamma::complex_gamma(z);
+
+        assert_approx_eq!(0.00450634588361239, result.real_part(), 1e-12);
+        assert_approx_eq!(-0.02596441521525025, result.imag_part(), 1e-12);
+    }
+}
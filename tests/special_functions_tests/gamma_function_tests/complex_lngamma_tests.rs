@@ -0,0 +1,38 @@
+use numerilib::special::Gamma;
+use numerilib::Complex;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn complex_lngamma_test1() {
+        let z = Complex::new(1.0, 2.0);
+
+        let result = Gamma::complex_lngamma(z);
+
+        assert_approx_eq!(-1.8760787864309293, result.real_part(), 1e-12);
+        assert_approx_eq!(0.12964631630978831, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_lngamma_test2() {
+        let z = Complex::new(-2.5, 0.5);
+
+        let result = Gamma::complex_lngamma(z);
+
+        assert_approx_eq!(-0.9350856212982775, result.real_part(), 1e-12);
+        assert_approx_eq!(-8.87096288524746, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_lngamma_test3() {
+        let z = Complex::new(-3.5, -1.0);
+
+        let result = Gamma::complex_lngamma(z);
+
+        assert_approx_eq!(-3.636189428681708, result.real_part(), 1e-12);
+        assert_approx_eq!(11.167420981242608, result.imag_part(), 1e-12);
+    }
+}
@@ -22,7 +22,7 @@ pub mod tests {
 
         let incgamma = Gamma::incgammac(bound, x);
 
-        assert_approx_eq!(1.35335283233834464, incgamma);
+        assert_approx_eq!(1.353_352_832_338_345, incgamma);
     }
 
     #[test]
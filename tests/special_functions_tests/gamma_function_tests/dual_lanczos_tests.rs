@@ -0,0 +1,40 @@
+use numerilib::special::Gamma;
+use numerilib::{Dual, HyperDual};
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn dual_lanczos_test1() {
+        let result = Gamma::dual_lanczos(Dual::variable(6.0));
+
+        assert_eq!(Gamma::lanczos(6.0), result.real_part());
+        assert_approx_eq!(204.73412021181606, result.dual_part(), 1e-6);
+    }
+
+    #[test]
+    pub fn dual_lanczos_test2() {
+        // The derivative of ln Γ(z) is the digamma function
+        let result = Gamma::dual_lanczosln(Dual::variable(2.5));
+
+        assert_approx_eq!(0.7031566406452432, result.dual_part(), 1e-9);
+    }
+
+    #[test]
+    pub fn hyper_dual_lanczos_test1() {
+        let result = Gamma::hyper_dual_lanczos(HyperDual::variable(6.0));
+
+        assert_approx_eq!(204.73412021181606, result.first_part(), 1e-6);
+        assert_approx_eq!(371.0592545126734, result.mixed_part(), 1e-6);
+    }
+
+    #[test]
+    pub fn hyper_dual_lanczos_test2() {
+        // The second derivative of ln Γ(z) is the trigamma function
+        let result = Gamma::hyper_dual_lanczosln(HyperDual::variable(2.5));
+
+        assert_approx_eq!(0.4903577561002349, result.mixed_part(), 1e-9);
+    }
+}
@@ -1,5 +1,8 @@
+mod complex_gamma_tests;
+mod complex_lngamma_tests;
 mod complimentary_incomplete_gamma_function_tests;
 mod complimentary_regularized_incomplete_gamma_function_tests;
+mod dual_lanczos_tests;
 mod incomplete_gamma_function_tests;
 mod lanczos_approximation_tests;
 mod lanczos_log_gamma_approximation_tests;
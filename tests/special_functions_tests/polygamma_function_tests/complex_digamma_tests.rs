@@ -0,0 +1,38 @@
+use numerilib::special::Polygamma;
+use numerilib::Complex;
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    pub fn complex_digamma_test1() {
+        let z = Complex::new(1.0, 2.0);
+
+        let result = Polygamma::complex_digamma(z);
+
+        assert_approx_eq!(0.7145915153739775, result.real_part(), 1e-12);
+        assert_approx_eq!(1.3208072826422302, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_digamma_test2() {
+        let z = Complex::new(-2.5, 0.5);
+
+        let result = Polygamma::complex_digamma(z);
+
+        assert_approx_eq!(1.1165080219699073, result.real_part(), 1e-12);
+        assert_approx_eq!(2.7175825969005915, result.imag_part(), 1e-12);
+    }
+
+    #[test]
+    pub fn complex_digamma_test3() {
+        let z = Complex::new(-3.5, -1.0);
+
+        let result = Polygamma::complex_digamma(z);
+
+        assert_approx_eq!(1.418755340147172, result.real_part(), 1e-12);
+        assert_approx_eq!(-2.886035558104153, result.imag_part(), 1e-12);
+    }
+}
@@ -1,2 +1,3 @@
+mod complex_digamma_tests;
 mod digamma_tests;
-mod polygamma_tests;
\ No newline at end of file
+mod polygamma_tests;
@@ -20,7 +20,7 @@ pub mod tests {
 
         let digamma = Polygamma::digamma(x);
 
-        assert_approx_eq!(-10.423744443973368, digamma);
+        assert_approx_eq!(-10.423754940411076, digamma);
     }
 
     #[test]
@@ -29,7 +29,7 @@ pub mod tests {
 
         let digamma = Polygamma::digamma(x);
 
-        assert_approx_eq!(-5.289037189298927, digamma);
+        assert_approx_eq!(-5.289039896592188, digamma);
     }
 
     #[test]
@@ -35,7 +35,6 @@ pub mod tests {
     #[test]
     pub fn geometric_trials_sd_test4() {
         let probability = 0.255;
-        let trials = 15_f64;
 
         let geometric_trials = GeometricTrials::sd(probability);
 
@@ -22,7 +22,7 @@ mod tests {
 
         let x = Student::inv(area, df);
 
-        assert_approx_eq!(-1.6657206563868145, x);
+        assert_approx_eq!(-1.66940222170681, x);
     }
 
     #[test]
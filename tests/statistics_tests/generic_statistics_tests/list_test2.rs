@@ -6,7 +6,6 @@ const NUMBERS: [f64; 10] = [3.5, 2.1, 78.9, 2.0, 89.0, 173.5, 0.0, 9.7, 73.4, 3.
 #[cfg(test)]
 mod tests {
     use super::*;
-    use assert_approx_eq::assert_approx_eq;
 
     #[test]
     pub fn mean_test() {
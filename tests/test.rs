@@ -1,5 +1,10 @@
 mod complex_numbers_test;
+mod dual_numbers_test;
+mod quaternion_test;
 pub mod functions_tests;
+pub mod interpolation_tests;
 pub mod matix_functions_tests;
+pub mod ode_tests;
+pub mod optimize_tests;
 pub mod special_functions_tests;
 pub mod statistics_tests;